@@ -0,0 +1,95 @@
+//! Cancellation of running queries.
+//!
+//! A [`CancellationToken`] is handed to a query and checked by its operators between units of
+//! work. Once the token is cancelled the query stops with [`PolarsError::Cancelled`], and once
+//! its timeout has elapsed with [`PolarsError::Timeout`].
+//!
+//! The default engine checks the token before every operator, between the aggregations of a
+//! groupby and between the row groups of a parquet scan. The streaming engine checks it for
+//! every morsel.
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::prelude::*;
+
+struct TokenInner {
+    cancelled: AtomicBool,
+    // the point in time at which the query times out and the timeout itself
+    deadline: Option<(Instant, Duration)>,
+}
+
+/// A token to stop a running query from another thread.
+///
+/// This is cheap to clone, all clones refer to the same token.
+#[derive(Clone)]
+pub struct CancellationToken {
+    inner: Arc<TokenInner>,
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        CancellationToken {
+            inner: Arc::new(TokenInner {
+                cancelled: AtomicBool::new(false),
+                deadline: None,
+            }),
+        }
+    }
+
+    /// A token that times out once `timeout` has elapsed, measured from now. It can also be
+    /// cancelled before that.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        CancellationToken {
+            inner: Arc::new(TokenInner {
+                cancelled: AtomicBool::new(false),
+                deadline: Some((Instant::now() + timeout, timeout)),
+            }),
+        }
+    }
+
+    /// Cancel the queries that run with this token.
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::Relaxed)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Return an error if the token is cancelled or has timed out.
+    pub fn check(&self) -> PolarsResult<()> {
+        polars_ensure!(!self.is_cancelled(), Cancelled: "query was cancelled");
+        if let Some((deadline, timeout)) = self.inner.deadline {
+            polars_ensure!(
+                Instant::now() < deadline,
+                Timeout: "query did not finish within {:?}", timeout
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cancellation_token() {
+        let token = CancellationToken::new();
+        assert!(token.check().is_ok());
+        token.clone().cancel();
+        assert!(matches!(token.check(), Err(PolarsError::Cancelled(_))));
+
+        let token = CancellationToken::with_timeout(Duration::ZERO);
+        assert!(matches!(token.check(), Err(PolarsError::Timeout(_))));
+        let token = CancellationToken::with_timeout(Duration::from_secs(3600));
+        assert!(token.check().is_ok());
+    }
+}
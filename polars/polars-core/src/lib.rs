@@ -4,6 +4,7 @@ extern crate core;
 
 #[macro_use]
 pub mod utils;
+pub mod cancel;
 pub mod chunked_array;
 pub mod cloud;
 pub mod config;
@@ -16,6 +17,7 @@ pub mod fmt;
 pub mod frame;
 pub mod functions;
 pub(crate) mod hashing;
+pub mod memory;
 mod named_from;
 pub mod prelude;
 pub mod schema;
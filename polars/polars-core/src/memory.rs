@@ -0,0 +1,177 @@
+//! Memory accounting of running queries.
+//!
+//! Every query gets a [`QueryMemory`]. Operators that hold on to data, such as the sinks
+//! of the streaming engine and the caches of the default engine, register their allocations
+//! with the query, which also counts them in the process wide [`MEMORY_MANAGER`].
+//! Once the budget of the query or the limit of the process is exceeded, the operators
+//! that are able to spill to disk are asked to do so.
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use once_cell::sync::Lazy;
+
+pub(crate) const MEMORY_LIMIT: &str = "POLARS_MEMORY_LIMIT";
+
+/// Process wide memory manager, the limit is shared by all queries in this process.
+///
+/// The limit can be set in bytes with the `POLARS_MEMORY_LIMIT` environment variable
+/// or with [`MemoryManager::set_limit`]. A value that is not an integer is ignored.
+pub static MEMORY_MANAGER: Lazy<MemoryManager> = Lazy::new(|| {
+    let limit = std::env::var(MEMORY_LIMIT)
+        .ok()
+        .and_then(|s| match s.parse::<usize>() {
+            Ok(limit) => Some(limit),
+            Err(_) => {
+                eprintln!("ignoring {MEMORY_LIMIT}={s:?}, expected a number of bytes");
+                None
+            }
+        });
+    MemoryManager::new(limit)
+});
+
+pub struct MemoryManager {
+    // 0 means no limit
+    limit: AtomicUsize,
+    used: AtomicUsize,
+}
+
+impl MemoryManager {
+    fn new(limit: Option<usize>) -> Self {
+        MemoryManager {
+            limit: AtomicUsize::new(limit.unwrap_or(0)),
+            used: Default::default(),
+        }
+    }
+
+    /// Set the limit in bytes of the memory all queries together may register.
+    pub fn set_limit(&self, limit: Option<usize>) {
+        self.limit.store(limit.unwrap_or(0), Ordering::Relaxed)
+    }
+
+    pub fn limit(&self) -> Option<usize> {
+        match self.limit.load(Ordering::Relaxed) {
+            0 => None,
+            limit => Some(limit),
+        }
+    }
+
+    /// The memory in bytes that is registered by all running queries.
+    pub fn used(&self) -> usize {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    fn exceeds_limit(&self) -> bool {
+        self.limit().map_or(false, |limit| self.used() > limit)
+    }
+}
+
+struct QueryMemoryInner {
+    budget: Option<usize>,
+    used: AtomicUsize,
+    // whether an operator of this query was asked to spill
+    spilled: AtomicBool,
+}
+
+impl Drop for QueryMemoryInner {
+    fn drop(&mut self) {
+        MEMORY_MANAGER
+            .used
+            .fetch_sub(*self.used.get_mut(), Ordering::Relaxed);
+    }
+}
+
+/// The memory registered by a single query.
+///
+/// This is cheap to clone, all clones refer to the same query. The registered memory is
+/// released from the [`MEMORY_MANAGER`] once the last clone is dropped.
+#[derive(Clone)]
+pub struct QueryMemory {
+    inner: Arc<QueryMemoryInner>,
+}
+
+impl Default for QueryMemory {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl QueryMemory {
+    /// Memory of a new query, that may register at most `budget` bytes.
+    pub fn new(budget: Option<usize>) -> Self {
+        QueryMemory {
+            inner: Arc::new(QueryMemoryInner {
+                budget,
+                used: Default::default(),
+                spilled: Default::default(),
+            }),
+        }
+    }
+
+    pub fn budget(&self) -> Option<usize> {
+        self.inner.budget
+    }
+
+    /// The memory in bytes that is registered by this query.
+    pub fn used(&self) -> usize {
+        self.inner.used.load(Ordering::Relaxed)
+    }
+
+    /// Register an allocation of `bytes`.
+    pub fn grow(&self, bytes: usize) {
+        self.inner.used.fetch_add(bytes, Ordering::Relaxed);
+        MEMORY_MANAGER.used.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    /// Release an allocation of `bytes` that was registered with [`QueryMemory::grow`].
+    pub fn shrink(&self, bytes: usize) {
+        let prev = self.inner.used.fetch_sub(bytes, Ordering::Relaxed);
+        debug_assert!(prev >= bytes);
+        MEMORY_MANAGER.used.fetch_sub(bytes, Ordering::Relaxed);
+    }
+
+    /// Whether operators of this query should spill to disk, because either the budget
+    /// of this query or the limit of the process is exceeded.
+    pub fn should_spill(&self) -> bool {
+        let should_spill = self
+            .inner
+            .budget
+            .map_or(false, |budget| self.used() > budget)
+            || MEMORY_MANAGER.exceeds_limit();
+        if should_spill {
+            self.inner.spilled.store(true, Ordering::Relaxed)
+        }
+        should_spill
+    }
+
+    /// Whether operators of this query were asked to spill to disk at some point.
+    pub fn spilled(&self) -> bool {
+        self.inner.spilled.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query_memory() {
+        let budget = 1 << 30;
+        let a = QueryMemory::new(Some(budget));
+        let b = QueryMemory::new(None);
+        a.grow(budget);
+        assert!(!a.should_spill());
+        assert!(!a.spilled());
+        a.clone().grow(1);
+        assert!(a.should_spill());
+        assert!(a.spilled());
+        assert_eq!(a.used(), budget + 1);
+
+        b.grow(10);
+        assert!(MEMORY_MANAGER.used() >= budget + 11);
+        a.shrink(budget);
+        assert!(!a.should_spill());
+        assert!(a.spilled());
+        drop(b);
+        drop(a);
+    }
+}
@@ -37,6 +37,8 @@ impl Display for ErrString {
 pub enum PolarsError {
     #[error(transparent)]
     ArrowError(Box<ArrowError>),
+    #[error("cancelled: {0}")]
+    Cancelled(ErrString),
     #[error("not found: {0}")]
     ColumnNotFound(ErrString),
     #[error("{0}")]
@@ -57,6 +59,8 @@ pub enum PolarsError {
     ShapeMismatch(ErrString),
     #[error("field not found: {0}")]
     StructFieldNotFound(ErrString),
+    #[error("timed out: {0}")]
+    Timeout(ErrString),
 }
 
 impl From<ArrowError> for PolarsError {
@@ -81,6 +85,7 @@ impl PolarsError {
         use PolarsError::*;
         match self {
             ArrowError(err) => ComputeError(func(&format!("ArrowError: {err}")).into()),
+            Cancelled(msg) => Cancelled(func(msg).into()),
             ColumnNotFound(msg) => ColumnNotFound(func(msg).into()),
             ComputeError(msg) => ComputeError(func(msg).into()),
             Duplicate(msg) => Duplicate(func(msg).into()),
@@ -91,6 +96,7 @@ impl PolarsError {
             SchemaMismatch(msg) => SchemaMismatch(func(msg).into()),
             ShapeMismatch(msg) => ShapeMismatch(func(msg).into()),
             StructFieldNotFound(msg) => StructFieldNotFound(func(msg).into()),
+            Timeout(msg) => Timeout(func(msg).into()),
         }
     }
 }
@@ -12,9 +12,11 @@ mod test {
 
     use polars_core::df;
     use polars_core::prelude::*;
+    use polars_core::utils::accumulate_dataframes_vertical;
 
     use super::{write, AvroReader, AvroWriter};
     use crate::prelude::*;
+    use crate::RowCount;
 
     #[test]
     fn test_write_and_read_with_compression() -> PolarsResult<()> {
@@ -98,4 +100,39 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_batched() -> PolarsResult<()> {
+        let mut df = df!(
+            "i64" => (0..10i64).collect::<Vec<_>>(),
+            "utf8" => (0..10).map(|i| i.to_string()).collect::<Vec<_>>()
+        )?;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+
+        AvroWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        // columns are read in the order of the file
+        let mut reader = AvroReader::new(buf)
+            .with_columns(Some(vec!["utf8".to_string(), "i64".to_string()]))
+            .with_n_rows(Some(7))
+            .with_row_count(Some(RowCount {
+                name: "row_nr".into(),
+                offset: 1,
+            }))
+            .batched(3)?;
+
+        let mut batches = vec![];
+        while let Some(dfs) = reader.next_batches(2)? {
+            batches.extend(dfs);
+        }
+        assert!(batches.len() > 1);
+        let read_df = accumulate_dataframes_vertical(batches)?;
+        assert_eq!(read_df.get_column_names(), &["row_nr", "i64", "utf8"]);
+        assert_eq!(read_df.height(), 7);
+        assert_eq!(read_df.column("row_nr")?.idx()?.get(6), Some(7 as IdxSize));
+
+        Ok(())
+    }
 }
@@ -1,11 +1,15 @@
+use std::collections::VecDeque;
 use std::io::{Read, Seek};
 
 use arrow::io::avro::{self, read};
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+use polars_core::utils::split_df;
 
 use super::{finish_reader, ArrowChunk, ArrowReader, ArrowResult};
+use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
+use crate::RowCount;
 
 /// Read Apache Avro format into a DataFrame
 ///
@@ -30,6 +34,8 @@ pub struct AvroReader<R> {
     n_rows: Option<usize>,
     columns: Option<Vec<String>>,
     projection: Option<Vec<usize>>,
+    row_count: Option<RowCount>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
 }
 
 impl<R: Read + Seek> AvroReader<R> {
@@ -65,6 +71,117 @@ impl<R: Read + Seek> AvroReader<R> {
         self.columns = columns;
         self
     }
+
+    /// Add a `row_count` column.
+    pub fn with_row_count(mut self, row_count: Option<RowCount>) -> Self {
+        self.row_count = row_count;
+        self
+    }
+
+    /// Filter the rows of every block with `predicate` while reading.
+    pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
+        self.predicate = predicate;
+        self
+    }
+
+    /// The reader of the blocks of the file and the schema of the projected columns.
+    /// Columns are read in the order of the file, regardless of the order of the projection.
+    fn into_reader(mut self) -> PolarsResult<(read::Reader<R>, ArrowSchema)> {
+        let metadata =
+            avro::avro_schema::read::read_metadata(&mut self.reader).map_err(to_compute_err)?;
+        let schema = read::infer_schema(&metadata.record)?;
+
+        if let Some(columns) = &self.columns {
+            self.projection = Some(columns_to_projection(columns, &schema)?);
+        }
+
+        let (projection, projected_schema) = if let Some(mut projection) = self.projection {
+            projection.sort_unstable();
+            let mut prj = vec![false; schema.fields.len()];
+            for &index in projection.iter() {
+                prj[index] = true;
+            }
+            (Some(prj), apply_projection(&schema, &projection))
+        } else {
+            (None, schema.clone())
+        };
+
+        let avro_reader = avro::read::Reader::new(self.reader, metadata, schema.fields, projection);
+        Ok((avro_reader, projected_schema))
+    }
+
+    /// Read the file in batches of about `chunk_size` rows, see [`BatchedAvroReader`].
+    pub fn batched(self, chunk_size: usize) -> PolarsResult<BatchedAvroReader<R>> {
+        let n_rows = self.n_rows;
+        let row_count = self.row_count.clone();
+        let predicate = self.predicate.clone();
+        let (reader, schema) = self.into_reader()?;
+
+        Ok(BatchedAvroReader {
+            reader,
+            schema,
+            row_count,
+            predicate,
+            rows_left: n_rows.unwrap_or(usize::MAX),
+            rows_read: 0,
+            chunk_size,
+            chunks_fifo: VecDeque::new(),
+        })
+    }
+}
+
+/// Reads the blocks of an Avro file one by one. Blocks that are larger than the
+/// chunk size are split.
+pub struct BatchedAvroReader<R: Read + Seek> {
+    reader: read::Reader<R>,
+    schema: ArrowSchema,
+    row_count: Option<RowCount>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    rows_left: usize,
+    rows_read: IdxSize,
+    chunk_size: usize,
+    chunks_fifo: VecDeque<DataFrame>,
+}
+
+impl<R: Read + Seek> BatchedAvroReader<R> {
+    pub fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
+        // fill up fifo stack
+        while self.chunks_fifo.len() < n && self.rows_left > 0 {
+            let Some(batch) = self.reader.next_record_batch()? else {
+                break;
+            };
+            let mut df = DataFrame::try_from((batch, self.schema.fields.as_slice()))?;
+            if df.height() > self.rows_left {
+                df = df.slice(0, self.rows_left);
+            }
+            self.rows_left -= df.height();
+            if let Some(rc) = &self.row_count {
+                df.with_row_count_mut(&rc.name, Some(self.rows_read + rc.offset));
+            }
+            self.rows_read += df.height() as IdxSize;
+
+            if let Some(predicate) = &self.predicate {
+                let s = predicate.evaluate(&df)?;
+                let mask = s.bool().expect("filter predicates was not of type boolean");
+                df = df.filter(mask)?;
+            }
+
+            // make sure that the chunks are not too large
+            let n = df.height() / self.chunk_size;
+            if n > 1 {
+                self.chunks_fifo.extend(split_df(&mut df, n)?)
+            } else {
+                self.chunks_fifo.push_back(df)
+            }
+        }
+
+        if self.chunks_fifo.is_empty() {
+            Ok(None)
+        } else {
+            let n = std::cmp::min(n, self.chunks_fifo.len());
+            Ok(Some(self.chunks_fifo.drain(..n).collect()))
+        }
+    }
 }
 
 impl<R> ArrowReader for read::Reader<R>
@@ -87,6 +204,8 @@ where
             n_rows: None,
             columns: None,
             projection: None,
+            row_count: None,
+            predicate: None,
         }
     }
 
@@ -95,36 +214,20 @@ where
         self
     }
 
-    fn finish(mut self) -> PolarsResult<DataFrame> {
+    fn finish(self) -> PolarsResult<DataFrame> {
         let rechunk = self.rechunk;
-        let metadata =
-            avro::avro_schema::read::read_metadata(&mut self.reader).map_err(to_compute_err)?;
-        let schema = read::infer_schema(&metadata.record)?;
-
-        if let Some(columns) = &self.columns {
-            self.projection = Some(columns_to_projection(columns, &schema)?);
-        }
-
-        let (projection, projected_schema) = if let Some(projection) = self.projection {
-            let mut prj = vec![false; schema.fields.len()];
-            for &index in projection.iter() {
-                prj[index] = true;
-            }
-            (Some(prj), apply_projection(&schema, &projection))
-        } else {
-            (None, schema.clone())
-        };
-
-        let avro_reader =
-            avro::read::Reader::new(&mut self.reader, metadata, schema.fields, projection);
+        let n_rows = self.n_rows;
+        let row_count = self.row_count.clone();
+        let predicate = self.predicate.clone();
+        let (avro_reader, projected_schema) = self.into_reader()?;
 
         finish_reader(
             avro_reader,
             rechunk,
-            self.n_rows,
-            None,
+            n_rows,
+            predicate,
             &projected_schema,
-            None,
+            row_count,
         )
     }
 }
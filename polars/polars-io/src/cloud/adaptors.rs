@@ -1,5 +1,8 @@
 //! Interface with the object_store crate and define AsyncSeek, AsyncRead.
 //! This is used, for example, by the parquet2 crate.
+//!
+//! It also defines [`CloudWriter`], a synchronous `std::io::Write` on top of
+//! the multipart upload API, so that the regular writers can write to an object store.
 use std::io::{self};
 use std::pin::Pin;
 use std::sync::Arc;
@@ -10,7 +13,12 @@ use futures::future::BoxFuture;
 use futures::lock::Mutex;
 use futures::{AsyncRead, AsyncSeek, Future, TryFutureExt};
 use object_store::path::Path;
-use object_store::ObjectStore;
+use object_store::{MultipartId, ObjectStore};
+use polars_core::cloud::CloudOptions;
+use polars_core::error::{to_compute_err, PolarsError, PolarsResult};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use super::build;
 
 type OptionalFuture = Arc<Mutex<Option<BoxFuture<'static, std::io::Result<Vec<u8>>>>>>;
 
@@ -129,3 +137,195 @@ impl AsyncSeek for CloudReader {
         std::task::Poll::Ready(Ok(self.pos))
     }
 }
+
+/// Adaptor which exposes the asynchronous multipart upload API of [`ObjectStore`]
+/// as a synchronous `std::io::Write`.
+///
+/// This allows it to be used in sync code which would otherwise write to a `File`,
+/// such as `ParquetWriter`, `IpcWriter` and `CsvWriter`.
+///
+/// The object only becomes visible in the store once the upload is completed by
+/// [`CloudWriter::close`]. If a write fails, or the writer is dropped before it is
+/// closed, the upload is aborted, so that no truncated object is published.
+pub struct CloudWriter {
+    // Hold a reference to the store.
+    object_store: Arc<dyn ObjectStore>,
+    // The path in the object_store of the object being written.
+    path: Path,
+    // ID of the multipart upload, needed to abort it on error.
+    multipart_id: MultipartId,
+    // The runtime used to drive the asynchronous writer.
+    runtime: tokio::runtime::Runtime,
+    // The multipart writer, `None` once the upload is completed or aborted.
+    writer: Option<Box<dyn AsyncWrite + Send + Unpin>>,
+}
+
+impl CloudWriter {
+    /// Start a multipart upload to `path` in the given `object_store`.
+    pub fn new_with_object_store(
+        object_store: Arc<dyn ObjectStore>,
+        path: Path,
+    ) -> PolarsResult<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        let (multipart_id, writer) = runtime
+            .block_on(object_store.put_multipart(&path))
+            .map_err(to_compute_err)?;
+        Ok(Self {
+            object_store,
+            path,
+            multipart_id,
+            runtime,
+            writer: Some(writer),
+        })
+    }
+
+    /// Start a multipart upload to the object at `uri`, e.g. `s3://bucket/file.parquet`.
+    pub fn new(uri: &str, cloud_options: Option<&CloudOptions>) -> PolarsResult<Self> {
+        let (cloud_location, object_store) = build(uri, cloud_options)?;
+        Self::new_with_object_store(Arc::from(object_store), cloud_location.prefix.into())
+    }
+
+    /// Complete the upload. After this the object is visible in the store.
+    pub fn close(mut self) -> PolarsResult<()> {
+        self.complete().map_err(PolarsError::from)
+    }
+
+    fn complete(&mut self) -> io::Result<()> {
+        match self.writer.take() {
+            Some(mut writer) => {
+                let res = self.runtime.block_on(writer.shutdown());
+                if res.is_err() {
+                    self.abort();
+                }
+                res
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn abort(&mut self) {
+        self.writer = None;
+        let _ = self.runtime.block_on(
+            self.object_store
+                .abort_multipart(&self.path, &self.multipart_id),
+        );
+    }
+}
+
+fn err_upload_closed() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Other,
+        "the multipart upload has already been completed or aborted",
+    )
+}
+
+impl io::Write for CloudWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let res = match self.writer.as_mut() {
+            Some(writer) => self.runtime.block_on(writer.write_all(buf)),
+            None => return Err(err_upload_closed()),
+        };
+        if res.is_err() {
+            self.abort();
+        }
+        res.map(|_| buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let res = match self.writer.as_mut() {
+            Some(writer) => self.runtime.block_on(writer.flush()),
+            None => return Err(err_upload_closed()),
+        };
+        if res.is_err() {
+            self.abort();
+        }
+        res
+    }
+}
+
+impl Drop for CloudWriter {
+    fn drop(&mut self) {
+        if self.writer.is_some() {
+            self.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use object_store::memory::InMemory;
+
+    use super::*;
+
+    #[test]
+    fn test_cloud_writer() {
+        let object_store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let path = Path::from("data/test.csv");
+
+        let mut writer =
+            CloudWriter::new_with_object_store(object_store.clone(), path.clone()).unwrap();
+        writer.write_all(b"a,b\n1,2\n").unwrap();
+        writer.write_all(b"3,4\n").unwrap();
+        writer.close().unwrap();
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let bytes = rt
+            .block_on(async { object_store.get(&path).await.unwrap().bytes().await })
+            .unwrap();
+        assert_eq!(bytes.as_ref(), b"a,b\n1,2\n3,4\n");
+    }
+
+    #[test]
+    fn test_cloud_writer_dropped() {
+        let object_store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let path = Path::from("data/test.csv");
+
+        let mut writer =
+            CloudWriter::new_with_object_store(object_store.clone(), path.clone()).unwrap();
+        writer.write_all(b"a,b\n1,2\n").unwrap();
+        drop(writer);
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let res = rt.block_on(object_store.head(&path));
+        assert!(matches!(res, Err(object_store::Error::NotFound { .. })));
+    }
+
+    #[test]
+    #[cfg(feature = "csv-file")]
+    fn test_cloud_writer_local_file_system() {
+        use object_store::local::LocalFileSystem;
+        use polars_core::df;
+        use polars_core::prelude::*;
+
+        use crate::csv::{CsvReader, CsvWriter};
+        use crate::{SerReader, SerWriter};
+
+        let mut df = df![
+            "a" => [1, 2, 3],
+            "b" => ["x", "y", "z"],
+        ]
+        .unwrap();
+
+        let dir = std::env::temp_dir();
+        let object_store: Arc<dyn ObjectStore> =
+            Arc::new(LocalFileSystem::new_with_prefix(&dir).unwrap());
+        let mut writer =
+            CloudWriter::new_with_object_store(object_store, Path::from("cloud_writer.csv"))
+                .unwrap();
+        CsvWriter::new(&mut writer).finish(&mut df).unwrap();
+        writer.close().unwrap();
+
+        let path = dir.join("cloud_writer.csv");
+        let out = CsvReader::from_path(&path).unwrap().finish().unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert!(out.frame_equal(&df));
+    }
+}
@@ -1,4 +1,4 @@
-use memchr::memchr2_iter;
+use memchr::{memchr2_iter, memchr3_iter};
 use num_traits::Pow;
 use polars_core::prelude::*;
 
@@ -27,28 +27,34 @@ pub(crate) fn next_line_position_naive(input: &[u8], eol_char: u8) -> Option<usi
 }
 
 /// Find the nearest next line position that is not embedded in a String field.
+///
+/// As `input` may start in the middle of a line, we cannot know whether we are in a quoted field.
+/// We therefore look for lines that have the expected number of fields. If `truncated_rows` is set,
+/// lines with fewer fields are also accepted.
 pub(crate) fn next_line_position(
     mut input: &[u8],
     mut expected_fields: Option<usize>,
-    delimiter: u8,
+    delimiter: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
+    truncated_rows: bool,
 ) -> Option<usize> {
     fn accept_line(
         line: &[u8],
         expected_fields: usize,
-        delimiter: u8,
+        delimiter: &[u8],
         eol_char: u8,
         quote_char: Option<u8>,
+        truncated_rows: bool,
     ) -> bool {
         let mut count = 0usize;
         for (field, _) in SplitFields::new(line, delimiter, quote_char, eol_char) {
-            if memchr2_iter(delimiter, eol_char, field).count() >= expected_fields {
+            if memchr2_iter(delimiter[0], eol_char, field).count() >= expected_fields {
                 return false;
             }
             count += 1;
         }
-        count == expected_fields
+        count == expected_fields || (truncated_rows && count < expected_fields)
     }
 
     // we check 3 subsequent lines for `accept_line` before we accept
@@ -85,10 +91,24 @@ pub(crate) fn next_line_position(
         match (line, expected_fields) {
             // count the fields, and determine if they are equal to what we expect from the schema
             (Some(line), Some(expected_fields)) => {
-                if accept_line(line, expected_fields, delimiter, eol_char, quote_char) {
+                if accept_line(
+                    line,
+                    expected_fields,
+                    delimiter,
+                    eol_char,
+                    quote_char,
+                    truncated_rows,
+                ) {
                     let mut valid = true;
                     for line in lines.take(2) {
-                        if !accept_line(line, expected_fields, delimiter, eol_char, quote_char) {
+                        if !accept_line(
+                            line,
+                            expected_fields,
+                            delimiter,
+                            eol_char,
+                            quote_char,
+                            truncated_rows,
+                        ) {
                             valid = false;
                             break;
                         }
@@ -114,6 +134,57 @@ pub(crate) fn next_line_position(
     }
 }
 
+/// Find the position after the first line ending at or after `search_pos` that is not embedded
+/// in a quoted field.
+///
+/// In contrast to [`next_line_position`], `input` must start at the start of a line. This allows
+/// us to keep track of the quoting state, so no guessing based on the number of fields is needed.
+/// Returns `None` if no such line ending is found, or if it is the last byte of `input`.
+pub(crate) fn next_line_position_quoted(
+    input: &[u8],
+    search_pos: usize,
+    delimiter: &[u8],
+    quote_char: Option<u8>,
+    eol_char: u8,
+) -> Option<usize> {
+    let pos = match quote_char {
+        None => search_pos + memchr::memchr(eol_char, input.get(search_pos..)?)?,
+        Some(quote_char) => {
+            let mut in_field = false;
+            let mut field_start = 0;
+            // a quote directly after a closing quote is an escaped quote
+            let mut closed_at = usize::MAX;
+            let mut found = None;
+            for i in memchr3_iter(quote_char, delimiter[0], eol_char, input) {
+                let c = input[i];
+                if in_field {
+                    if c == quote_char {
+                        in_field = false;
+                        closed_at = i;
+                    }
+                } else if c == quote_char {
+                    if i == field_start || i == closed_at.wrapping_add(1) {
+                        in_field = true;
+                    }
+                } else if c == eol_char {
+                    if i >= search_pos {
+                        found = Some(i);
+                        break;
+                    }
+                    field_start = i + 1;
+                } else if input[i + 1..].starts_with(&delimiter[1..]) {
+                    field_start = i + delimiter.len();
+                }
+            }
+            found?
+        }
+    } + 1;
+    if pos == input.len() {
+        return None;
+    }
+    Some(pos)
+}
+
 pub(crate) fn is_line_ending(b: u8, eol_char: u8) -> bool {
     b == eol_char || b == b'\r'
 }
@@ -187,8 +258,9 @@ pub(crate) fn get_line_stats(
     n_lines: usize,
     eol_char: u8,
     expected_fields: usize,
-    delimiter: u8,
+    delimiter: &[u8],
     quote_char: Option<u8>,
+    truncated_rows: bool,
 ) -> Option<(f32, f32)> {
     let mut lengths = Vec::with_capacity(n_lines);
 
@@ -206,6 +278,7 @@ pub(crate) fn get_line_stats(
             delimiter,
             quote_char,
             eol_char,
+            truncated_rows,
         )?;
         bytes_trunc = &bytes_trunc[pos + 1..];
 
@@ -349,7 +422,7 @@ fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &[u8] {
 pub(super) fn parse_lines<'a>(
     mut bytes: &'a [u8],
     offset: usize,
-    delimiter: u8,
+    delimiter: &[u8],
     comment_char: Option<u8>,
     quote_char: Option<u8>,
     eol_char: u8,
@@ -383,9 +456,9 @@ pub(super) fn parse_lines<'a>(
         // only when we have one column \n should not be skipped
         // other widths should have commas.
         bytes = if schema_len > 1 {
-            skip_whitespace_line_ending_exclude(bytes, delimiter, eol_char)
+            skip_whitespace_line_ending_exclude(bytes, delimiter[0], eol_char)
         } else {
-            skip_whitespace_exclude(bytes, delimiter)
+            skip_whitespace_exclude(bytes, delimiter[0])
         };
         if bytes.is_empty() {
             return Ok(original_bytes_len);
@@ -423,6 +496,10 @@ pub(super) fn parse_lines<'a>(
 
                     // +1 is the split character that is consumed by the iterator.
                     read_sol += field_len + 1;
+                    // the rest of a multi-byte delimiter is consumed as well.
+                    if delimiter.len() > 1 && bytes.get(read_sol - 1) != Some(&eol_char) {
+                        read_sol += delimiter.len() - 1;
+                    }
 
                     if idx == next_projected as u32 {
                         // the iterator is finished when it encounters a `\n`
@@ -525,19 +602,49 @@ mod test {
     #[test]
     fn test_splitfields() {
         let input = "\"foo\",\"bar\"";
-        let mut fields = SplitFields::new(input.as_bytes(), b',', Some(b'"'), b'\n');
+        let mut fields = SplitFields::new(input.as_bytes(), b",", Some(b'"'), b'\n');
 
         assert_eq!(fields.next(), Some(("\"foo\"".as_bytes(), true)));
         assert_eq!(fields.next(), Some(("\"bar\"".as_bytes(), true)));
         assert_eq!(fields.next(), None);
 
         let input2 = "\"foo\n bar\";\"baz\";12345";
-        let mut fields2 = SplitFields::new(input2.as_bytes(), b';', Some(b'"'), b'\n');
+        let mut fields2 = SplitFields::new(input2.as_bytes(), b";", Some(b'"'), b'\n');
 
         assert_eq!(fields2.next(), Some(("\"foo\n bar\"".as_bytes(), true)));
         assert_eq!(fields2.next(), Some(("\"baz\"".as_bytes(), true)));
         assert_eq!(fields2.next(), Some(("12345".as_bytes(), false)));
         assert_eq!(fields2.next(), None);
+
+        let input3 = "\"a||b\"||c|d||||e\n";
+        let mut fields3 = SplitFields::new(input3.as_bytes(), b"||", Some(b'"'), b'\n');
+
+        assert_eq!(fields3.next(), Some(("\"a||b\"".as_bytes(), true)));
+        assert_eq!(fields3.next(), Some(("c|d".as_bytes(), false)));
+        assert_eq!(fields3.next(), Some(("".as_bytes(), false)));
+        assert_eq!(fields3.next(), Some(("e".as_bytes(), false)));
+        assert_eq!(fields3.next(), None);
+    }
+
+    #[test]
+    fn test_next_line_position_quoted() {
+        let input = b"1,\"a\nb\"\n2,\"c\"\"\n\"\n3,d\"\n4,e\n";
+        let pos = next_line_position_quoted(input, 0, b",", Some(b'"'), b'\n').unwrap();
+        assert_eq!(&input[pos..pos + 2], b"2,");
+        let pos = next_line_position_quoted(input, pos, b",", Some(b'"'), b'\n').unwrap();
+        assert_eq!(&input[pos..pos + 2], b"3,");
+        // a quote that doesn't start a field doesn't start quoting
+        let pos = next_line_position_quoted(input, pos, b",", Some(b'"'), b'\n').unwrap();
+        assert_eq!(&input[pos..pos + 2], b"4,");
+        // the last line ending is not a new line position
+        assert_eq!(
+            next_line_position_quoted(input, pos, b",", Some(b'"'), b'\n'),
+            None
+        );
+        assert_eq!(
+            next_line_position_quoted(input, 3, b",", None, b'\n'),
+            Some(5)
+        );
     }
 
     #[test]
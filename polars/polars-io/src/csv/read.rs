@@ -21,7 +21,8 @@ pub enum NullValues {
     AllColumnsSingle(String),
     /// Multiple values that are used for all columns
     AllColumns(Vec<String>),
-    /// Tuples that map column names to null value of that column
+    /// Tuples that map column names to null value of that column.
+    /// A column name may occur multiple times to set multiple null values for that column.
     Named(Vec<(String, String)>),
 }
 
@@ -30,8 +31,8 @@ pub(super) enum NullValuesCompiled {
     AllColumnsSingle(String),
     // Multiple null values that are null for all columns
     AllColumns(Vec<String>),
-    /// A different null value per column, computed from `NullValues::Named`
-    Columns(Vec<String>),
+    /// Different null values per column, computed from `NullValues::Named`
+    Columns(Vec<Vec<String>>),
 }
 
 impl NullValuesCompiled {
@@ -55,7 +56,7 @@ impl NullValuesCompiled {
             AllColumns(v) => v.iter().any(|v| v.as_bytes() == field),
             Columns(v) => {
                 debug_assert!(index < v.len());
-                v.get_unchecked(index).as_bytes() == field
+                v.get_unchecked(index).iter().any(|v| v.as_bytes() == field)
             }
         }
     }
@@ -67,10 +68,16 @@ impl NullValues {
             NullValues::AllColumnsSingle(v) => NullValuesCompiled::AllColumnsSingle(v),
             NullValues::AllColumns(v) => NullValuesCompiled::AllColumns(v),
             NullValues::Named(v) => {
-                let mut null_values = vec!["".to_string(); schema.len()];
+                let mut null_values = vec![vec![]; schema.len()];
                 for (name, null_value) in v {
                     let i = schema.try_index_of(&name)?;
-                    null_values[i] = null_value;
+                    null_values[i].push(null_value);
+                }
+                // columns without a given null value only treat empty fields as null
+                for nv in &mut null_values {
+                    if nv.is_empty() {
+                        nv.push("".to_string())
+                    }
                 }
                 NullValuesCompiled::Columns(null_values)
             }
@@ -111,7 +118,7 @@ where
     projection: Option<Vec<usize>>,
     /// Optional column names to project/ select.
     columns: Option<Vec<String>>,
-    delimiter: Option<u8>,
+    delimiter: Option<Vec<u8>>,
     has_header: bool,
     ignore_errors: bool,
     pub(crate) schema: Option<SchemaRef>,
@@ -132,6 +139,7 @@ where
     skip_rows_after_header: usize,
     try_parse_dates: bool,
     row_count: Option<RowCount>,
+    truncated_rows: bool,
 }
 
 impl<'a, R> CsvReader<'a, R>
@@ -204,7 +212,13 @@ where
 
     /// Set the CSV file's column delimiter as a byte character
     pub fn with_delimiter(mut self, delimiter: u8) -> Self {
-        self.delimiter = Some(delimiter);
+        self.delimiter = Some(vec![delimiter]);
+        self
+    }
+
+    /// Set the CSV file's column delimiter as one or more bytes, e.g. `"||"`.
+    pub fn with_multi_char_delimiter(mut self, delimiter: impl AsRef<[u8]>) -> Self {
+        self.delimiter = Some(delimiter.as_ref().to_vec());
         self
     }
 
@@ -232,6 +246,15 @@ where
         self
     }
 
+    /// Expect rows that have fewer fields than the schema. The missing fields are read as null.
+    ///
+    /// Such rows are always accepted, but setting this ensures that the file can still be split
+    /// into chunks that are parsed in parallel if many rows are truncated.
+    pub fn with_truncated_rows(mut self, toggle: bool) -> Self {
+        self.truncated_rows = toggle;
+        self
+    }
+
     /// Overwrite the schema with the dtypes in this given Schema. The given schema may be a subset
     /// of the total schema.
     pub fn with_dtypes(mut self, schema: Option<SchemaRef>) -> Self {
@@ -345,7 +368,7 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
             self.skip_rows_before_header,
             std::mem::take(&mut self.projection),
             self.max_records,
-            self.delimiter,
+            self.delimiter.clone(),
             self.has_header,
             self.ignore_errors,
             self.schema.clone(),
@@ -367,6 +390,7 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
             self.skip_rows_after_header,
             std::mem::take(&mut self.row_count),
             self.try_parse_dates,
+            self.truncated_rows,
         )
     }
 
@@ -442,7 +466,7 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
 
                 let (inferred_schema, _, _) = infer_file_schema(
                     &reader_bytes,
-                    self.delimiter.unwrap_or(b','),
+                    self.delimiter.as_deref().unwrap_or(b","),
                     self.max_records,
                     self.has_header,
                     None,
@@ -470,7 +494,7 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
 
                 let (inferred_schema, _, _) = infer_file_schema(
                     &reader_bytes,
-                    self.delimiter.unwrap_or(b','),
+                    self.delimiter.as_deref().unwrap_or(b","),
                     self.max_records,
                     self.has_header,
                     None,
@@ -524,6 +548,7 @@ where
             skip_rows_after_header: 0,
             try_parse_dates: false,
             row_count: None,
+            truncated_rows: false,
         }
     }
 
@@ -12,26 +12,25 @@ pub(crate) fn get_file_chunks_iterator(
     n_chunks: usize,
     chunk_size: usize,
     bytes: &[u8],
-    expected_fields: usize,
-    delimiter: u8,
+    delimiter: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
 ) {
     for _ in 0..n_chunks {
-        let search_pos = *last_pos + chunk_size;
-
-        if search_pos >= bytes.len() {
+        if *last_pos + chunk_size >= bytes.len() {
             break;
         }
 
-        let end_pos = match next_line_position(
-            &bytes[search_pos..],
-            Some(expected_fields),
+        // `last_pos` is always at the start of a line, so we can track
+        // quoted fields that contain new lines.
+        let end_pos = match next_line_position_quoted(
+            &bytes[*last_pos..],
+            chunk_size,
             delimiter,
             quote_char,
             eol_char,
         ) {
-            Some(pos) => search_pos + pos,
+            Some(pos) => *last_pos + pos,
             None => {
                 break;
             }
@@ -48,8 +47,7 @@ struct ChunkOffsetIter<'a> {
     n_chunks: usize,
     // not a promise, but something we want
     rows_per_batch: usize,
-    expected_fields: usize,
-    delimiter: u8,
+    delimiter: Vec<u8>,
     quote_char: Option<u8>,
     eol_char: u8,
 }
@@ -65,15 +63,14 @@ impl<'a> Iterator for ChunkOffsetIter<'a> {
                     return None;
                 }
                 let bytes_first_row = if self.rows_per_batch > 1 {
-                    let bytes_first_row = next_line_position(
-                        &self.bytes[self.last_offset + 2..],
-                        Some(self.expected_fields),
-                        self.delimiter,
+                    next_line_position_quoted(
+                        &self.bytes[self.last_offset..],
+                        0,
+                        &self.delimiter,
                         self.quote_char,
                         self.eol_char,
                     )
-                    .unwrap_or(1);
-                    bytes_first_row + 2
+                    .unwrap_or(1)
                 } else {
                     1
                 };
@@ -83,8 +80,7 @@ impl<'a> Iterator for ChunkOffsetIter<'a> {
                     self.n_chunks,
                     self.rows_per_batch * bytes_first_row,
                     self.bytes,
-                    self.expected_fields,
-                    self.delimiter,
+                    &self.delimiter,
                     self.quote_char,
                     self.eol_char,
                 );
@@ -122,8 +118,7 @@ impl<'a> CoreReader<'a> {
             last_offset: 0,
             n_chunks: offset_batch_size,
             rows_per_batch: self.chunk_size,
-            expected_fields: self.schema.len(),
-            delimiter: self.delimiter,
+            delimiter: self.delimiter.clone(),
             quote_char: self.quote_char,
             eol_char: self.eol_char,
         };
@@ -189,7 +184,7 @@ pub struct BatchedCsvReaderMmap<'a> {
     ignore_errors: bool,
     n_rows: Option<usize>,
     encoding: CsvEncoding,
-    delimiter: u8,
+    delimiter: Vec<u8>,
     schema: SchemaRef,
     rows_read: IdxSize,
     #[cfg(feature = "dtype-categorical")]
@@ -230,7 +225,7 @@ impl<'a> BatchedCsvReaderMmap<'a> {
                 .map(|(bytes_offset_thread, stop_at_nbytes)| {
                     let mut df = read_chunk(
                         bytes,
-                        self.delimiter,
+                        &self.delimiter,
                         self.schema.as_ref(),
                         self.ignore_errors,
                         &self.projection,
@@ -7,14 +7,12 @@ use crate::csv::CsvReader;
 use crate::mmap::MmapBytesReader;
 use crate::prelude::update_row_counts2;
 
-#[allow(clippy::too_many_arguments)]
 pub(crate) fn get_offsets(
     offsets: &mut VecDeque<(usize, usize)>,
     n_chunks: usize,
     chunk_size: usize,
     bytes: &[u8],
-    expected_fields: usize,
-    delimiter: u8,
+    delimiter: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
 ) {
@@ -26,14 +24,16 @@ pub(crate) fn get_offsets(
             break;
         }
 
-        let end_pos = match next_line_position(
-            &bytes[search_pos..],
-            Some(expected_fields),
+        // `start` is always at the start of a line, so we can track
+        // quoted fields that contain new lines.
+        let end_pos = match next_line_position_quoted(
+            &bytes[start..],
+            search_pos.saturating_sub(start),
             delimiter,
             quote_char,
             eol_char,
         ) {
-            Some(pos) => search_pos + pos,
+            Some(pos) => start + pos,
             None => {
                 break;
             }
@@ -56,8 +56,7 @@ struct ChunkReader<'a> {
     n_chunks: usize,
     // not a promise, but something we want
     rows_per_batch: usize,
-    expected_fields: usize,
-    delimiter: u8,
+    delimiter: Vec<u8>,
     quote_char: Option<u8>,
     eol_char: u8,
 }
@@ -66,8 +65,7 @@ impl<'a> ChunkReader<'a> {
     fn new(
         file: &'a File,
         rows_per_batch: usize,
-        expected_fields: usize,
-        delimiter: u8,
+        delimiter: Vec<u8>,
         quote_char: Option<u8>,
         eol_char: u8,
         page_size: u64,
@@ -84,7 +82,6 @@ impl<'a> ChunkReader<'a> {
             // otherwise the chunks are not deterministic
             n_chunks: 16,
             rows_per_batch,
-            expected_fields,
             delimiter,
             quote_char,
             eol_char,
@@ -127,31 +124,26 @@ impl<'a> ChunkReader<'a> {
         }
 
         let bytes_first_row = if self.rows_per_batch > 1 {
-            let mut bytes_first_row;
             loop {
-                bytes_first_row = next_line_position(
-                    &self.buf[2..],
-                    Some(self.expected_fields),
-                    self.delimiter,
+                if let Some(pos) = next_line_position_quoted(
+                    &self.buf,
+                    0,
+                    &self.delimiter,
                     self.quote_char,
                     self.eol_char,
-                );
-
-                if bytes_first_row.is_some() {
-                    break;
-                } else {
-                    let read = self
-                        .file
-                        .take(self.page_size)
-                        .read_to_end(&mut self.buf)
-                        .unwrap();
-                    if read == 0 {
-                        self.finished = true;
-                        return false;
-                    }
+                ) {
+                    break pos;
+                }
+                let read = self
+                    .file
+                    .take(self.page_size)
+                    .read_to_end(&mut self.buf)
+                    .unwrap();
+                if read == 0 {
+                    self.finished = true;
+                    return false;
                 }
             }
-            bytes_first_row.unwrap_or(1) + 2
         } else {
             1
         };
@@ -178,8 +170,7 @@ impl<'a> ChunkReader<'a> {
             self.n_chunks,
             self.rows_per_batch * bytes_first_row,
             &self.buf,
-            self.expected_fields,
-            self.delimiter,
+            &self.delimiter,
             self.quote_char,
             self.eol_char,
         );
@@ -202,8 +193,7 @@ impl<'a> CoreReader<'a> {
         let chunk_iter = ChunkReader::new(
             file,
             self.chunk_size,
-            self.schema.len(),
-            self.delimiter,
+            self.delimiter.clone(),
             self.quote_char,
             self.eol_char,
             4096,
@@ -270,7 +260,7 @@ pub struct BatchedCsvReaderRead<'a> {
     ignore_errors: bool,
     n_rows: Option<usize>,
     encoding: CsvEncoding,
-    delimiter: u8,
+    delimiter: Vec<u8>,
     schema: SchemaRef,
     rows_read: IdxSize,
     #[cfg(feature = "dtype-categorical")]
@@ -325,7 +315,7 @@ impl<'a> BatchedCsvReaderRead<'a> {
                     let stop_at_n_bytes = chunk.len();
                     let mut df = read_chunk(
                         chunk,
-                        self.delimiter,
+                        &self.delimiter,
                         self.schema.as_ref(),
                         self.ignore_errors,
                         &self.projection,
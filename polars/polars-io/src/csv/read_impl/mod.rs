@@ -89,7 +89,7 @@ pub(crate) struct CoreReader<'a> {
     encoding: CsvEncoding,
     n_threads: Option<usize>,
     has_header: bool,
-    delimiter: u8,
+    delimiter: Vec<u8>,
     sample_size: usize,
     chunk_size: usize,
     low_memory: bool,
@@ -101,6 +101,7 @@ pub(crate) struct CoreReader<'a> {
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     to_cast: Vec<Field>,
     row_count: Option<RowCount>,
+    truncated_rows: bool,
 }
 
 impl<'a> fmt::Debug for CoreReader<'a> {
@@ -169,7 +170,7 @@ impl<'a> CoreReader<'a> {
         mut skip_rows: usize,
         mut projection: Option<Vec<usize>>,
         max_records: Option<usize>,
-        delimiter: Option<u8>,
+        delimiter: Option<Vec<u8>>,
         has_header: bool,
         ignore_errors: bool,
         schema: Option<SchemaRef>,
@@ -191,6 +192,7 @@ impl<'a> CoreReader<'a> {
         skip_rows_after_header: usize,
         row_count: Option<RowCount>,
         try_parse_dates: bool,
+        truncated_rows: bool,
     ) -> PolarsResult<CoreReader<'a>> {
         #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
         let mut reader_bytes = reader_bytes;
@@ -203,8 +205,10 @@ impl<'a> CoreReader<'a> {
             );
         }
 
+        let delimiter = delimiter.unwrap_or_else(|| vec![b',']);
+        polars_ensure!(!delimiter.is_empty(), InvalidOperation: "CSV delimiter cannot be empty");
+
         // check if schema should be inferred
-        let delimiter = delimiter.unwrap_or(b',');
 
         let mut schema = match schema {
             Some(schema) => schema,
@@ -215,14 +219,14 @@ impl<'a> CoreReader<'a> {
                     // again after decompression.
                     #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
                     if let Some(b) =
-                        decompress(&reader_bytes, n_rows, delimiter, quote_char, eol_char)
+                        decompress(&reader_bytes, n_rows, &delimiter, quote_char, eol_char)
                     {
                         reader_bytes = ReaderBytes::Owned(b);
                     }
 
                     let (inferred_schema, _, _) = infer_file_schema(
                         &reader_bytes,
-                        delimiter,
+                        &delimiter,
                         max_records,
                         has_header,
                         schema_overwrite.as_deref(),
@@ -287,6 +291,7 @@ impl<'a> CoreReader<'a> {
             predicate,
             to_cast,
             row_count,
+            truncated_rows,
         })
     }
 
@@ -298,7 +303,7 @@ impl<'a> CoreReader<'a> {
         let starting_point_offset = bytes.as_ptr() as usize;
 
         // Skip all leading white space and the occasional utf8-bom
-        bytes = skip_whitespace_exclude(skip_bom(bytes), self.delimiter);
+        bytes = skip_whitespace_exclude(skip_bom(bytes), self.delimiter[0]);
         // \n\n can be a empty string row of a single column
         // in other cases we skip it.
         if self.schema.len() > 1 {
@@ -327,7 +332,14 @@ impl<'a> CoreReader<'a> {
                     // we don't pass expected fields
                     // as we want to skip all rows
                     // no matter the no. of fields
-                    _ => next_line_position(bytes, None, self.delimiter, self.quote_char, eol_char),
+                    _ => next_line_position(
+                        bytes,
+                        None,
+                        &self.delimiter,
+                        self.quote_char,
+                        eol_char,
+                        self.truncated_rows,
+                    ),
                 }
                 .ok_or_else(|| polars_err!(NoData: "not enough lines to skip"))?;
 
@@ -364,8 +376,9 @@ impl<'a> CoreReader<'a> {
             self.sample_size,
             self.eol_char,
             self.schema.len(),
-            self.delimiter,
+            &self.delimiter,
             self.quote_char,
+            self.truncated_rows,
         ) {
             if logging {
                 eprintln!("avg line length: {mean}\nstd. dev. line length: {std}");
@@ -388,9 +401,10 @@ impl<'a> CoreReader<'a> {
                     if let Some(pos) = next_line_position(
                         &bytes[n_bytes..],
                         Some(self.schema.len()),
-                        self.delimiter,
+                        &self.delimiter,
                         self.quote_char,
                         self.eol_char,
+                        self.truncated_rows,
                     ) {
                         if set_upper_bound {
                             (bytes, remaining_bytes) =
@@ -443,9 +457,10 @@ impl<'a> CoreReader<'a> {
             bytes,
             n_file_chunks,
             self.schema.len(),
-            self.delimiter,
+            &self.delimiter,
             self.quote_char,
             self.eol_char,
+            self.truncated_rows,
         );
 
         if logging {
@@ -575,7 +590,7 @@ impl<'a> CoreReader<'a> {
                 file_chunks
                     .into_par_iter()
                     .map(|(bytes_offset_thread, stop_at_nbytes)| {
-                        let delimiter = self.delimiter;
+                        let delimiter = &self.delimiter;
                         let schema = self.schema.as_ref();
                         let ignore_errors = self.ignore_errors;
                         let projection = &projection;
@@ -670,7 +685,7 @@ impl<'a> CoreReader<'a> {
                     .map(|(bytes_offset_thread, stop_at_nbytes)| {
                         let mut df = read_chunk(
                             bytes,
-                            self.delimiter,
+                            &self.delimiter,
                             self.schema.as_ref(),
                             self.ignore_errors,
                             &projection,
@@ -721,7 +736,7 @@ impl<'a> CoreReader<'a> {
                             parse_lines(
                                 remaining_bytes,
                                 0,
-                                self.delimiter,
+                                &self.delimiter,
                                 self.comment_char,
                                 self.quote_char,
                                 self.eol_char,
@@ -798,7 +813,7 @@ fn update_string_stats(
 #[allow(clippy::too_many_arguments)]
 fn read_chunk(
     bytes: &[u8],
-    delimiter: u8,
+    delimiter: &[u8],
     schema: &Schema,
     ignore_errors: bool,
     projection: &[usize],
@@ -2,25 +2,29 @@
 mod inner {
     /// An adapted version of std::iter::Split.
     /// This exists solely because we cannot split the lines naively as
-    pub(crate) struct SplitFields<'a> {
+    pub(crate) struct SplitFields<'a, 'b> {
         v: &'a [u8],
         delimiter: u8,
+        // the bytes of a multi-byte delimiter after the first one
+        delimiter_tail: &'b [u8],
         finished: bool,
         quote_char: u8,
         quoting: bool,
         eol_char: u8,
     }
 
-    impl<'a> SplitFields<'a> {
+    impl<'a, 'b> SplitFields<'a, 'b> {
         pub(crate) fn new(
             slice: &'a [u8],
-            delimiter: u8,
+            delimiter: &'b [u8],
             quote_char: Option<u8>,
             eol_char: u8,
         ) -> Self {
+            let (&first, delimiter_tail) = delimiter.split_first().expect("empty delimiter");
             Self {
                 v: slice,
-                delimiter,
+                delimiter: first,
+                delimiter_tail,
                 finished: false,
                 quote_char: quote_char.unwrap_or(b'"'),
                 quoting: quote_char.is_some(),
@@ -46,9 +50,15 @@ mod inner {
         fn eof_oel(&self, current_ch: u8) -> bool {
             current_ch == self.delimiter || current_ch == self.eol_char
         }
+
+        /// Whether the whole delimiter starts at `idx`, which holds its first byte.
+        #[inline]
+        fn is_delimiter_at(&self, idx: usize) -> bool {
+            self.delimiter_tail.is_empty() || self.v[idx + 1..].starts_with(self.delimiter_tail)
+        }
     }
 
-    impl<'a> Iterator for SplitFields<'a> {
+    impl<'a, 'b> Iterator for SplitFields<'a, 'b> {
         // the bool is used to indicate that it requires escaping
         type Item = (&'a [u8], bool);
 
@@ -94,8 +104,10 @@ mod inner {
                                 self.finish_eol(needs_escaping, current_idx as usize)
                             };
                         }
-                        idx = current_idx;
-                        break;
+                        if self.is_delimiter_at(current_idx as usize) {
+                            idx = current_idx;
+                            break;
+                        }
                     }
                     current_idx += 1;
                 }
@@ -106,17 +118,23 @@ mod inner {
 
                 idx as usize
             } else {
-                match self.v.iter().position(|&c| self.eof_oel(c)) {
-                    None => return self.finish(needs_escaping),
-                    Some(idx) => unsafe {
-                        // Safety:
-                        // idx was just found
-                        if *self.v.get_unchecked(idx) == self.eol_char {
-                            return self.finish_eol(needs_escaping, idx);
-                        } else {
-                            idx
-                        }
-                    },
+                let mut start = 0;
+                loop {
+                    match self.v[start..].iter().position(|&c| self.eof_oel(c)) {
+                        None => return self.finish(needs_escaping),
+                        Some(idx) => unsafe {
+                            let idx = start + idx;
+                            // Safety:
+                            // idx was just found
+                            if *self.v.get_unchecked(idx) == self.eol_char {
+                                return self.finish_eol(needs_escaping, idx);
+                            } else if self.is_delimiter_at(idx) {
+                                break idx;
+                            }
+                            // only the first byte of the delimiter
+                            start = idx + 1;
+                        },
+                    }
                 }
             };
 
@@ -125,7 +143,7 @@ mod inner {
                 // safety
                 // we are in bounds
                 let ret = Some((self.v.get_unchecked(..pos), needs_escaping));
-                self.v = self.v.get_unchecked(pos + 1..);
+                self.v = self.v.get_unchecked(pos + 1 + self.delimiter_tail.len()..);
                 ret
             }
         }
@@ -155,9 +173,11 @@ mod inner {
 
     /// An adapted version of std::iter::Split.
     /// This exists solely because we cannot split the lines naively as
-    pub(crate) struct SplitFields<'a> {
+    pub(crate) struct SplitFields<'a, 'b> {
         pub v: &'a [u8],
         delimiter: u8,
+        // the bytes of a multi-byte delimiter after the first one
+        delimiter_tail: &'b [u8],
         pub finished: bool,
         quote_char: u8,
         quoting: bool,
@@ -166,19 +186,21 @@ mod inner {
         simd_eol_char: SimdVec,
     }
 
-    impl<'a> SplitFields<'a> {
+    impl<'a, 'b> SplitFields<'a, 'b> {
         pub(crate) fn new(
             slice: &'a [u8],
-            delimiter: u8,
+            delimiter: &'b [u8],
             quote_char: Option<u8>,
             eol_char: u8,
         ) -> Self {
-            let simd_delimiter = SimdVec::splat(delimiter);
+            let (&first, delimiter_tail) = delimiter.split_first().expect("empty delimiter");
+            let simd_delimiter = SimdVec::splat(first);
             let simd_eol_char = SimdVec::splat(eol_char);
 
             Self {
                 v: slice,
-                delimiter,
+                delimiter: first,
+                delimiter_tail,
                 finished: false,
                 quote_char: quote_char.unwrap_or(b'"'),
                 quoting: quote_char.is_some(),
@@ -206,9 +228,15 @@ mod inner {
         fn eof_oel(&self, current_ch: u8) -> bool {
             current_ch == self.delimiter || current_ch == self.eol_char
         }
+
+        /// Whether the whole delimiter starts at `idx`, which holds its first byte.
+        #[inline]
+        fn is_delimiter_at(&self, idx: usize) -> bool {
+            self.delimiter_tail.is_empty() || self.v[idx + 1..].starts_with(self.delimiter_tail)
+        }
     }
 
-    impl<'a> Iterator for SplitFields<'a> {
+    impl<'a, 'b> Iterator for SplitFields<'a, 'b> {
         // the bool is used to indicate that it requires escaping
         type Item = (&'a [u8], bool);
 
@@ -254,8 +282,10 @@ mod inner {
                                 self.finish_eol(needs_escaping, current_idx as usize)
                             };
                         }
-                        idx = current_idx;
-                        break;
+                        if self.is_delimiter_at(current_idx as usize) {
+                            idx = current_idx;
+                            break;
+                        }
                     }
                     current_idx += 1;
                 }
@@ -268,7 +298,7 @@ mod inner {
             } else {
                 let mut total_idx = 0;
 
-                loop {
+                'search: loop {
                     let bytes = unsafe { self.v.get_unchecked_release(total_idx..) };
 
                     if bytes.len() > SIMD_SIZE {
@@ -288,9 +318,9 @@ mod inner {
                                     [bool; SIMD_SIZE],
                                 >(has_any);
                                 total_idx += simple_argmax(&has_any);
-                                break;
                             } else {
                                 total_idx += SIMD_SIZE;
+                                continue;
                             }
                         }
                     } else {
@@ -298,17 +328,18 @@ mod inner {
                             None => return self.finish(needs_escaping),
                             Some(idx) => {
                                 total_idx += idx;
-                                break;
                             }
                         }
                     }
-                }
-                unsafe {
-                    if *self.v.get_unchecked_release(total_idx) == self.eol_char {
-                        return self.finish_eol(needs_escaping, total_idx);
-                    } else {
-                        total_idx
+                    unsafe {
+                        if *self.v.get_unchecked_release(total_idx) == self.eol_char {
+                            return self.finish_eol(needs_escaping, total_idx);
+                        } else if self.is_delimiter_at(total_idx) {
+                            break 'search total_idx;
+                        }
                     }
+                    // only the first byte of the delimiter
+                    total_idx += 1;
                 }
             };
 
@@ -317,7 +348,7 @@ mod inner {
                 // safety
                 // we are in bounds
                 let ret = Some((self.v.get_unchecked(..pos), needs_escaping));
-                self.v = self.v.get_unchecked(pos + 1..);
+                self.v = self.v.get_unchecked(pos + 1 + self.delimiter_tail.len()..);
                 ret
             }
         }
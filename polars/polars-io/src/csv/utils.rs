@@ -23,9 +23,10 @@ pub(crate) fn get_file_chunks(
     bytes: &[u8],
     n_chunks: usize,
     expected_fields: usize,
-    delimiter: u8,
+    delimiter: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
+    truncated_rows: bool,
 ) -> Vec<(usize, usize)> {
     let mut last_pos = 0;
     let total_len = bytes.len();
@@ -44,6 +45,7 @@ pub(crate) fn get_file_chunks(
             delimiter,
             quote_char,
             eol_char,
+            truncated_rows,
         ) {
             Some(pos) => search_pos + pos,
             None => {
@@ -177,7 +179,7 @@ pub(crate) fn parse_bytes_with_encoding(
 #[allow(clippy::too_many_arguments)]
 pub fn infer_file_schema(
     reader_bytes: &ReaderBytes,
-    delimiter: u8,
+    delimiter: &[u8],
     max_read_rows: Option<usize>,
     has_header: bool,
     schema_overwrite: Option<&Schema>,
@@ -191,6 +193,7 @@ pub fn infer_file_schema(
     null_values: Option<&NullValues>,
     try_parse_dates: bool,
 ) -> PolarsResult<(Schema, usize, usize)> {
+    polars_ensure!(!delimiter.is_empty(), InvalidOperation: "CSV delimiter cannot be empty");
     // keep track so that we can determine the amount of bytes read
     let start_ptr = reader_bytes.as_ptr() as usize;
 
@@ -273,7 +276,7 @@ pub fn infer_file_schema(
                 .collect();
             // needed because SplitLines does not return the \n char, so SplitFields does not catch
             // the latest value if ending with a delimiter.
-            if header_line.ends_with(&[delimiter]) {
+            if header_line.ends_with(delimiter) {
                 column_names.push(format!("column_{}", column_names.len() + 1))
             }
             column_names
@@ -386,13 +389,11 @@ pub fn infer_file_schema(
                         }
                         Some(NullValues::Named(names)) => {
                             let current_name = &headers[i];
-                            let null_name = &names.iter().find(|name| &name.0 == current_name);
-
-                            if let Some(null_name) = null_name {
-                                if null_name.1 != s.as_ref() {
-                                    column_types[i].insert(infer_field_schema(&s, try_parse_dates));
-                                }
-                            } else {
+                            // a column can have multiple null values
+                            if !names
+                                .iter()
+                                .any(|(name, nv)| name == current_name && nv == s.as_ref())
+                            {
                                 column_types[i].insert(infer_field_schema(&s, try_parse_dates));
                             }
                         }
@@ -495,6 +496,7 @@ const GZIP: [u8; 2] = [31, 139];
 const ZLIB0: [u8; 2] = [0x78, 0x01];
 const ZLIB1: [u8; 2] = [0x78, 0x9C];
 const ZLIB2: [u8; 2] = [0x78, 0xDA];
+const ZSTD: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
 
 /// check if csv file is compressed
 pub fn is_compressed(bytes: &[u8]) -> bool {
@@ -502,13 +504,14 @@ pub fn is_compressed(bytes: &[u8]) -> bool {
         || bytes.starts_with(&ZLIB1)
         || bytes.starts_with(&ZLIB2)
         || bytes.starts_with(&GZIP)
+        || bytes.starts_with(&ZSTD)
 }
 
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
 fn decompress_impl<R: Read>(
     decoder: &mut R,
     n_rows: Option<usize>,
-    delimiter: u8,
+    delimiter: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Option<Vec<u8>> {
@@ -556,6 +559,7 @@ fn decompress_impl<R: Read>(
                     delimiter,
                     quote_char,
                     eol_char,
+                    false,
                 ) {
                     Some(pos) => {
                         line_count += 1;
@@ -581,7 +585,7 @@ fn decompress_impl<R: Read>(
 pub(crate) fn decompress(
     bytes: &[u8],
     n_rows: Option<usize>,
-    delimiter: u8,
+    delimiter: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Option<Vec<u8>> {
@@ -591,6 +595,9 @@ pub(crate) fn decompress(
     } else if bytes.starts_with(&ZLIB0) || bytes.starts_with(&ZLIB1) || bytes.starts_with(&ZLIB2) {
         let mut decoder = flate2::read::ZlibDecoder::new(bytes);
         decompress_impl(&mut decoder, n_rows, delimiter, quote_char, eol_char)
+    } else if bytes.starts_with(&ZSTD) {
+        let mut decoder = zstd::Decoder::with_buffer(bytes).ok()?;
+        decompress_impl(&mut decoder, n_rows, delimiter, quote_char, eol_char)
     } else {
         None
     }
@@ -658,7 +665,11 @@ mod test {
         let s = std::fs::read_to_string(path).unwrap();
         let bytes = s.as_bytes();
         // can be within -1 / +1 bounds.
-        assert!((get_file_chunks(bytes, 10, 4, b',', None, b'\n').len() as i32 - 10).abs() <= 1);
-        assert!((get_file_chunks(bytes, 8, 4, b',', None, b'\n').len() as i32 - 8).abs() <= 1);
+        assert!(
+            (get_file_chunks(bytes, 10, 4, b",", None, b'\n', false).len() as i32 - 10).abs() <= 1
+        );
+        assert!(
+            (get_file_chunks(bytes, 8, 4, b",", None, b'\n', false).len() as i32 - 8).abs() <= 1
+        );
     }
 }
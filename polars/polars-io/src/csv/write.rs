@@ -9,6 +9,10 @@ pub struct CsvWriter<W: Write> {
     buffer: W,
     options: write_impl::SerializeOptions,
     header: bool,
+    bom: bool,
+    header_only: bool,
+    append: bool,
+    compression: CsvCompression,
     batch_size: usize,
 }
 
@@ -27,16 +31,30 @@ where
             buffer,
             options,
             header: true,
+            bom: false,
+            header_only: false,
+            append: false,
+            compression: CsvCompression::Uncompressed,
             batch_size: 1024,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
         let names = df.get_column_names();
-        if self.header {
-            write_impl::write_header(&mut self.buffer, &names, &self.options)?;
-        }
-        write_impl::write(&mut self.buffer, df, self.batch_size, &self.options)
+        with_compression(&mut self.buffer, self.compression, |writer| {
+            if !self.append {
+                if self.bom {
+                    write_impl::write_bom(writer)?;
+                }
+                if self.header {
+                    write_impl::write_header(writer, &names, &self.options)?;
+                }
+            }
+            if !self.header_only {
+                write_impl::write(writer, df, self.batch_size, &self.options)?;
+            }
+            Ok(())
+        })
     }
 }
 
@@ -104,4 +122,129 @@ where
         self.options.null = null_value;
         self
     }
+
+    /// Set when fields are surrounded with the quoting character
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.options.quote_style = quote_style;
+        self
+    }
+
+    /// Set the string that is written at the end of every line, e.g. `"\r\n"`
+    pub fn with_line_terminator(mut self, line_terminator: String) -> Self {
+        self.options.line_terminator = line_terminator;
+        self
+    }
+
+    /// Set whether to start the output with a UTF-8 byte order mark
+    pub fn include_bom(mut self, include_bom: bool) -> Self {
+        self.bom = include_bom;
+        self
+    }
+
+    /// Only write the header (and byte order mark), the rows are skipped
+    pub fn header_only(mut self, header_only: bool) -> Self {
+        self.header_only = header_only;
+        self
+    }
+
+    /// Append the rows to existing CSV data, the header and byte order mark
+    /// are not written as the data should already start with them
+    pub fn append(mut self, append: bool) -> Self {
+        self.append = append;
+        self
+    }
+
+    /// Compress the output with gzip or zstd.
+    ///
+    /// Every write of a [`BatchedWriter`] is compressed as a separate gzip member or
+    /// zstd frame. Decoders read these concatenated members as a single stream.
+    pub fn with_compression(mut self, compression: CsvCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Write the header (if enabled) for `schema` and return a writer to which
+    /// [`DataFrame`]s can be written in batches.
+    pub fn batched(mut self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        if !self.append && (self.bom || self.header) {
+            let names = schema
+                .iter_names()
+                .map(|name| name.as_str())
+                .collect::<Vec<_>>();
+            with_compression(&mut self.buffer, self.compression, |writer| {
+                if self.bom {
+                    write_impl::write_bom(writer)?;
+                }
+                if self.header {
+                    write_impl::write_header(writer, &names, &self.options)?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(BatchedWriter { writer: self })
+    }
+}
+
+pub struct BatchedWriter<W: Write> {
+    writer: CsvWriter<W>,
+}
+
+impl<W: Write> BatchedWriter<W> {
+    /// Write a batch to the csv writer.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let writer = &mut self.writer;
+        if writer.header_only || df.height() == 0 {
+            return Ok(());
+        }
+        with_compression(&mut writer.buffer, writer.compression, |buffer| {
+            write_impl::write(buffer, df, writer.batch_size, &writer.options)
+        })
+    }
+
+    /// Flush the underlying writer.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        self.writer.buffer.flush()?;
+        Ok(())
+    }
+}
+
+/// Pass `writer` to `write`, wrapped in an encoder if the output is compressed.
+fn with_compression<W: Write>(
+    writer: &mut W,
+    compression: CsvCompression,
+    write: impl FnOnce(&mut dyn Write) -> PolarsResult<()>,
+) -> PolarsResult<()> {
+    match compression {
+        CsvCompression::Uncompressed => write(writer),
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        CsvCompression::Gzip(level) => {
+            let level = match level {
+                Some(level) => {
+                    polars_ensure!(
+                        level <= 9,
+                        ComputeError: "gzip compression level must be between 0 and 9, got {}", level,
+                    );
+                    flate2::Compression::new(level)
+                }
+                None => flate2::Compression::default(),
+            };
+            let mut encoder = flate2::write::GzEncoder::new(writer, level);
+            write(&mut encoder)?;
+            encoder.finish()?;
+            Ok(())
+        }
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        CsvCompression::Zstd(level) => {
+            let level = level.unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL);
+            let mut encoder = zstd::Encoder::new(writer, level)?;
+            write(&mut encoder)?;
+            encoder.finish()?;
+            Ok(())
+        }
+        #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+        _ => polars_bail!(
+            ComputeError: "cannot write compressed CSV file; \
+            compile with feature 'decompress' or 'decompress-fast'"
+        ),
+    }
 }
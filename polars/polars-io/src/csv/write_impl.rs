@@ -11,37 +11,60 @@ use chrono::TimeZone;
 #[cfg(feature = "timezones")]
 use chrono_tz::Tz;
 use lexical_core::{FormattedSize, ToLexical};
-use memchr::{memchr, memchr2};
+use memchr::{memchr, memchr3};
 use polars_core::prelude::*;
 use polars_core::series::SeriesIter;
 use polars_core::POOL;
 use polars_utils::contention_pool::LowContentionPool;
 use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 fn fmt_and_escape_str(f: &mut Vec<u8>, v: &str, options: &SerializeOptions) -> std::io::Result<()> {
+    if options.quote_style == QuoteStyle::Never {
+        return write!(f, "{v}");
+    }
+    let quote = char::from(options.quote);
     if v.is_empty() {
-        write!(f, "\"\"")
-    } else {
-        let needs_escaping = memchr(options.quote, v.as_bytes()).is_some();
+        return write!(f, "{quote}{quote}");
+    }
+    let needs_escaping = memchr(options.quote, v.as_bytes()).is_some();
 
-        if needs_escaping {
-            let replaced = unsafe {
-                // replace from single quote "
-                // to double quote ""
-                v.replace(
-                    std::str::from_utf8_unchecked(&[options.quote]),
-                    std::str::from_utf8_unchecked(&[options.quote, options.quote]),
-                )
-            };
-            return write!(f, "\"{replaced}\"");
-        }
-        let surround_with_quotes = memchr2(options.delimiter, b'\n', v.as_bytes()).is_some();
+    if needs_escaping {
+        let replaced = unsafe {
+            // replace from single quote "
+            // to double quote ""
+            v.replace(
+                std::str::from_utf8_unchecked(&[options.quote]),
+                std::str::from_utf8_unchecked(&[options.quote, options.quote]),
+            )
+        };
+        return write!(f, "{quote}{replaced}{quote}");
+    }
+    let surround_with_quotes = match options.quote_style {
+        QuoteStyle::Always | QuoteStyle::NonNumeric => true,
+        _ => memchr3(options.delimiter, b'\n', b'\r', v.as_bytes()).is_some(),
+    };
 
-        if surround_with_quotes {
-            write!(f, "\"{v}\"")
-        } else {
-            write!(f, "{v}")
-        }
+    if surround_with_quotes {
+        write!(f, "{quote}{v}{quote}")
+    } else {
+        write!(f, "{v}")
+    }
+}
+
+/// Whether a value that is not a string is surrounded with quotes. Strings are
+/// quoted by [`fmt_and_escape_str`] and nulls are never quoted.
+fn quote_non_str(value: &AnyValue, quote_style: QuoteStyle) -> bool {
+    match value {
+        AnyValue::Null | AnyValue::Utf8(_) => false,
+        #[cfg(feature = "dtype-categorical")]
+        AnyValue::Categorical(..) => false,
+        _ => match quote_style {
+            QuoteStyle::Always => true,
+            QuoteStyle::NonNumeric => !value.dtype().is_numeric(),
+            QuoteStyle::Necessary | QuoteStyle::Never => false,
+        },
     }
 }
 
@@ -62,6 +85,10 @@ fn write_anyvalue(
     options: &SerializeOptions,
     #[allow(unused_variables)] datetime_format: Option<&str>,
 ) -> PolarsResult<()> {
+    let quote = quote_non_str(&value, options.quote_style);
+    if quote {
+        f.push(options.quote);
+    }
     match value {
         AnyValue::Null => write!(f, "{}", &options.null),
         AnyValue::Int8(v) => write!(f, "{v}"),
@@ -146,12 +173,45 @@ fn write_anyvalue(
             )
         }
         _ => polars_err!(ComputeError: "error writing value {}: {}", value, err),
-    })
+    })?;
+    if quote {
+        f.push(options.quote);
+    }
+    Ok(())
+}
+
+/// Quote style indicating when to insert quotes around a field.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum QuoteStyle {
+    /// Quote fields only when necessary: when they contain the delimiter, the quoting
+    /// character or a line break, or when they are an empty string.
+    #[default]
+    Necessary,
+    /// Quote every field, except for nulls.
+    Always,
+    /// Quote every field that is not numeric, except for nulls.
+    NonNumeric,
+    /// Never quote fields, even if that results in invalid CSV data.
+    Never,
+}
+
+/// Compression of the CSV output.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CsvCompression {
+    #[default]
+    Uncompressed,
+    /// gzip with a compression level from 0 to 9.
+    Gzip(Option<u32>),
+    /// zstd with a compression level from 1 to 22.
+    Zstd(Option<i32>),
 }
 
 /// Options to serialize logical types to CSV
 /// The default is to format times and dates as `chrono` crate formats them.
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SerializeOptions {
     /// used for [`DataType::Date`]
     pub date_format: Option<String>,
@@ -167,6 +227,10 @@ pub struct SerializeOptions {
     pub quote: u8,
     /// null value representation
     pub null: String,
+    /// when to quote fields
+    pub quote_style: QuoteStyle,
+    /// written at the end of every line
+    pub line_terminator: String,
 }
 
 impl Default for SerializeOptions {
@@ -179,6 +243,8 @@ impl Default for SerializeOptions {
             delimiter: b',',
             quote: b'"',
             null: String::new(),
+            quote_style: QuoteStyle::default(),
+            line_terminator: "\n".into(),
         }
     }
 }
@@ -193,7 +259,7 @@ impl<'a> std::fmt::Write for StringWrap<'a> {
     }
 }
 
-pub(crate) fn write<W: Write>(
+pub(crate) fn write<W: Write + ?Sized>(
     writer: &mut W,
     df: &DataFrame,
     chunk_size: usize,
@@ -306,7 +372,7 @@ pub(crate) fn write<W: Write>(
                     }
                 }
                 if !finished {
-                    writeln!(&mut write_buffer).unwrap();
+                    write_buffer.extend_from_slice(options.line_terminator.as_bytes());
                 }
             }
 
@@ -333,16 +399,26 @@ pub(crate) fn write<W: Write>(
     Ok(())
 }
 /// Writes a CSV header to `writer`
-pub(crate) fn write_header<W: Write>(
+pub(crate) fn write_header<W: Write + ?Sized>(
     writer: &mut W,
     names: &[&str],
     options: &SerializeOptions,
 ) -> PolarsResult<()> {
-    writer.write_all(
-        names
-            .join(std::str::from_utf8(&[options.delimiter]).unwrap())
-            .as_bytes(),
-    )?;
-    writer.write_all(&[b'\n'])?;
+    let mut header = Vec::new();
+    for (i, name) in names.iter().enumerate() {
+        if i > 0 {
+            header.push(options.delimiter);
+        }
+        fmt_and_escape_str(&mut header, name, options)?;
+    }
+    header.extend_from_slice(options.line_terminator.as_bytes());
+    writer.write_all(&header)?;
+    Ok(())
+}
+
+/// Writes a UTF-8 byte order mark to `writer`
+pub(crate) fn write_bom<W: Write + ?Sized>(writer: &mut W) -> PolarsResult<()> {
+    const BOM: [u8; 3] = [0xEF, 0xBB, 0xBF];
+    writer.write_all(&BOM)?;
     Ok(())
 }
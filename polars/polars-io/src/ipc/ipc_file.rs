@@ -32,12 +32,14 @@
 //! let df_read = IpcReader::new(buf).finish().unwrap();
 //! assert!(df.frame_equal(&df_read));
 //! ```
+use std::collections::VecDeque;
 use std::io::{Read, Seek};
 use std::sync::Arc;
 
 use arrow::io::ipc::read;
 use polars_core::frame::ArrowChunk;
 use polars_core::prelude::*;
+use polars_core::utils::split_df;
 
 use super::{finish_reader, ArrowReader, ArrowResult};
 use crate::mmap::MmapBytesReader;
@@ -181,6 +183,75 @@ impl<R: MmapBytesReader> IpcReader<R> {
     }
 }
 
+impl<R: MmapBytesReader> IpcReader<R> {
+    /// Read the file in batches of at most `chunk_size` rows, see [`BatchedIpcReader`].
+    pub fn batched(mut self, chunk_size: usize) -> PolarsResult<BatchedIpcReader<R>> {
+        let metadata = read::read_file_metadata(&mut self.reader)?;
+        if let Some(columns) = &self.columns {
+            let prj = columns_to_projection(columns, &metadata.schema)?;
+            self.projection = Some(prj);
+        }
+        let schema = if let Some(projection) = &self.projection {
+            apply_projection(&metadata.schema, projection)
+        } else {
+            metadata.schema.clone()
+        };
+        let reader = read::FileReader::new(self.reader, metadata, self.projection, self.n_rows);
+
+        Ok(BatchedIpcReader {
+            reader,
+            schema,
+            row_count: self.row_count,
+            rows_read: 0,
+            chunk_size,
+            chunks_fifo: VecDeque::new(),
+        })
+    }
+}
+
+/// Reads the record batches of an IPC file one by one. Record batches that are
+/// larger than the chunk size are split, so that a file that was written as a
+/// single record batch doesn't have to be loaded at once.
+pub struct BatchedIpcReader<R: MmapBytesReader> {
+    reader: read::FileReader<R>,
+    schema: ArrowSchema,
+    row_count: Option<RowCount>,
+    rows_read: IdxSize,
+    chunk_size: usize,
+    chunks_fifo: VecDeque<DataFrame>,
+}
+
+impl<R: MmapBytesReader> BatchedIpcReader<R> {
+    pub fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
+        // fill up fifo stack
+        while self.chunks_fifo.len() < n {
+            let Some(batch) = self.reader.next_record_batch()? else {
+                break;
+            };
+            let mut df = DataFrame::try_from((batch, self.schema.fields.as_slice()))?;
+            if let Some(rc) = &self.row_count {
+                df.with_row_count_mut(&rc.name, Some(self.rows_read + rc.offset));
+            }
+            self.rows_read += df.height() as IdxSize;
+
+            // make sure that the chunks are not too large
+            let n = df.height() / self.chunk_size;
+            if n > 1 {
+                self.chunks_fifo.extend(split_df(&mut df, n)?)
+            } else {
+                self.chunks_fifo.push_back(df)
+            }
+        }
+
+        if self.chunks_fifo.is_empty() {
+            Ok(None)
+        } else {
+            let n = std::cmp::min(n, self.chunks_fifo.len());
+            Ok(Some(self.chunks_fifo.drain(..n).collect()))
+        }
+    }
+}
+
 impl<R: MmapBytesReader> ArrowReader for read::FileReader<R>
 where
     R: Read + Seek,
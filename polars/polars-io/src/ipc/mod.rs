@@ -12,7 +12,7 @@ mod write;
 mod write_async;
 
 #[cfg(feature = "ipc")]
-pub use ipc_file::IpcReader;
+pub use ipc_file::{BatchedIpcReader, IpcReader};
 #[cfg(feature = "ipc_streaming")]
 pub use ipc_stream::*;
 pub use write::{BatchedWriter, IpcCompression, IpcWriter, IpcWriterOption};
@@ -17,6 +17,8 @@ pub mod json;
 pub mod ndjson_core;
 #[cfg(feature = "cloud")]
 pub use crate::cloud::glob as async_glob;
+#[cfg(feature = "cloud")]
+pub use crate::cloud::CloudWriter;
 
 #[cfg(any(
     feature = "csv-file",
@@ -173,7 +175,9 @@ pub(crate) fn finish_reader<R: ArrowReader>(
 
 /// Check if the path is a cloud url.
 pub fn is_cloud_url<P: AsRef<Path>>(p: P) -> bool {
-    p.as_ref().starts_with("s3://")
-        || p.as_ref().starts_with("file://")
-        || p.as_ref().starts_with("gcs://")
+    [
+        "s3://", "file://", "gs://", "gcs://", "gcp://", "az://", "adl://", "abfs://",
+    ]
+    .iter()
+    .any(|scheme| p.as_ref().starts_with(scheme))
 }
@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 use std::fs::File;
-use std::io::Cursor;
+use std::io::{BufRead, Cursor};
 use std::path::PathBuf;
 
 pub use arrow::array::StructArray;
@@ -243,6 +243,83 @@ impl<'a> CoreJsonReader<'a> {
     }
 }
 
+/// Reads a newline delimited json file in batches of `chunk_size` lines, so that
+/// only the lines of the batches that are being parsed need to be in memory.
+pub struct BatchedJsonLineReader<R: BufRead> {
+    reader: R,
+    schema: SchemaRef,
+    chunk_size: usize,
+    rows_left: usize,
+}
+
+impl<R: BufRead> BatchedJsonLineReader<R> {
+    pub fn new(reader: R, schema: SchemaRef, chunk_size: usize, n_rows: Option<usize>) -> Self {
+        BatchedJsonLineReader {
+            reader,
+            schema,
+            chunk_size: std::cmp::max(chunk_size, 1),
+            rows_left: n_rows.unwrap_or(usize::MAX),
+        }
+    }
+
+    /// Read the next `n` chunks of lines from the reader.
+    fn read_chunks(&mut self, n: usize) -> PolarsResult<Vec<(Vec<u8>, usize)>> {
+        let mut chunks = Vec::with_capacity(n);
+        while chunks.len() < n && self.rows_left > 0 {
+            let max_lines = std::cmp::min(self.chunk_size, self.rows_left);
+            let mut bytes = vec![];
+            let mut n_lines = 0;
+            while n_lines < max_lines {
+                let start = bytes.len();
+                if self.reader.read_until(NEWLINE, &mut bytes)? == 0 {
+                    break;
+                }
+                // blank lines don't produce a row
+                if bytes[start..].iter().all(|b| b.is_ascii_whitespace()) {
+                    bytes.truncate(start);
+                } else {
+                    n_lines += 1;
+                }
+            }
+            if n_lines == 0 {
+                break;
+            }
+            self.rows_left -= n_lines;
+            chunks.push((bytes, n_lines));
+        }
+        Ok(chunks)
+    }
+
+    pub fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
+        let chunks = self.read_chunks(n)?;
+        if chunks.is_empty() {
+            return Ok(None);
+        }
+        let schema = &self.schema;
+        let dfs = POOL.install(|| {
+            chunks
+                .into_par_iter()
+                .map(|(bytes, n_lines)| {
+                    let mut buffers = init_buffers(schema, n_lines)?;
+                    let mut buf = vec![];
+                    for line in bytes.split(|b| *b == NEWLINE) {
+                        if !line.iter().all(|b| b.is_ascii_whitespace()) {
+                            parse_impl(line, &mut buffers, &mut buf)?;
+                        }
+                    }
+                    DataFrame::new(
+                        buffers
+                            .into_values()
+                            .map(|buf| buf.into_series())
+                            .collect::<_>(),
+                    )
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        Ok(Some(dfs))
+    }
+}
+
 #[inline(always)]
 fn parse_impl(
     bytes: &[u8],
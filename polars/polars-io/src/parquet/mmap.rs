@@ -1,11 +1,13 @@
 use arrow::datatypes::Field;
+use arrow::io::parquet::read::indexes::FilteredPage;
 use arrow::io::parquet::read::{
-    column_iter_to_arrays, get_field_columns, ArrayIter, BasicDecompressor, ColumnChunkMetaData,
-    PageReader,
+    self, column_iter_to_arrays, get_field_columns, ArrayIter, BasicDecompressor,
+    ColumnChunkMetaData, PageReader,
 };
 #[cfg(feature = "async")]
 use polars_core::datatypes::PlHashMap;
 
+use super::predicates::ColumnPages;
 use super::*;
 
 /// Store columns data in two scenarios:
@@ -25,6 +27,17 @@ pub enum ColumnStore<'a> {
     Fetched(PlHashMap<u64, Vec<u8>>),
 }
 
+impl ColumnStore<'_> {
+    /// The bytes of the whole file, these are only available for local files.
+    pub(super) fn local_bytes(&self) -> Option<&[u8]> {
+        match self {
+            ColumnStore::Local(bytes) => Some(*bytes),
+            #[cfg(feature = "async")]
+            ColumnStore::Fetched(_) => None,
+        }
+    }
+}
+
 /// For local files memory maps all columns that are part of the parquet field `field_name`.
 /// For cloud files the relevant memory regions should have been prefetched.
 pub(super) fn mmap_columns<'a>(
@@ -87,3 +100,48 @@ pub(super) fn to_deserializer<'a>(
 
     column_iter_to_arrays(columns, types, field, Some(chunk_size), num_rows)
 }
+
+/// Like [`to_deserializer`], but only deserializes the selected `pages` of a flat field, which
+/// hold `num_rows` rows.
+pub(super) fn to_filtered_deserializer<'a>(
+    columns: Vec<(&ColumnChunkMetaData, &[u8])>,
+    pages: &ColumnPages,
+    field: Field,
+    num_rows: usize,
+) -> ArrowResult<ArrayIter<'a>> {
+    debug_assert_eq!(columns.len(), 1);
+    let (columns, filtered_pages): (Vec<_>, Vec<_>) = columns
+        .into_iter()
+        .map(|(column_meta, chunk)| {
+            // only the dictionary page and the selected pages are copied, back to back, and the
+            // offsets of the pages are moved accordingly
+            let (column_start, _) = column_meta.byte_range();
+            let len =
+                pages.dictionary.len() + pages.byte_ranges.iter().map(|r| r.len()).sum::<usize>();
+            let mut buf = Vec::with_capacity(len);
+            buf.extend_from_slice(&chunk[pages.dictionary.clone()]);
+            let filtered_pages = pages
+                .pages
+                .iter()
+                .zip(&pages.byte_ranges)
+                .map(|(page, range)| {
+                    let start = column_start + buf.len() as u64;
+                    buf.extend_from_slice(&chunk[range.clone()]);
+                    FilteredPage {
+                        start,
+                        ..page.clone()
+                    }
+                })
+                .collect::<Vec<_>>();
+            ((column_meta, buf), filtered_pages)
+        })
+        .unzip();
+
+    read::to_deserializer(
+        columns,
+        field,
+        num_rows,
+        Some(num_rows),
+        Some(filtered_pages),
+    )
+}
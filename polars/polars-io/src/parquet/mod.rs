@@ -72,6 +72,73 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_parquet_column_options_and_metadata() -> PolarsResult<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "a" => [1i64, 2, 3, 4],
+            "b" => ["x", "y", "x", "y"],
+            "c" => [Some("foo"), None, Some("bar"), Some("foo")]
+        )?;
+
+        ParquetWriter::new(&mut buf)
+            .with_compression(ParquetCompression::Snappy)
+            .with_column_options(
+                "a",
+                ParquetColumnOptions {
+                    encoding: Some(ParquetEncoding::DeltaBinaryPacked),
+                    compression: Some(ParquetCompression::Uncompressed),
+                },
+            )
+            .with_column_options(
+                "b",
+                ParquetColumnOptions {
+                    encoding: Some(ParquetEncoding::Dictionary),
+                    ..Default::default()
+                },
+            )
+            .with_column_options(
+                "c",
+                ParquetColumnOptions {
+                    encoding: Some(ParquetEncoding::DeltaLengthByteArray),
+                    ..Default::default()
+                },
+            )
+            .with_key_value_metadata(vec![("origin".into(), "test".into())])
+            .finish(&mut df)?;
+
+        buf.set_position(0);
+        let metadata = arrow::io::parquet::read::read_metadata(&mut buf)?;
+        let columns = metadata.row_groups[0].columns();
+        assert_eq!(format!("{:?}", columns[0].compression()), "Uncompressed");
+        assert_eq!(format!("{:?}", columns[1].compression()), "Snappy");
+        assert!(columns[0].dictionary_page_offset().is_none());
+        assert!(columns[1].dictionary_page_offset().is_some());
+
+        buf.set_position(0);
+        let mut reader = ParquetReader::new(buf);
+        assert_eq!(
+            reader.key_value_metadata()?,
+            [("origin".to_string(), Some("test".to_string()))]
+        );
+        // the dtypes are not changed by the encodings
+        assert!(reader.finish()?.frame_equal_missing(&df));
+
+        // booleans cannot be delta encoded
+        let mut df = df!("a" => [true, false])?;
+        let out = ParquetWriter::new(Cursor::new(Vec::new()))
+            .with_column_options(
+                "a",
+                ParquetColumnOptions {
+                    encoding: Some(ParquetEncoding::DeltaBinaryPacked),
+                    ..Default::default()
+                },
+            )
+            .finish(&mut df);
+        assert!(out.is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_read_parquet_with_projection() {
         let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
@@ -1,9 +1,18 @@
+use std::cell::Cell;
+use std::io::Cursor;
+use std::ops::Range;
+
+use arrow::array::new_null_array;
 use arrow::compute::concatenate::concatenate;
+use arrow::datatypes::PhysicalType;
+use arrow::io::parquet::bloom_filter;
+use arrow::io::parquet::read::indexes::{self, FieldPageStatistics, FilteredPage, Interval};
 use arrow::io::parquet::read::statistics::{deserialize, Statistics};
-use arrow::io::parquet::read::RowGroupMetaData;
+use arrow::io::parquet::read::{get_field_columns, read_pages_locations, RowGroupMetaData};
 use polars_core::prelude::*;
 
-use crate::predicates::PhysicalIoExpr;
+use super::mmap::ColumnStore;
+use crate::predicates::{PhysicalIoExpr, StatsEvaluator};
 use crate::ArrowResult;
 
 /// The statistics for a column in a Parquet file
@@ -11,8 +20,9 @@ use crate::ArrowResult;
 /// - max value
 /// - min value
 /// - null_count
+/// - and optionally a bloom filter
 #[cfg_attr(debug_assertions, derive(Debug))]
-pub struct ColumnStats(Statistics, Field);
+pub struct ColumnStats(Statistics, Field, Option<Vec<u8>>);
 
 impl ColumnStats {
     pub fn dtype(&self) -> DataType {
@@ -84,6 +94,13 @@ impl ColumnStats {
         }
     }
 
+    /// Whether any of the `values` may be in the column according to its bloom filter.
+    /// Returns `None` if the column has no bloom filter or if it cannot be used for these values.
+    pub fn bloom_filter_may_contain(&self, values: &Series) -> Option<bool> {
+        let bitset = self.2.as_deref()?;
+        bloom_filter_may_contain(bitset, self.1.data_type(), values)
+    }
+
     #[cfg(feature = "dtype-binary")]
     fn use_min_max(dtype: DataType) -> bool {
         dtype.is_numeric() || matches!(dtype, DataType::Utf8) || matches!(dtype, DataType::Binary)
@@ -111,6 +128,86 @@ impl BatchStats {
     }
 }
 
+/// Hashes the `values` as they are hashed in the bloom filter of a parquet column of type `dtype`.
+/// Returns `None` if the bloom filter of such a column cannot be used to look up these values.
+fn bloom_filter_hashes(values: &Series, dtype: &DataType) -> Option<Vec<u64>> {
+    // floats are not supported, as `-0.0 == 0.0` and they don't hash the same
+    let compatible = if dtype.is_integer() {
+        values.dtype().is_numeric()
+    } else {
+        values.dtype() == dtype
+    };
+    if !compatible {
+        return None;
+    }
+    // a value that cannot be represented in the column becomes null
+    let values = values.cast(dtype).ok()?;
+    if values.null_count() > 0 {
+        return None;
+    }
+
+    let hashes = match dtype {
+        // these are stored as parquet INT32
+        DataType::UInt32 => values
+            .u32()
+            .unwrap()
+            .into_no_null_iter()
+            .map(|v| bloom_filter::hash_native(v as i32))
+            .collect(),
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::UInt8 | DataType::UInt16 => {
+            let values = values.cast(&DataType::Int32).ok()?;
+            values
+                .i32()
+                .unwrap()
+                .into_no_null_iter()
+                .map(bloom_filter::hash_native)
+                .collect()
+        }
+        // these are stored as parquet INT64
+        DataType::Int64 => values
+            .i64()
+            .unwrap()
+            .into_no_null_iter()
+            .map(bloom_filter::hash_native)
+            .collect(),
+        DataType::UInt64 => values
+            .u64()
+            .unwrap()
+            .into_no_null_iter()
+            .map(|v| bloom_filter::hash_native(v as i64))
+            .collect(),
+        DataType::Utf8 => values
+            .utf8()
+            .unwrap()
+            .into_no_null_iter()
+            .map(bloom_filter::hash_byte)
+            .collect(),
+        #[cfg(feature = "dtype-binary")]
+        DataType::Binary => values
+            .binary()
+            .unwrap()
+            .into_no_null_iter()
+            .map(bloom_filter::hash_byte)
+            .collect(),
+        _ => return None,
+    };
+    Some(hashes)
+}
+
+/// Whether any of the `values` may be in the bloom filter `bitset` of a parquet column of type `dtype`.
+pub(crate) fn bloom_filter_may_contain(
+    bitset: &[u8],
+    dtype: &DataType,
+    values: &Series,
+) -> Option<bool> {
+    let hashes = bloom_filter_hashes(values, dtype)?;
+    Some(
+        hashes
+            .into_iter()
+            .any(|hash| bloom_filter::is_in_set(bitset, hash)),
+    )
+}
+
 /// Collect the statistics in a column chunk.
 pub(crate) fn collect_statistics(
     md: &[RowGroupMetaData],
@@ -128,7 +225,7 @@ pub(crate) fn collect_statistics(
             Some(rg) => deserialize(fld, &md[rg..rg + 1])?,
         };
         schema.with_column((&fld.name).into(), (&fld.data_type).into());
-        stats.push(ColumnStats(st, Field::from(fld)));
+        stats.push(ColumnStats(st, Field::from(fld), None));
     }
 
     Ok(if stats.is_empty() {
@@ -138,24 +235,303 @@ pub(crate) fn collect_statistics(
     })
 }
 
+/// Add the bloom filters of the columns in the row group to the statistics.
+/// Returns `false` if none of the columns has a bloom filter.
+fn load_bloom_filters(
+    stats: &mut BatchStats,
+    md: &RowGroupMetaData,
+    bytes: &[u8],
+) -> ArrowResult<bool> {
+    let mut reader = Cursor::new(bytes);
+    let mut found = false;
+
+    for column_stats in stats.stats.iter_mut() {
+        // only flat columns consist of a single parquet column
+        let columns = get_field_columns(md.columns(), column_stats.1.name());
+        if let [column] = columns.as_slice() {
+            if column.metadata().bloom_filter_offset.is_none() {
+                continue;
+            }
+            let mut bitset = vec![];
+            bloom_filter::read(column, &mut reader, &mut bitset)?;
+            // the filter is empty if it is of an unsupported kind
+            if !bitset.is_empty() {
+                column_stats.2 = Some(bitset);
+                found = true;
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// A parquet file may not have statistics of all columns.
+fn should_read(pred: &dyn StatsEvaluator, stats: &BatchStats) -> PolarsResult<bool> {
+    match pred.should_read(stats) {
+        Err(PolarsError::ColumnNotFound(_)) => Ok(true),
+        should_read => should_read,
+    }
+}
+
 pub(super) fn read_this_row_group(
     predicate: Option<&Arc<dyn PhysicalIoExpr>>,
     file_metadata: &arrow::io::parquet::read::FileMetaData,
     schema: &ArrowSchema,
     rg: usize,
+    store: &ColumnStore,
 ) -> PolarsResult<bool> {
     if let Some(pred) = &predicate {
         if let Some(pred) = pred.as_stats_evaluator() {
-            if let Some(stats) = collect_statistics(&file_metadata.row_groups, schema, Some(rg))? {
-                let should_read = pred.should_read(&stats);
-                // a parquet file may not have statistics of all columns
-                if matches!(should_read, Ok(false)) {
+            if let Some(mut stats) =
+                collect_statistics(&file_metadata.row_groups, schema, Some(rg))?
+            {
+                if !should_read(pred, &stats)? {
                     return Ok(false);
-                } else if !matches!(should_read, Err(PolarsError::ColumnNotFound(_))) {
-                    let _ = should_read?;
+                }
+                // the min/max statistics were not sufficient, the bloom filters may be
+                if let Some(bytes) = store.local_bytes() {
+                    if load_bloom_filters(&mut stats, &file_metadata.row_groups[rg], bytes)? {
+                        return should_read(pred, &stats);
+                    }
                 }
             }
         }
     }
     Ok(true)
 }
+
+/// The pages of a parquet column that have to be read.
+pub(super) struct ColumnPages {
+    pub(super) pages: Vec<FilteredPage>,
+    /// The byte range of the dictionary page, relative to the start of the column chunk. This
+    /// range is empty if the column has no dictionary page.
+    pub(super) dictionary: Range<usize>,
+    /// The byte ranges of the `pages`, relative to the start of the column chunk.
+    pub(super) byte_ranges: Vec<Range<usize>>,
+}
+
+/// Which pages of a row group have to be read.
+pub(super) enum PageSelection {
+    /// All pages.
+    All,
+    /// None of the rows can pass the predicate.
+    Skip,
+    /// Only the given pages of the projected fields, these hold `num_rows` rows.
+    Pages {
+        fields: Vec<ColumnPages>,
+        num_rows: usize,
+    },
+}
+
+impl PageSelection {
+    /// The pages of the `i`th projected field and the number of rows they hold.
+    pub(super) fn field(&self, i: usize) -> Option<(&ColumnPages, usize)> {
+        match self {
+            PageSelection::Pages { fields, num_rows } => Some((&fields[i], *num_rows)),
+            _ => None,
+        }
+    }
+}
+
+/// Use the page indexes (page statistics) of the row group to select the pages of the
+/// projected fields that may hold rows that pass the predicate.
+pub(super) fn select_pages(
+    predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+    store: &ColumnStore,
+    md: &RowGroupMetaData,
+    schema: &ArrowSchema,
+    projection: &[usize],
+) -> PolarsResult<PageSelection> {
+    let (pred, bytes) = match (
+        predicate.and_then(|pred| pred.as_stats_evaluator()),
+        store.local_bytes(),
+    ) {
+        (Some(pred), Some(bytes)) => (pred, bytes),
+        _ => return Ok(PageSelection::All),
+    };
+    let fields = projection
+        .iter()
+        .map(|i| schema.fields[*i].clone())
+        .collect::<Vec<_>>();
+    // reading a subset of the pages is only supported for flat columns
+    let is_flat = |fld: &ArrowField| {
+        matches!(
+            fld.data_type.to_physical_type(),
+            PhysicalType::Boolean
+                | PhysicalType::Primitive(_)
+                | PhysicalType::Utf8
+                | PhysicalType::LargeUtf8
+                | PhysicalType::Binary
+                | PhysicalType::LargeBinary
+        )
+    };
+    if !indexes::has_indexes(md) || !fields.iter().all(is_flat) {
+        return Ok(PageSelection::All);
+    }
+
+    let num_rows = md.num_rows();
+    let selected_rows = Cell::new(num_rows);
+    let mut reader = Cursor::new(bytes);
+    let pages = indexes::read_filtered_pages(&mut reader, md, &fields, |stats, intervals| {
+        let selected = select_intervals(pred, &fields, stats, intervals, num_rows);
+        selected_rows.set(selected.iter().map(|interval| interval.length).sum());
+        selected
+    });
+    // the page indexes are only an optimization, files without valid ones can still be read
+    let (pages, locations) = match (pages, read_pages_locations(&mut reader, md.columns())) {
+        (Ok(pages), Ok(locations)) if locations.iter().all(|pages| !pages.is_empty()) => {
+            (pages, locations)
+        }
+        _ => return Ok(PageSelection::All),
+    };
+
+    Ok(match selected_rows.get() {
+        0 => PageSelection::Skip,
+        n if n == num_rows => PageSelection::All,
+        n => {
+            let fields = fields
+                .iter()
+                .zip(pages)
+                .map(|(fld, mut pages)| {
+                    let (column_idx, column) = md
+                        .columns()
+                        .iter()
+                        .enumerate()
+                        .find(|(_, column)| column.descriptor().path_in_schema[0] == fld.name)
+                        .unwrap();
+                    let (column_start, _) = column.byte_range();
+                    let locations = &locations[column_idx];
+                    let to_range = |offset: i64, size: i32| {
+                        let start = (offset as u64 - column_start) as usize;
+                        start..start + size as usize
+                    };
+                    // the dictionary page precedes the first data page
+                    let first = &locations[0];
+                    let dictionary = 0..to_range(first.offset, first.compressed_page_size).start;
+                    let pages = pages.pop().unwrap();
+                    let byte_ranges = pages
+                        .iter()
+                        .map(|page| {
+                            let location = locations
+                                .iter()
+                                .find(|location| location.offset as u64 == page.start)
+                                .unwrap();
+                            to_range(location.offset, location.compressed_page_size)
+                        })
+                        .collect();
+                    ColumnPages {
+                        pages,
+                        dictionary,
+                        byte_ranges,
+                    }
+                })
+                .collect();
+            PageSelection::Pages {
+                fields,
+                num_rows: n,
+            }
+        }
+    })
+}
+
+/// Split the rows of the row group at the page boundaries of all columns with page statistics,
+/// such that every range of rows is covered by a single page per column, and return the ranges
+/// for which the predicate cannot be ruled out by the statistics of those pages.
+fn select_intervals(
+    pred: &dyn StatsEvaluator,
+    fields: &[ArrowField],
+    stats: &[FieldPageStatistics],
+    intervals: &[Vec<Vec<Interval>>],
+    num_rows: usize,
+) -> Vec<Interval> {
+    let columns = fields
+        .iter()
+        .zip(stats)
+        .zip(intervals)
+        .filter_map(
+            |((fld, stats), intervals)| match (stats, intervals.as_slice()) {
+                (FieldPageStatistics::Single(stats), [pages]) if !pages.is_empty() => {
+                    Some((fld, stats, pages))
+                }
+                _ => None,
+            },
+        )
+        .collect::<Vec<_>>();
+    if columns.is_empty() {
+        return vec![Interval::new(0, num_rows)];
+    }
+
+    let mut schema = Schema::with_capacity(columns.len());
+    for (fld, _, _) in &columns {
+        schema.with_column((&fld.name).into(), (&fld.data_type).into());
+    }
+    let mut bounds = columns
+        .iter()
+        .flat_map(|(_, _, pages)| pages.iter().map(|page| page.start))
+        .chain([0, num_rows])
+        .collect::<Vec<_>>();
+    bounds.sort_unstable();
+    bounds.dedup();
+
+    // the page of every column that holds the current range of rows
+    let mut page_idx = vec![0usize; columns.len()];
+    let mut selected: Vec<Interval> = vec![];
+    for window in bounds.windows(2) {
+        let (start, end) = (window[0], window[1]);
+        let stats = columns
+            .iter()
+            .zip(page_idx.iter_mut())
+            .map(|((fld, stats, pages), idx)| {
+                while *idx + 1 < pages.len() && pages[*idx + 1].start <= start {
+                    *idx += 1;
+                }
+                let statistics = Statistics {
+                    null_count: stats.null_count.clone().sliced(*idx, 1).boxed(),
+                    distinct_count: new_null_array(ArrowDataType::UInt64, 1),
+                    min_value: stats.min.sliced(*idx, 1),
+                    max_value: stats.max.sliced(*idx, 1),
+                };
+                ColumnStats(statistics, Field::from(*fld), None)
+            })
+            .collect();
+        let stats = BatchStats {
+            schema: schema.clone(),
+            stats,
+        };
+
+        if !matches!(pred.should_read(&stats), Ok(false)) {
+            match selected.last_mut() {
+                Some(last) if last.start + last.length == start => last.length += end - start,
+                _ => selected.push(Interval::new(start, end - start)),
+            }
+        }
+    }
+    selected
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bloom_filter_may_contain() {
+        let mut bitset = vec![0; 32];
+        for v in [1i64, 2, 3] {
+            bloom_filter::insert(&mut bitset, bloom_filter::hash_native(v));
+        }
+        let may_contain =
+            |values: Series| bloom_filter_may_contain(&bitset, &DataType::Int64, &values);
+        assert_eq!(may_contain(Series::new("", [2i64])), Some(true));
+        // literals are cast to the type of the column
+        assert_eq!(may_contain(Series::new("", [5i32, 3])), Some(true));
+        assert_eq!(may_contain(Series::new("", [1000i64, 10_000])), Some(false));
+        assert_eq!(may_contain(Series::new("", [Some(1i64), None])), None);
+        assert_eq!(may_contain(Series::new("", ["1"])), None);
+
+        let mut bitset = vec![0; 32];
+        bloom_filter::insert(&mut bitset, bloom_filter::hash_byte("foo"));
+        let may_contain =
+            |values: Series| bloom_filter_may_contain(&bitset, &DataType::Utf8, &values);
+        assert_eq!(may_contain(Series::new("", ["foo"])), Some(true));
+        assert_eq!(may_contain(Series::new("", ["bar", "baz"])), Some(false));
+    }
+}
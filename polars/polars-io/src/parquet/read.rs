@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use arrow::io::parquet::read;
 use arrow::io::parquet::write::FileMetaData;
+use polars_core::cancel::CancellationToken;
 #[cfg(feature = "cloud")]
 use polars_core::cloud::CloudOptions;
 use polars_core::prelude::*;
@@ -49,6 +50,7 @@ pub struct ParquetReader<R: Read + Seek> {
     low_memory: bool,
     metadata: Option<FileMetaData>,
     use_statistics: bool,
+    cancel_token: Option<CancellationToken>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -74,6 +76,7 @@ impl<R: MmapBytesReader> ParquetReader<R> {
             self.parallel,
             self.row_count,
             self.use_statistics,
+            self.cancel_token.as_ref(),
         )
         .map(|mut df| {
             if rechunk {
@@ -136,12 +139,31 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Stop reading with an error between row groups once `cancel_token` is cancelled.
+    pub fn with_cancel_token(mut self, cancel_token: Option<CancellationToken>) -> Self {
+        self.cancel_token = cancel_token;
+        self
+    }
+
     /// Number of rows in the parquet file.
     pub fn num_rows(&mut self) -> PolarsResult<usize> {
         let metadata = self.get_metadata()?;
         Ok(metadata.num_rows)
     }
 
+    /// Custom key-value metadata in the footer of the parquet file.
+    /// The arrow schema that is also stored there is not included.
+    pub fn key_value_metadata(&mut self) -> PolarsResult<Vec<(String, Option<String>)>> {
+        let metadata = self.get_metadata()?;
+        Ok(metadata
+            .key_value_metadata
+            .iter()
+            .flatten()
+            .filter(|kv| kv.key != "ARROW:schema")
+            .map(|kv| (kv.key.clone(), kv.value.clone()))
+            .collect())
+    }
+
     fn get_metadata(&mut self) -> PolarsResult<&FileMetaData> {
         if self.metadata.is_none() {
             self.metadata = Some(read::read_metadata(&mut self.reader)?);
@@ -181,6 +203,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             low_memory: false,
             metadata: None,
             use_statistics: true,
+            cancel_token: None,
         }
     }
 
@@ -207,6 +230,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.parallel,
             self.row_count,
             self.use_statistics,
+            self.cancel_token.as_ref(),
         )
         .map(|mut df| {
             if self.rechunk {
@@ -7,6 +7,7 @@ use std::sync::Arc;
 use arrow::array::new_empty_array;
 use arrow::io::parquet::read;
 use arrow::io::parquet::read::{ArrayIter, FileMetaData, RowGroupMetaData};
+use polars_core::cancel::CancellationToken;
 use polars_core::prelude::*;
 use polars_core::utils::{accumulate_dataframes_vertical, split_df};
 use polars_core::POOL;
@@ -15,7 +16,7 @@ use rayon::prelude::*;
 use super::mmap::ColumnStore;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::parquet::mmap::mmap_columns;
-use crate::parquet::predicates::read_this_row_group;
+use crate::parquet::predicates::{read_this_row_group, select_pages, ColumnPages, PageSelection};
 use crate::parquet::{mmap, ParallelStrategy};
 use crate::predicates::{apply_predicate, arrow_schema_to_empty_df, PhysicalIoExpr};
 use crate::prelude::utils::get_reader_bytes;
@@ -29,6 +30,7 @@ fn column_idx_to_series(
     schema: &ArrowSchema,
     store: &mmap::ColumnStore,
     chunk_size: usize,
+    pages: Option<(&ColumnPages, usize)>,
 ) -> PolarsResult<Series> {
     let mut field = schema.fields[column_i].clone();
 
@@ -44,6 +46,10 @@ fn column_idx_to_series(
     }
 
     let columns = mmap_columns(store, md.columns(), &field.name);
+    if let Some((pages, num_rows)) = pages {
+        let iter = mmap::to_filtered_deserializer(columns, pages, field.clone(), num_rows)?;
+        return array_iter_to_series(iter, &field, None);
+    }
     let iter = mmap::to_deserializer(columns, field.clone(), remaining_rows, Some(chunk_size))?;
 
     if remaining_rows < md.num_rows() {
@@ -100,14 +106,30 @@ fn rg_to_dfs(
     parallel: ParallelStrategy,
     projection: &[usize],
     use_statistics: bool,
+    cancel_token: Option<&CancellationToken>,
 ) -> PolarsResult<Vec<DataFrame>> {
     let mut dfs = Vec::with_capacity(row_group_end - row_group_start);
 
     for rg in row_group_start..row_group_end {
+        if let Some(token) = cancel_token {
+            token.check()?;
+        }
         let md = &file_metadata.row_groups[rg];
         let current_row_count = md.num_rows() as IdxSize;
 
-        if use_statistics && !read_this_row_group(predicate.as_ref(), file_metadata, schema, rg)? {
+        if use_statistics
+            && !read_this_row_group(predicate.as_ref(), file_metadata, schema, rg, store)?
+        {
+            *previous_row_count += current_row_count;
+            continue;
+        }
+        // skipping pages changes the row count and the rows a limit applies to
+        let pages = if use_statistics && row_count.is_none() && *remaining_rows >= md.num_rows() {
+            select_pages(predicate.as_ref(), store, md, schema, projection)?
+        } else {
+            PageSelection::All
+        };
+        if let PageSelection::Skip = pages {
             *previous_row_count += current_row_count;
             continue;
         }
@@ -122,7 +144,8 @@ fn rg_to_dfs(
             POOL.install(|| {
                 projection
                     .par_iter()
-                    .map(|column_i| {
+                    .enumerate()
+                    .map(|(i, column_i)| {
                         column_idx_to_series(
                             *column_i,
                             md,
@@ -130,6 +153,7 @@ fn rg_to_dfs(
                             schema,
                             store,
                             chunk_size,
+                            pages.field(i),
                         )
                     })
                     .collect::<PolarsResult<Vec<_>>>()
@@ -137,8 +161,17 @@ fn rg_to_dfs(
         } else {
             projection
                 .iter()
-                .map(|column_i| {
-                    column_idx_to_series(*column_i, md, *remaining_rows, schema, store, chunk_size)
+                .enumerate()
+                .map(|(i, column_i)| {
+                    column_idx_to_series(
+                        *column_i,
+                        md,
+                        *remaining_rows,
+                        schema,
+                        store,
+                        chunk_size,
+                        pages.field(i),
+                    )
                 })
                 .collect::<PolarsResult<Vec<_>>>()?
         };
@@ -176,6 +209,7 @@ fn rg_to_dfs_par(
     row_count: Option<RowCount>,
     projection: &[usize],
     use_statistics: bool,
+    cancel_token: Option<&CancellationToken>,
 ) -> PolarsResult<Vec<DataFrame>> {
     // compute the limits per row group and the row count offsets
     let row_groups = file_metadata
@@ -198,12 +232,30 @@ fn rg_to_dfs_par(
     let dfs = row_groups
         .into_par_iter()
         .map(|(rg_idx, md, local_limit, row_count_start)| {
+            if let Some(token) = cancel_token {
+                token.check()?;
+            }
             if local_limit == 0
                 || use_statistics
-                    && !read_this_row_group(predicate.as_ref(), file_metadata, schema, rg_idx)?
+                    && !read_this_row_group(
+                        predicate.as_ref(),
+                        file_metadata,
+                        schema,
+                        rg_idx,
+                        store,
+                    )?
             {
                 return Ok(None);
             }
+            // skipping pages changes the row count and the rows a limit applies to
+            let pages = if use_statistics && row_count.is_none() && local_limit >= md.num_rows() {
+                select_pages(predicate.as_ref(), store, md, schema, projection)?
+            } else {
+                PageSelection::All
+            };
+            if let PageSelection::Skip = pages {
+                return Ok(None);
+            }
             // test we don't read the parquet file if this env var is set
             #[cfg(debug_assertions)]
             {
@@ -213,8 +265,17 @@ fn rg_to_dfs_par(
             let chunk_size = md.num_rows();
             let columns = projection
                 .iter()
-                .map(|column_i| {
-                    column_idx_to_series(*column_i, md, local_limit, schema, store, chunk_size)
+                .enumerate()
+                .map(|(i, column_i)| {
+                    column_idx_to_series(
+                        *column_i,
+                        md,
+                        local_limit,
+                        schema,
+                        store,
+                        chunk_size,
+                        pages.field(i),
+                    )
                 })
                 .collect::<PolarsResult<Vec<_>>>()?;
 
@@ -243,6 +304,7 @@ pub fn read_parquet<R: MmapBytesReader>(
     mut parallel: ParallelStrategy,
     row_count: Option<RowCount>,
     use_statistics: bool,
+    cancel_token: Option<&CancellationToken>,
 ) -> PolarsResult<DataFrame> {
     let file_metadata = metadata
         .map(Ok)
@@ -282,6 +344,7 @@ pub fn read_parquet<R: MmapBytesReader>(
             parallel,
             &projection,
             use_statistics,
+            cancel_token,
         )?,
         ParallelStrategy::RowGroups => rg_to_dfs_par(
             &store,
@@ -295,6 +358,7 @@ pub fn read_parquet<R: MmapBytesReader>(
             row_count,
             &projection,
             use_statistics,
+            cancel_token,
         )?,
         // auto should already be replaced by Columns or RowGroups
         ParallelStrategy::Auto => unimplemented!(),
@@ -423,6 +487,7 @@ impl BatchedParquetReader {
                         ParallelStrategy::Columns,
                         &self.projection,
                         self.use_statistics,
+                        None,
                     )?;
                     self.row_group_offset += n;
                     dfs
@@ -440,6 +505,7 @@ impl BatchedParquetReader {
                         self.row_count.clone(),
                         &self.projection,
                         self.use_statistics,
+                        None,
                     )?;
                     self.row_group_offset += n;
                     dfs
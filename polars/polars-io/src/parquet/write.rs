@@ -2,7 +2,8 @@ use std::io::Write;
 
 use arrow::array::Array;
 use arrow::chunk::Chunk;
-use arrow::datatypes::{DataType as ArrowDataType, PhysicalType};
+use arrow::compute::cast::{cast, CastOptions};
+use arrow::datatypes::{DataType as ArrowDataType, IntegerType, PhysicalType};
 use arrow::error::Error as ArrowError;
 use arrow::io::parquet::read::ParquetError;
 use arrow::io::parquet::write::{self, DynIter, DynStreamingIterator, Encoding, FileWriter, *};
@@ -85,6 +86,41 @@ impl From<ParquetCompression> for CompressionOptions {
     }
 }
 
+/// The encoding used for the data pages of a column.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParquetEncoding {
+    Plain,
+    /// Write a dictionary page with the unique values and encode the data pages as indices
+    /// into that dictionary. Categorical columns are always dictionary encoded.
+    Dictionary,
+    /// Delta encoding for integer and temporal columns.
+    DeltaBinaryPacked,
+    /// Delta encoding of the lengths of string and binary values.
+    DeltaLengthByteArray,
+}
+
+impl From<ParquetEncoding> for Encoding {
+    fn from(value: ParquetEncoding) -> Self {
+        match value {
+            ParquetEncoding::Plain => Encoding::Plain,
+            ParquetEncoding::Dictionary => Encoding::RleDictionary,
+            ParquetEncoding::DeltaBinaryPacked => Encoding::DeltaBinaryPacked,
+            ParquetEncoding::DeltaLengthByteArray => Encoding::DeltaLengthByteArray,
+        }
+    }
+}
+
+/// Options that overrule the file wide options for a single column.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetColumnOptions {
+    /// Data page encoding. If `None`, the default encoding of the dtype is used.
+    pub encoding: Option<ParquetEncoding>,
+    /// Data page compression. If `None`, the compression of the file is used.
+    pub compression: Option<ParquetCompression>,
+}
+
 /// Write a DataFrame to parquet format
 ///
 #[must_use]
@@ -100,6 +136,10 @@ pub struct ParquetWriter<W> {
     data_pagesize_limit: Option<usize>,
     /// Serialize columns in parallel
     parallel: bool,
+    /// Options per column name that overrule the options above.
+    column_options: PlHashMap<String, ParquetColumnOptions>,
+    /// Custom metadata written to the footer of the file.
+    key_value_metadata: Vec<(String, String)>,
 }
 
 impl<W> ParquetWriter<W>
@@ -118,6 +158,8 @@ where
             row_group_size: None,
             data_pagesize_limit: None,
             parallel: true,
+            column_options: Default::default(),
+            key_value_metadata: vec![],
         }
     }
 
@@ -155,6 +197,23 @@ where
         self
     }
 
+    /// Set the encoding and/or compression of a single column.
+    pub fn with_column_options<S: Into<String>>(
+        mut self,
+        column: S,
+        options: ParquetColumnOptions,
+    ) -> Self {
+        self.column_options.insert(column.into(), options);
+        self
+    }
+
+    /// Add key-value metadata to the footer of the file. This can be read back with
+    /// [`ParquetReader::key_value_metadata`](crate::parquet::ParquetReader::key_value_metadata).
+    pub fn with_key_value_metadata(mut self, metadata: Vec<(String, String)>) -> Self {
+        self.key_value_metadata = metadata;
+        self
+    }
+
     fn materialize_options(&self) -> WriteOptions {
         WriteOptions {
             write_statistics: self.statistics,
@@ -165,20 +224,45 @@ where
     }
 
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        for name in self.column_options.keys() {
+            schema.try_index_of(name)?;
+        }
         let fields = schema.to_arrow().fields;
         let schema = ArrowSchema::from(fields);
 
         let parquet_schema = to_parquet_schema(&schema)?;
-        let encodings = get_encodings(&schema);
+        let columns = schema
+            .fields
+            .iter()
+            .map(|field| {
+                let column_options = self.column_options.get(&field.name);
+                let compression = column_options
+                    .and_then(|options| options.compression)
+                    .map_or(self.compression, Into::into);
+                let (encodings, cast_to) =
+                    get_encodings(field, column_options.and_then(|options| options.encoding))?;
+                Ok(ColumnWriteOptions {
+                    encodings,
+                    compression,
+                    cast_to,
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
         let options = self.materialize_options();
         let writer = FileWriter::try_new(self.writer, schema, options)?;
+        let key_value_metadata = self
+            .key_value_metadata
+            .into_iter()
+            .map(|(key, value)| KeyValue::new(key, value))
+            .collect();
 
         Ok(BatchedWriter {
             writer,
             parquet_schema,
-            encodings,
+            columns,
             options,
             parallel: self.parallel,
+            key_value_metadata,
         })
     }
 
@@ -203,7 +287,7 @@ where
 fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
     parquet_schema: &'a SchemaDescriptor,
-    encodings: &'a [Vec<Encoding>],
+    columns: &'a [ColumnWriteOptions],
     options: WriteOptions,
     parallel: bool,
 ) -> impl Iterator<Item = Result<RowGroupIter<'a, ArrowError>, ArrowError>> + 'a {
@@ -212,19 +296,73 @@ fn prepare_rg_iter<'a>(
         0 => None,
         _ => {
             let row_group =
-                create_serializer(batch, parquet_schema.fields(), encodings, options, parallel);
+                create_serializer(batch, parquet_schema.fields(), columns, options, parallel);
 
             Some(row_group)
         }
     })
 }
 
-fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
-    schema
-        .fields
-        .iter()
-        .map(|f| transverse(&f.data_type, encoding_map))
-        .collect()
+/// The resolved write options of a single column.
+struct ColumnWriteOptions {
+    /// Encoding per leave of the column.
+    encodings: Vec<Encoding>,
+    compression: CompressionOptions,
+    /// The column is cast to this (dictionary) type before it is encoded.
+    cast_to: Option<ArrowDataType>,
+}
+
+fn get_encodings(
+    field: &ArrowField,
+    encoding: Option<ParquetEncoding>,
+) -> PolarsResult<(Vec<Encoding>, Option<ArrowDataType>)> {
+    let data_type = &field.data_type;
+    let is_dictionary = matches!(data_type.to_physical_type(), PhysicalType::Dictionary(_));
+    match encoding {
+        None => Ok((transverse(data_type, encoding_map), None)),
+        Some(ParquetEncoding::Dictionary) => {
+            if is_dictionary {
+                return Ok((vec![Encoding::RleDictionary], None));
+            }
+            use ArrowDataType::*;
+            polars_ensure!(
+                matches!(
+                    data_type,
+                    Int8 | Int16
+                        | Int32
+                        | Int64
+                        | UInt8
+                        | UInt16
+                        | UInt32
+                        | UInt64
+                        | Utf8
+                        | LargeUtf8
+                        | Binary
+                        | LargeBinary
+                ),
+                InvalidOperation: "cannot dictionary encode column '{}' of type {:?}",
+                field.name, data_type
+            );
+            let cast_to = Dictionary(IntegerType::UInt32, Box::new(data_type.clone()), false);
+            Ok((vec![Encoding::RleDictionary], Some(cast_to)))
+        }
+        Some(encoding) => {
+            polars_ensure!(
+                !is_dictionary,
+                InvalidOperation: "categorical column '{}' can only be dictionary encoded",
+                field.name
+            );
+            let encoding = encoding.into();
+            polars_ensure!(
+                transverse(data_type, |dt| can_encode(dt, encoding))
+                    .into_iter()
+                    .all(|ok| ok),
+                InvalidOperation: "cannot encode column '{}' of type {:?} with {:?}",
+                field.name, data_type, encoding
+            );
+            Ok((transverse(data_type, |_| encoding), None))
+        }
+    }
 }
 
 /// Declare encodings
@@ -239,9 +377,10 @@ fn encoding_map(data_type: &ArrowDataType) -> Encoding {
 pub struct BatchedWriter<W: Write> {
     writer: FileWriter<W>,
     parquet_schema: SchemaDescriptor,
-    encodings: Vec<Vec<Encoding>>,
+    columns: Vec<ColumnWriteOptions>,
     options: WriteOptions,
     parallel: bool,
+    key_value_metadata: Vec<KeyValue>,
 }
 
 impl<W: Write> BatchedWriter<W> {
@@ -253,7 +392,7 @@ impl<W: Write> BatchedWriter<W> {
         let row_group_iter = prepare_rg_iter(
             df,
             &self.parquet_schema,
-            &self.encodings,
+            &self.columns,
             self.options,
             self.parallel,
         );
@@ -265,7 +404,10 @@ impl<W: Write> BatchedWriter<W> {
 
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn finish(&mut self) -> PolarsResult<u64> {
-        let size = self.writer.end(None)?;
+        let key_value_metadata = std::mem::take(&mut self.key_value_metadata);
+        let size = self
+            .writer
+            .end((!key_value_metadata.is_empty()).then_some(key_value_metadata))?;
         Ok(size)
     }
 }
@@ -273,12 +415,21 @@ impl<W: Write> BatchedWriter<W> {
 fn create_serializer<'a>(
     batch: Chunk<Box<dyn Array>>,
     fields: &[ParquetType],
-    encodings: &[Vec<Encoding>],
+    columns: &[ColumnWriteOptions],
     options: WriteOptions,
     parallel: bool,
 ) -> Result<RowGroupIter<'a, ArrowError>, ArrowError> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
-        let encoded_columns = array_to_columns(array, type_.clone(), options, encoding).unwrap();
+    let func = move |((array, type_), column): ((&ArrayRef, &ParquetType), &ColumnWriteOptions)| {
+        let casted;
+        let array = match &column.cast_to {
+            Some(data_type) => {
+                casted = cast(array.as_ref(), data_type, CastOptions::default()).unwrap();
+                &casted
+            }
+            None => array,
+        };
+        let encoded_columns =
+            array_to_columns(array, type_.clone(), options, &column.encodings).unwrap();
 
         encoded_columns
             .into_iter()
@@ -293,7 +444,7 @@ fn create_serializer<'a>(
                                 ))
                             })
                         }),
-                        options.compression,
+                        column.compression,
                         vec![],
                     )
                     .map_err(|e| ArrowError::External(format!("{e}"), Box::new(e))),
@@ -310,7 +461,7 @@ fn create_serializer<'a>(
                 .columns()
                 .par_iter()
                 .zip(fields)
-                .zip(encodings)
+                .zip(columns)
                 .flat_map(func)
                 .collect::<Vec<_>>()
         })
@@ -319,7 +470,7 @@ fn create_serializer<'a>(
             .columns()
             .iter()
             .zip(fields)
-            .zip(encodings)
+            .zip(columns)
             .flat_map(func)
             .collect::<Vec<_>>()
     };
@@ -1,28 +1,44 @@
 use std::any::Any;
-use std::path::Path;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
 use std::thread::JoinHandle;
 
 use crossbeam_channel::{bounded, Receiver, Sender};
+use polars_core::cloud::CloudOptions;
 use polars_core::prelude::*;
+#[cfg(feature = "csv-file")]
+use polars_io::csv::CsvWriter;
+use polars_io::is_cloud_url;
+#[cfg(feature = "json")]
+use polars_io::json::{JsonFormat, JsonWriter};
 #[cfg(feature = "parquet")]
 use polars_io::parquet::ParquetWriter;
 #[cfg(feature = "ipc")]
 use polars_io::prelude::IpcWriter;
-#[cfg(feature = "ipc")]
+#[cfg(feature = "async")]
+use polars_io::CloudWriter;
+#[cfg(any(feature = "ipc", feature = "csv-file", feature = "json"))]
 use polars_io::SerWriter;
 use polars_plan::prelude::*;
 
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
 use crate::pipeline::morsels_per_sink;
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
 trait SinkWriter {
     fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()>;
     fn _finish(&mut self) -> PolarsResult<()>;
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
-impl SinkWriter for polars_io::parquet::BatchedWriter<std::fs::File> {
+/// The output a [`SinkWriter`] writes to, either a local file or an object store upload.
+type SinkFile = Box<dyn Write + Send>;
+type BoxedSinkWriter = Box<dyn SinkWriter + Send>;
+type CreateSinkWriter = Box<dyn Fn(SinkFile) -> PolarsResult<BoxedSinkWriter> + Send + Sync>;
+
+#[cfg(feature = "parquet")]
+impl SinkWriter for polars_io::parquet::BatchedWriter<SinkFile> {
     fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         self.write_batch(df)
     }
@@ -34,7 +50,7 @@ impl SinkWriter for polars_io::parquet::BatchedWriter<std::fs::File> {
 }
 
 #[cfg(feature = "ipc")]
-impl SinkWriter for polars_io::ipc::BatchedWriter<std::fs::File> {
+impl SinkWriter for polars_io::ipc::BatchedWriter<SinkFile> {
     fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         self.write_batch(df)
     }
@@ -45,6 +61,28 @@ impl SinkWriter for polars_io::ipc::BatchedWriter<std::fs::File> {
     }
 }
 
+#[cfg(feature = "csv-file")]
+impl SinkWriter for polars_io::csv::BatchedWriter<SinkFile> {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.write_batch(df)
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        self.finish()
+    }
+}
+
+#[cfg(feature = "json")]
+impl SinkWriter for polars_io::json::BatchedWriter<SinkFile> {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.write_batch(df)
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        self.finish()
+    }
+}
+
 #[cfg(feature = "parquet")]
 pub struct ParquetSink {}
 #[cfg(feature = "parquet")]
@@ -52,37 +90,37 @@ impl ParquetSink {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(
         path: &Path,
+        partitioning: Option<&SinkPartitionOptions>,
         options: ParquetWriteOptions,
         schema: &Schema,
+        cloud_options: Option<&CloudOptions>,
     ) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
-        let writer = ParquetWriter::new(file)
-            .with_compression(options.compression)
-            .with_data_pagesize_limit(options.data_pagesize_limit)
-            .with_statistics(options.statistics)
-            .with_row_group_size(options.row_group_size)
-            // This is important! Otherwise we will deadlock
-            // See: #7074
-            .set_parallel(false)
-            .batched(schema)?;
-
-        let writer = Box::new(writer) as Box<dyn SinkWriter + Send + Sync>;
-
-        let morsels_per_sink = morsels_per_sink();
-        let backpressure = morsels_per_sink * 2;
-        let (sender, receiver) = bounded(backpressure);
-
-        let io_thread_handle = Arc::new(Some(init_writer_thread(
-            receiver,
-            writer,
-            options.maintain_order,
-            morsels_per_sink,
-        )));
-
-        Ok(FilesSink {
-            sender,
-            io_thread_handle,
-        })
+        let schema = schema.clone();
+        let maintain_order = options.maintain_order;
+        let create_writer: CreateSinkWriter = Box::new(move |file| {
+            let mut writer = ParquetWriter::new(file)
+                .with_compression(options.compression)
+                .with_data_pagesize_limit(options.data_pagesize_limit)
+                .with_statistics(options.statistics)
+                .with_row_group_size(options.row_group_size)
+                .with_key_value_metadata(options.key_value_metadata.clone())
+                // This is important! Otherwise we will deadlock
+                // See: #7074
+                .set_parallel(false);
+            for (column, column_options) in &options.column_options {
+                writer = writer.with_column_options(column.as_str(), *column_options);
+            }
+            let writer = writer.batched(&schema)?;
+            Ok(Box::new(writer) as BoxedSinkWriter)
+        });
+        FilesSink::new(
+            path,
+            partitioning,
+            "parquet",
+            create_writer,
+            maintain_order,
+            cloud_options,
+        )
     }
 }
 
@@ -91,42 +129,415 @@ pub struct IpcSink {}
 #[cfg(feature = "ipc")]
 impl IpcSink {
     #[allow(clippy::new_ret_no_self)]
-    pub fn new(path: &Path, options: IpcWriterOptions, schema: &Schema) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
-        let writer = IpcWriter::new(file)
-            .with_compression(options.compression)
-            .batched(schema)?;
-
-        let writer = Box::new(writer) as Box<dyn SinkWriter + Send + Sync>;
+    pub fn new(
+        path: &Path,
+        partitioning: Option<&SinkPartitionOptions>,
+        options: IpcWriterOptions,
+        schema: &Schema,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<FilesSink> {
+        let schema = schema.clone();
+        let create_writer: CreateSinkWriter = Box::new(move |file| {
+            let writer = IpcWriter::new(file)
+                .with_compression(options.compression)
+                .batched(&schema)?;
+            Ok(Box::new(writer) as BoxedSinkWriter)
+        });
+        FilesSink::new(
+            path,
+            partitioning,
+            "ipc",
+            create_writer,
+            options.maintain_order,
+            cloud_options,
+        )
+    }
+}
 
-        let morsels_per_sink = morsels_per_sink();
-        let backpressure = morsels_per_sink * 2;
-        let (sender, receiver) = bounded(backpressure);
+#[cfg(feature = "csv-file")]
+pub struct CsvSink {}
+#[cfg(feature = "csv-file")]
+impl CsvSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        path: &Path,
+        partitioning: Option<&SinkPartitionOptions>,
+        options: CsvWriterOptions,
+        schema: &Schema,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<FilesSink> {
+        let schema = schema.clone();
+        let maintain_order = options.maintain_order;
+        let create_writer: CreateSinkWriter = Box::new(move |file| {
+            let serialize_options = &options.serialize_options;
+            let writer = CsvWriter::new(file)
+                .has_header(options.include_header)
+                .with_batch_size(options.batch_size)
+                .with_delimiter(serialize_options.delimiter)
+                .with_quoting_char(serialize_options.quote)
+                .with_null_value(serialize_options.null.clone())
+                .with_quote_style(serialize_options.quote_style)
+                .with_line_terminator(serialize_options.line_terminator.clone())
+                .with_date_format(serialize_options.date_format.clone())
+                .with_time_format(serialize_options.time_format.clone())
+                .with_datetime_format(serialize_options.datetime_format.clone())
+                .with_float_precision(serialize_options.float_precision)
+                .batched(&schema)?;
+            Ok(Box::new(writer) as BoxedSinkWriter)
+        });
+        FilesSink::new(
+            path,
+            partitioning,
+            "csv",
+            create_writer,
+            maintain_order,
+            cloud_options,
+        )
+    }
+}
 
-        let io_thread_handle = Arc::new(Some(init_writer_thread(
-            receiver,
-            writer,
+#[cfg(feature = "json")]
+pub struct JsonSink {}
+#[cfg(feature = "json")]
+impl JsonSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        path: &Path,
+        partitioning: Option<&SinkPartitionOptions>,
+        options: JsonWriterOptions,
+        schema: &Schema,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<FilesSink> {
+        let schema = schema.clone();
+        let create_writer: CreateSinkWriter = Box::new(move |file| {
+            let writer = JsonWriter::new(file)
+                .with_json_format(JsonFormat::JsonLines)
+                .batched(&schema)?;
+            Ok(Box::new(writer) as BoxedSinkWriter)
+        });
+        FilesSink::new(
+            path,
+            partitioning,
+            "ndjson",
+            create_writer,
             options.maintain_order,
-            morsels_per_sink,
-        )));
+            cloud_options,
+        )
+    }
+}
 
-        Ok(FilesSink {
-            sender,
-            io_thread_handle,
+/// The maximum number of partition files that are open at the same time. If rows of
+/// another partition arrive, the file of the least recently written partition is
+/// finished, and that partition continues in a new file when it receives more rows.
+const MAX_OPEN_PARTITION_FILES: usize = 64;
+
+/// A buffered file of a partition that counts the bytes that are written to it.
+///
+/// It is shared with the [`SinkWriter`] that writes to it, so that the buffer can be
+/// flushed, and its errors reported, once that writer is finished.
+#[derive(Clone)]
+struct PartitionOutput {
+    file: Arc<Mutex<BufWriter<File>>>,
+    written: Arc<AtomicU64>,
+}
+
+impl PartitionOutput {
+    fn create(path: &Path) -> PolarsResult<Self> {
+        Ok(Self {
+            file: Arc::new(Mutex::new(BufWriter::new(File::create(path)?))),
+            written: Default::default(),
+        })
+    }
+
+    fn written(&self) -> u64 {
+        self.written.load(Ordering::Relaxed)
+    }
+}
+
+impl Write for PartitionOutput {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = self.file.lock().unwrap().write(buf)?;
+        self.written.fetch_add(n as u64, Ordering::Relaxed);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.file.lock().unwrap().flush()
+    }
+}
+
+/// The file of a partition that is currently written to.
+struct PartitionFile {
+    writer: BoxedSinkWriter,
+    output: PartitionOutput,
+    rows: usize,
+    // the write after which this file was last written to
+    last_write: u64,
+}
+
+impl PartitionFile {
+    fn finish(mut self) -> PolarsResult<()> {
+        self.writer._finish()?;
+        self.output.flush()?;
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct Partition {
+    file: Option<PartitionFile>,
+    // the number of files that were created for this partition
+    n_files: usize,
+}
+
+/// A [`SinkWriter`] that routes the rows of every batch to the files of their partition.
+///
+/// Every partition writes to a `key=value` directory under `root` and starts a new
+/// `data-0000.ext` file whenever the current one exceeds the configured number of rows
+/// or bytes.
+struct PartitionedSinkWriter {
+    root: PathBuf,
+    by: Vec<String>,
+    max_rows_per_file: usize,
+    max_bytes_per_file: u64,
+    extension: &'static str,
+    create_writer: CreateSinkWriter,
+    partitions: PlHashMap<PathBuf, Partition>,
+    n_open_files: usize,
+    n_writes: u64,
+}
+
+impl PartitionedSinkWriter {
+    fn new(
+        root: &Path,
+        partitioning: &SinkPartitionOptions,
+        extension: &'static str,
+        create_writer: CreateSinkWriter,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            partitioning.max_rows_per_file != Some(0),
+            ComputeError: "'max_rows_per_file' must be greater than 0"
+        );
+        std::fs::create_dir_all(root)?;
+        Ok(Self {
+            root: root.to_path_buf(),
+            by: partitioning.by.clone(),
+            max_rows_per_file: partitioning.max_rows_per_file.unwrap_or(usize::MAX),
+            max_bytes_per_file: partitioning.max_bytes_per_file.unwrap_or(u64::MAX),
+            extension,
+            create_writer,
+            partitions: Default::default(),
+            n_open_files: 0,
+            n_writes: 0,
         })
     }
+
+    /// The directory of the partition of `df`, which only holds rows of a single partition.
+    fn partition_dir(&self, df: &DataFrame) -> PolarsResult<PathBuf> {
+        let mut dir = self.root.clone();
+        for key in &self.by {
+            let value = df.column(key)?.get(0)?;
+            dir.push(hive_partition_dir(key, &value)?);
+        }
+        Ok(dir)
+    }
+
+    /// Finish the file of the partition that was written to least recently.
+    fn close_least_recently_written(&mut self) -> PolarsResult<()> {
+        let lru = self
+            .partitions
+            .values_mut()
+            .filter(|partition| partition.file.is_some())
+            .min_by_key(|partition| partition.file.as_ref().unwrap().last_write);
+        if let Some(partition) = lru {
+            partition.file.take().unwrap().finish()?;
+            self.n_open_files -= 1;
+        }
+        Ok(())
+    }
+
+    fn write_partition(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let dir = self.partition_dir(df)?;
+        let is_open = self
+            .partitions
+            .get(&dir)
+            .map_or(false, |partition| partition.file.is_some());
+        if !is_open && self.n_open_files >= MAX_OPEN_PARTITION_FILES {
+            self.close_least_recently_written()?;
+        }
+        self.n_writes += 1;
+        let partition = self.partitions.entry(dir.clone()).or_default();
+
+        let mut offset = 0;
+        while offset < df.height() {
+            if let Some(file) = &partition.file {
+                if file.rows >= self.max_rows_per_file
+                    || file.output.written() >= self.max_bytes_per_file
+                {
+                    partition.file.take().unwrap().finish()?;
+                    self.n_open_files -= 1;
+                }
+            }
+            let file = match &mut partition.file {
+                Some(file) => file,
+                None => {
+                    if partition.n_files == 0 {
+                        std::fs::create_dir_all(&dir)?;
+                    }
+                    let path =
+                        dir.join(format!("data-{:04}.{}", partition.n_files, self.extension));
+                    let output = PartitionOutput::create(&path)?;
+                    partition.n_files += 1;
+                    self.n_open_files += 1;
+                    partition.file.insert(PartitionFile {
+                        writer: (self.create_writer)(Box::new(output.clone()))?,
+                        output,
+                        rows: 0,
+                        last_write: 0,
+                    })
+                }
+            };
+            file.last_write = self.n_writes;
+            let mut len = (df.height() - offset).min(self.max_rows_per_file - file.rows);
+            if self.max_bytes_per_file < u64::MAX {
+                // split large batches by the average size of the rows in the current file,
+                // or by their in-memory size if nothing is written yet
+                let written = file.output.written();
+                let row_size = if file.rows > 0 {
+                    written / file.rows as u64
+                } else {
+                    (df.estimated_size() / df.height()) as u64
+                };
+                let fits = self.max_bytes_per_file.saturating_sub(written) / row_size.max(1);
+                len = len.min(fits.max(1) as usize);
+            }
+            file.writer._write_batch(&df.slice(offset as i64, len))?;
+            file.rows += len;
+            offset += len;
+        }
+        Ok(())
+    }
+}
+
+impl SinkWriter for PartitionedSinkWriter {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        if self.by.is_empty() {
+            return self.write_partition(df);
+        }
+        let groups = df.groupby(&self.by)?.take_groups();
+        match groups {
+            GroupsProxy::Idx(idx) => {
+                for (_, group) in idx.iter() {
+                    // groups are in bounds
+                    let part_df = unsafe { df._take_unchecked_slice(group, false) };
+                    self.write_partition(&part_df)?;
+                }
+            }
+            GroupsProxy::Slice { groups, .. } => {
+                for [first, len] in groups {
+                    self.write_partition(&df.slice(first as i64, len as usize))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        for partition in self.partitions.values_mut() {
+            if let Some(file) = partition.file.take() {
+                file.finish()?;
+            }
+        }
+        self.n_open_files = 0;
+        Ok(())
+    }
+}
+
+/// A shared handle to a [`CloudWriter`], so that the upload can be completed once the
+/// [`SinkWriter`] that writes to it is finished.
+#[cfg(feature = "async")]
+#[derive(Clone)]
+struct SharedCloudWriter(Arc<Mutex<Option<CloudWriter>>>);
+
+#[cfg(feature = "async")]
+impl SharedCloudWriter {
+    fn with_writer<T>(
+        &self,
+        f: impl FnOnce(&mut CloudWriter) -> std::io::Result<T>,
+    ) -> std::io::Result<T> {
+        match self.0.lock().unwrap().as_mut() {
+            Some(writer) => f(writer),
+            None => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "the upload has already been completed",
+            )),
+        }
+    }
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(feature = "async")]
+impl Write for SharedCloudWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.with_writer(|writer| writer.write(buf))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.with_writer(|writer| writer.flush())
+    }
+}
+
+/// A [`SinkWriter`] that writes to an object store and completes the upload when finished.
+#[cfg(feature = "async")]
+struct CloudSinkWriter {
+    writer: BoxedSinkWriter,
+    upload: SharedCloudWriter,
+}
+
+#[cfg(feature = "async")]
+impl CloudSinkWriter {
+    fn new(
+        uri: &str,
+        cloud_options: Option<&CloudOptions>,
+        create_writer: CreateSinkWriter,
+    ) -> PolarsResult<Self> {
+        let upload = SharedCloudWriter(Arc::new(Mutex::new(Some(CloudWriter::new(
+            uri,
+            cloud_options,
+        )?))));
+        let writer = create_writer(Box::new(upload.clone()))?;
+        Ok(Self { writer, upload })
+    }
+}
+
+#[cfg(feature = "async")]
+impl SinkWriter for CloudSinkWriter {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.writer._write_batch(df)
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        self.writer._finish()?;
+        match self.upload.0.lock().unwrap().take() {
+            Some(upload) => upload.close(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Write the chunks that are received on a separate thread.
+///
+/// The thread stops at the first error, which is returned when it is joined. If the
+/// channel is closed before the last chunk is received, e.g. because the query is
+/// cancelled, the writer is dropped without finishing it.
 fn init_writer_thread(
     receiver: Receiver<Option<DataChunk>>,
-    mut writer: Box<dyn SinkWriter + Send + Sync>,
+    mut writer: BoxedSinkWriter,
     maintain_order: bool,
     // this is used to determine when a batch of chunks should be written to disk
     // all chunks per push should be collected to determine in which order they should
     // be written
     morsels_per_sink: usize,
-) -> JoinHandle<()> {
+) -> JoinHandle<PolarsResult<()>> {
     std::thread::spawn(move || {
         // keep chunks around until all chunks per sink are written
         // then we write them all at once.
@@ -148,34 +559,105 @@ fn init_writer_thread(
                 }
 
                 for chunk in chunks.iter() {
-                    writer._write_batch(&chunk.data).unwrap()
+                    writer._write_batch(&chunk.data)?
                 }
                 // all chunks are written remove them
                 chunks.clear();
 
                 if last_write {
-                    writer._finish().unwrap();
-                    return;
+                    return writer._finish();
                 }
             }
         }
+        Ok(())
     })
 }
 
 // Ensure the data is return in the order it was streamed
 #[derive(Clone)]
-#[cfg(any(feature = "parquet", feature = "ipc"))]
 pub struct FilesSink {
     sender: Sender<Option<DataChunk>>,
-    io_thread_handle: Arc<Option<JoinHandle<()>>>,
+    io_thread_handle: Arc<Mutex<Option<JoinHandle<PolarsResult<()>>>>>,
+}
+
+impl FilesSink {
+    /// Write to the file at `path`, or to a directory of files at `path` if the output is
+    /// partitioned.
+    fn new(
+        path: &Path,
+        partitioning: Option<&SinkPartitionOptions>,
+        extension: &'static str,
+        create_writer: CreateSinkWriter,
+        maintain_order: bool,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Self> {
+        let writer: BoxedSinkWriter = if is_cloud_url(path) {
+            polars_ensure!(
+                partitioning.is_none(),
+                ComputeError: "partitioned sinks to cloud urls are not supported"
+            );
+            let uri = path.to_string_lossy();
+            #[cfg(feature = "async")]
+            {
+                Box::new(CloudSinkWriter::new(&uri, cloud_options, create_writer)?)
+            }
+            #[cfg(not(feature = "async"))]
+            {
+                let _ = (create_writer, cloud_options);
+                polars_bail!(
+                    ComputeError:
+                    "feature 'async' must be enabled in order to sink to cloud url '{}'", uri
+                )
+            }
+        } else {
+            match partitioning {
+                Some(partitioning) => Box::new(PartitionedSinkWriter::new(
+                    path,
+                    partitioning,
+                    extension,
+                    create_writer,
+                )?),
+                None => create_writer(Box::new(File::create(path)?))?,
+            }
+        };
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Mutex::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            maintain_order,
+            morsels_per_sink,
+        ))));
+
+        Ok(FilesSink {
+            sender,
+            io_thread_handle,
+        })
+    }
+
+    /// Wait until the io thread is done and return its error, if any. Only the first
+    /// caller joins the thread.
+    fn join_io_thread(&self) -> PolarsResult<()> {
+        let handle = self.io_thread_handle.lock().unwrap().take();
+        match handle {
+            Some(handle) => handle.join().map_err(
+                |_| polars_err!(ComputeError: "the io thread of the file sink panicked"),
+            )?,
+            None => Ok(()),
+        }
+    }
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
 impl Sink for FilesSink {
     fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
         // don't add empty dataframes
-        if chunk.data.height() > 0 {
-            self.sender.send(Some(chunk)).unwrap();
+        if chunk.data.height() > 0 && self.sender.send(Some(chunk)).is_err() {
+            // the io thread stopped because writing failed
+            self.join_io_thread()?;
+            polars_bail!(ComputeError: "the io thread of the file sink has stopped");
         };
         Ok(SinkResult::CanHaveMoreInput)
     }
@@ -189,16 +671,11 @@ impl Sink for FilesSink {
     }
     fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
         // `None` indicates that we can flush all remaining chunks.
-        self.sender.send(None).unwrap();
+        // if this fails the io thread has already stopped with an error
+        let _ = self.sender.send(None);
 
         // wait until all files written
-        // some unwrap/mut kung-fu to get a hold of `self`
-        Arc::get_mut(&mut self.io_thread_handle)
-            .unwrap()
-            .take()
-            .unwrap()
-            .join()
-            .unwrap();
+        self.join_io_thread()?;
 
         // return a dummy dataframe;
         Ok(FinalizedSink::Finished(Default::default()))
@@ -207,6 +684,6 @@ impl Sink for FilesSink {
         self
     }
     fn fmt(&self) -> &str {
-        "parquet_sink"
+        "file_sink"
     }
 }
@@ -3,20 +3,24 @@ use std::sync::Arc;
 
 use polars_core::datatypes::Field;
 use polars_core::error::PolarsResult;
-use polars_core::prelude::{DataType, SchemaRef, Series, IDX_DTYPE};
+use polars_core::prelude::{DataType, QuantileInterpolOptions, SchemaRef, Series, IDX_DTYPE};
 use polars_core::schema::Schema;
 use polars_plan::dsl::Expr;
 use polars_plan::logical_plan::{ArenaExprIter, Context};
-use polars_plan::prelude::{AAggExpr, AExpr};
+use polars_plan::prelude::{AAggExpr, AExpr, LiteralValue};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::IdxSize;
 
 use crate::executors::sinks::groupby::aggregates::count::CountAgg;
 use crate::executors::sinks::groupby::aggregates::first::FirstAgg;
 use crate::executors::sinks::groupby::aggregates::last::LastAgg;
+use crate::executors::sinks::groupby::aggregates::list::ListAgg;
 use crate::executors::sinks::groupby::aggregates::mean::MeanAgg;
 use crate::executors::sinks::groupby::aggregates::min_max::{new_max, new_min};
+use crate::executors::sinks::groupby::aggregates::n_unique::NUniqueAgg;
 use crate::executors::sinks::groupby::aggregates::null::NullAgg;
+use crate::executors::sinks::groupby::aggregates::quantile::QuantileAgg;
+use crate::executors::sinks::groupby::aggregates::var::VarAgg;
 use crate::executors::sinks::groupby::aggregates::{AggregateFunction, SumAgg};
 use crate::expressions::PhysicalPipedExpr;
 use crate::operators::DataChunk;
@@ -37,6 +41,35 @@ impl PhysicalPipedExpr for Count {
     }
 }
 
+fn input_dtype(input: Node, expr_arena: &Arena<AExpr>, input_schema: &Schema) -> Option<DataType> {
+    expr_arena
+        .get(input)
+        .to_field(input_schema, Context::Default, expr_arena)
+        .ok()
+        .map(|field| field.dtype)
+}
+
+/// The values of these dtypes can be hashed and collected as physical `AnyValue`s.
+fn is_hashable_physical(dtype: &DataType) -> bool {
+    match dtype {
+        #[cfg(feature = "dtype-categorical")]
+        // the physical values of different chunks may map to different categories
+        DataType::Categorical(_) => false,
+        dt => {
+            let dt = dt.to_physical();
+            dt.is_numeric() || matches!(dt, DataType::Utf8 | DataType::Binary | DataType::Boolean)
+        }
+    }
+}
+
+/// The floating point output type of the statistical aggregations.
+fn float_dtype(dtype: &DataType) -> DataType {
+    match dtype {
+        DataType::Float32 => DataType::Float32,
+        _ => DataType::Float64,
+    }
+}
+
 pub fn can_convert_to_hash_agg(
     mut node: Node,
     expr_arena: &Arena<AExpr>,
@@ -95,7 +128,24 @@ pub fn can_convert_to_hash_agg(
                     } else {
                         false
                     }
-                })
+                }) || match agg_fn {
+                    AAggExpr::Std(input, _) | AAggExpr::Var(input, _) | AAggExpr::Median(input) => {
+                        input_dtype(*input, expr_arena, input_schema)
+                            .map_or(false, |dt| dt.is_numeric())
+                    }
+                    AAggExpr::Quantile { expr, quantile, .. } => {
+                        matches!(
+                            expr_arena.get(*quantile),
+                            AExpr::Literal(LiteralValue::Float64(_))
+                        ) && input_dtype(*expr, expr_arena, input_schema)
+                            .map_or(false, |dt| dt.is_numeric())
+                    }
+                    AAggExpr::NUnique(input) | AAggExpr::List(input) => {
+                        input_dtype(*input, expr_arena, input_schema)
+                            .map_or(false, |dt| is_hashable_physical(&dt))
+                    }
+                    _ => false,
+                }
             }
             _ => false,
         }
@@ -234,6 +284,56 @@ where
                 let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
                 (phys_expr, AggregateFunction::Count(CountAgg::new()))
             }
+            AAggExpr::Std(input, ddof) | AAggExpr::Var(input, ddof) => {
+                let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
+                let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+                let std = matches!(agg, AAggExpr::Std(..));
+                (
+                    phys_expr,
+                    AggregateFunction::Var(VarAgg::new(*ddof, std, float_dtype(&logical_dtype))),
+                )
+            }
+            AAggExpr::Median(input) => {
+                let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
+                let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+                (
+                    phys_expr,
+                    AggregateFunction::Quantile(QuantileAgg::new(
+                        0.5,
+                        QuantileInterpolOptions::Linear,
+                        float_dtype(&logical_dtype),
+                    )),
+                )
+            }
+            AAggExpr::Quantile {
+                expr,
+                quantile,
+                interpol,
+            } => {
+                let phys_expr = to_physical(*expr, expr_arena, Some(schema)).unwrap();
+                let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+                let quantile = match expr_arena.get(*quantile) {
+                    AExpr::Literal(LiteralValue::Float64(q)) => *q,
+                    _ => unreachable!(),
+                };
+                (
+                    phys_expr,
+                    AggregateFunction::Quantile(QuantileAgg::new(
+                        quantile,
+                        *interpol,
+                        float_dtype(&logical_dtype),
+                    )),
+                )
+            }
+            AAggExpr::NUnique(input) => {
+                let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
+                (phys_expr, AggregateFunction::NUnique(NUniqueAgg::new()))
+            }
+            AAggExpr::List(input) => {
+                let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
+                let dtype = phys_expr.field(schema).unwrap().dtype;
+                (phys_expr, AggregateFunction::List(ListAgg::new(dtype)))
+            }
             agg => panic!("{agg:?} not yet implemented."),
         },
         _ => todo!(),
@@ -8,9 +8,13 @@ use polars_core::prelude::{AnyValue, Series};
 use crate::executors::sinks::groupby::aggregates::count::CountAgg;
 use crate::executors::sinks::groupby::aggregates::first::FirstAgg;
 use crate::executors::sinks::groupby::aggregates::last::LastAgg;
+use crate::executors::sinks::groupby::aggregates::list::ListAgg;
 use crate::executors::sinks::groupby::aggregates::mean::MeanAgg;
 use crate::executors::sinks::groupby::aggregates::min_max::MinMaxAgg;
+use crate::executors::sinks::groupby::aggregates::n_unique::NUniqueAgg;
 use crate::executors::sinks::groupby::aggregates::null::NullAgg;
+use crate::executors::sinks::groupby::aggregates::quantile::QuantileAgg;
+use crate::executors::sinks::groupby::aggregates::var::VarAgg;
 use crate::executors::sinks::groupby::aggregates::SumAgg;
 use crate::operators::IdxSize;
 
@@ -93,6 +97,10 @@ pub(crate) enum AggregateFunction {
     MinMaxI16(MinMaxAgg<i16, fn(&i16, &i16) -> Ordering>),
     MinMaxI32(MinMaxAgg<i32, fn(&i32, &i32) -> Ordering>),
     MinMaxI64(MinMaxAgg<i64, fn(&i64, &i64) -> Ordering>),
+    Var(VarAgg),
+    Quantile(QuantileAgg),
+    NUnique(NUniqueAgg),
+    List(ListAgg),
 }
 
 impl AggregateFunction {
@@ -121,6 +129,10 @@ impl AggregateFunction {
             MinMaxI16(inner) => MinMaxI16(inner.split()),
             MinMaxI32(inner) => MinMaxI32(inner.split()),
             MinMaxI64(inner) => MinMaxI64(inner.split()),
+            Var(inner) => Var(inner.split()),
+            Quantile(inner) => Quantile(inner.split()),
+            NUnique(_) => NUnique(NUniqueAgg::new()),
+            List(agg) => List(ListAgg::new(agg.dtype.clone())),
         }
     }
 }
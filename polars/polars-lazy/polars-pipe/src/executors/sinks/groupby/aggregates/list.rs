@@ -0,0 +1,80 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+use crate::operators::IdxSize;
+
+/// Collects the values of a group in a list.
+///
+/// Every value is stored with the index of its chunk, so that the states of different
+/// threads can be merged in the order of the input.
+pub(crate) struct ListAgg {
+    values: Vec<(IdxSize, AnyValue<'static>)>,
+    // dtype of the values in the output, the values are collected in their physical dtype
+    pub(crate) dtype: DataType,
+}
+
+impl ListAgg {
+    pub(crate) fn new(dtype: DataType) -> Self {
+        // temporal values are cast back to their logical dtype when finalized
+        let dtype = if dtype.is_temporal() {
+            dtype
+        } else {
+            dtype.to_physical()
+        };
+        ListAgg {
+            values: vec![],
+            dtype,
+        }
+    }
+}
+
+impl AggregateFn for ListAgg {
+    fn pre_agg(&mut self, chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.values.push((chunk_idx, item.into_static().unwrap()));
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        let values = values.slice(offset as i64, length as usize);
+        self.values.extend(
+            values
+                .phys_iter()
+                .map(|item| (chunk_idx, item.into_static().unwrap())),
+        );
+    }
+
+    fn dtype(&self) -> DataType {
+        // like the default engine, a group is collected into a list of a single list
+        DataType::List(Box::new(DataType::List(Box::new(self.dtype.clone()))))
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        self.values.extend_from_slice(&other.values);
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        let mut values = std::mem::take(&mut self.values);
+        // stable, so the values of a single chunk keep their order
+        values.sort_by_key(|(chunk_idx, _)| *chunk_idx);
+        let values = values.into_iter().map(|(_, av)| av).collect::<Vec<_>>();
+        let s = Series::from_any_values_and_dtype("", &values, &self.dtype.to_physical(), true)
+            .unwrap()
+            .cast(&self.dtype)
+            .unwrap();
+        AnyValue::List(s.to_list().unwrap().into_series())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -3,10 +3,14 @@ mod count;
 mod first;
 mod interface;
 mod last;
+mod list;
 mod mean;
 mod min_max;
+mod n_unique;
 mod null;
+mod quantile;
 mod sum;
+mod var;
 
 pub use convert::*;
 pub(crate) use interface::{AggregateFn, AggregateFunction};
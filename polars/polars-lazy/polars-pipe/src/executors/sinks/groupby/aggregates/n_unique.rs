@@ -0,0 +1,57 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+use crate::operators::IdxSize;
+
+/// Counts the distinct values, null included, by keeping them in a set that can be merged.
+pub(crate) struct NUniqueAgg {
+    values: PlHashSet<AnyValue<'static>>,
+}
+
+impl NUniqueAgg {
+    pub(crate) fn new() -> Self {
+        NUniqueAgg {
+            values: Default::default(),
+        }
+    }
+}
+
+impl AggregateFn for NUniqueAgg {
+    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.values.insert(item.into_static().unwrap());
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        let values = values.slice(offset as i64, length as usize);
+        for item in values.phys_iter() {
+            self.values.insert(item.into_static().unwrap());
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        IDX_DTYPE
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        self.values.extend(other.values.iter().cloned());
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        AnyValue::from(std::mem::take(&mut self.values).len() as IdxSize)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,240 @@
+use std::any::Any;
+
+use polars_arrow::export::arrow::array::PrimitiveArray;
+use polars_core::export::num::NumCast;
+use polars_core::prelude::*;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+use crate::operators::{ArrowDataType, IdxSize};
+
+// Bounds the number of centroids of the sketch. Higher is more accurate.
+const COMPRESSION: f64 = 100.0;
+// Number of values and centroids we collect before we compress.
+const MAX_UNMERGED: usize = 5 * COMPRESSION as usize;
+
+#[derive(Copy, Clone)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// Quantile and median with a mergeable t-digest sketch.
+///
+/// As long as no values had to be merged into a centroid, the quantile is exact and
+/// respects the interpolation method. For larger groups the quantile is approximated by
+/// interpolating between the centroids.
+pub(crate) struct QuantileAgg {
+    // values and centroids that are not yet compressed
+    unmerged: Vec<Centroid>,
+    // sorted by mean
+    centroids: Vec<Centroid>,
+    min: f64,
+    max: f64,
+    quantile: f64,
+    interpol: QuantileInterpolOptions,
+    // Float32 or Float64
+    dtype: DataType,
+}
+
+impl QuantileAgg {
+    pub(crate) fn new(quantile: f64, interpol: QuantileInterpolOptions, dtype: DataType) -> Self {
+        QuantileAgg {
+            unmerged: vec![],
+            centroids: vec![],
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            quantile,
+            interpol,
+            dtype,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.quantile, self.interpol, self.dtype.clone())
+    }
+
+    #[inline]
+    fn update(&mut self, value: f64) {
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.unmerged.push(Centroid {
+            mean: value,
+            weight: 1.0,
+        });
+        if self.unmerged.len() >= MAX_UNMERGED {
+            self.compress()
+        }
+    }
+
+    fn pre_agg_primitive<T: NumCast>(&mut self, item: Option<T>) {
+        if let Some(value) = item {
+            self.update(unsafe { value.to_f64().unwrap_unchecked_release() })
+        }
+    }
+
+    /// Merge the unmerged values into the centroids. A centroid may only grow as large as
+    /// the scale function allows at its quantile, which keeps the tails accurate.
+    fn compress(&mut self) {
+        if self.unmerged.is_empty() {
+            return;
+        }
+        let mut all = std::mem::take(&mut self.centroids);
+        all.append(&mut self.unmerged);
+        all.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+        let total: f64 = all.iter().map(|c| c.weight).sum();
+
+        let mut iter = all.into_iter();
+        let mut current = iter.next().unwrap();
+        // the weight of the centroids before `current`
+        let mut cumulative = 0.0;
+        for c in iter {
+            let proposed = current.weight + c.weight;
+            let q = (cumulative + proposed / 2.0) / total;
+            let limit = 4.0 * total * q * (1.0 - q) / COMPRESSION;
+            if proposed <= limit {
+                current.mean += (c.mean - current.mean) * c.weight / proposed;
+                current.weight = proposed;
+            } else {
+                cumulative += current.weight;
+                self.centroids.push(current);
+                current = c;
+            }
+        }
+        self.centroids.push(current);
+    }
+
+    fn exact_quantile(&self) -> Option<f64> {
+        let values = self
+            .centroids
+            .iter()
+            .chain(self.unmerged.iter())
+            .map(|c| c.mean)
+            .collect::<Vec<_>>();
+        Float64Chunked::from_vec("", values)
+            .quantile(self.quantile, self.interpol)
+            .unwrap()
+    }
+
+    fn approx_quantile(&self) -> Option<f64> {
+        let total: f64 = self.centroids.iter().map(|c| c.weight).sum();
+        let target = self.quantile * total;
+
+        // the centroids are interpreted as points at the center of their weight
+        // and the min and max are at the edges
+        let mut prev_position = 0.0;
+        let mut prev_mean = self.min;
+        let mut cumulative = 0.0;
+        for c in &self.centroids {
+            let position = cumulative + c.weight / 2.0;
+            if target < position {
+                let fraction = (target - prev_position) / (position - prev_position);
+                return Some(prev_mean + fraction * (c.mean - prev_mean));
+            }
+            cumulative += c.weight;
+            prev_position = position;
+            prev_mean = c.mean;
+        }
+        let fraction = (target - prev_position) / (total - prev_position);
+        Some(prev_mean + fraction * (self.max - prev_mean))
+    }
+}
+
+impl AggregateFn for QuantileAgg {
+    fn has_physical_agg(&self) -> bool {
+        true
+    }
+    fn pre_agg_i8(&mut self, _chunk_idx: IdxSize, item: Option<i8>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_u8(&mut self, _chunk_idx: IdxSize, item: Option<u8>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_i16(&mut self, _chunk_idx: IdxSize, item: Option<i16>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_u16(&mut self, _chunk_idx: IdxSize, item: Option<u16>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_i32(&mut self, _chunk_idx: IdxSize, item: Option<i32>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_i64(&mut self, _chunk_idx: IdxSize, item: Option<i64>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_u32(&mut self, _chunk_idx: IdxSize, item: Option<u32>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_u64(&mut self, _chunk_idx: IdxSize, item: Option<u64>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_f32(&mut self, _chunk_idx: IdxSize, item: Option<f32>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_f64(&mut self, _chunk_idx: IdxSize, item: Option<f64>) {
+        self.pre_agg_primitive(item)
+    }
+
+    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        if let Some(value) = item.extract::<f64>() {
+            self.update(value)
+        }
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        let arr = unsafe {
+            let arr = values.chunks().get_unchecked(0);
+            arr.sliced_unchecked(offset as usize, length as usize)
+        };
+        let arr = polars_arrow::compute::cast::cast(arr.as_ref(), &ArrowDataType::Float64).unwrap();
+        let arr = unsafe {
+            arr.as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap_unchecked_release()
+        };
+        for value in arr.iter().flatten() {
+            self.update(*value)
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        self.dtype.clone()
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.unmerged.extend_from_slice(&other.centroids);
+        self.unmerged.extend_from_slice(&other.unmerged);
+        if self.unmerged.len() >= MAX_UNMERGED {
+            self.compress()
+        }
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        let is_exact = self.centroids.iter().all(|c| c.weight == 1.0);
+        let out = if is_exact {
+            self.exact_quantile()
+        } else {
+            self.compress();
+            self.approx_quantile()
+        };
+        match (out, &self.dtype) {
+            (None, _) => AnyValue::Null,
+            (Some(v), DataType::Float32) => AnyValue::Float32(v as f32),
+            (Some(v), _) => AnyValue::Float64(v),
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
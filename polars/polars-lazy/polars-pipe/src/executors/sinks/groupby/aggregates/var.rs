@@ -0,0 +1,159 @@
+use std::any::Any;
+
+use polars_arrow::export::arrow::array::PrimitiveArray;
+use polars_core::export::num::NumCast;
+use polars_core::prelude::*;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+use crate::operators::{ArrowDataType, IdxSize};
+
+/// Variance and standard deviation with Welford's online algorithm.
+///
+/// The states of different threads are merged with the parallel algorithm of Chan et al.
+pub(crate) struct VarAgg {
+    count: IdxSize,
+    mean: f64,
+    // sum of squares of differences from the current mean
+    m2: f64,
+    ddof: u8,
+    std: bool,
+    // Float32 or Float64
+    dtype: DataType,
+}
+
+impl VarAgg {
+    pub(crate) fn new(ddof: u8, std: bool, dtype: DataType) -> Self {
+        VarAgg {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            ddof,
+            std,
+            dtype,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.ddof, self.std, self.dtype.clone())
+    }
+
+    #[inline]
+    fn update(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    fn pre_agg_primitive<T: NumCast>(&mut self, item: Option<T>) {
+        if let Some(value) = item {
+            self.update(unsafe { value.to_f64().unwrap_unchecked_release() })
+        }
+    }
+}
+
+impl AggregateFn for VarAgg {
+    fn has_physical_agg(&self) -> bool {
+        true
+    }
+    fn pre_agg_i8(&mut self, _chunk_idx: IdxSize, item: Option<i8>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_u8(&mut self, _chunk_idx: IdxSize, item: Option<u8>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_i16(&mut self, _chunk_idx: IdxSize, item: Option<i16>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_u16(&mut self, _chunk_idx: IdxSize, item: Option<u16>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_i32(&mut self, _chunk_idx: IdxSize, item: Option<i32>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_i64(&mut self, _chunk_idx: IdxSize, item: Option<i64>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_u32(&mut self, _chunk_idx: IdxSize, item: Option<u32>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_u64(&mut self, _chunk_idx: IdxSize, item: Option<u64>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_f32(&mut self, _chunk_idx: IdxSize, item: Option<f32>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_f64(&mut self, _chunk_idx: IdxSize, item: Option<f64>) {
+        self.pre_agg_primitive(item)
+    }
+
+    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        if let Some(value) = item.extract::<f64>() {
+            self.update(value)
+        }
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        let arr = unsafe {
+            let arr = values.chunks().get_unchecked(0);
+            arr.sliced_unchecked(offset as usize, length as usize)
+        };
+        let arr = polars_arrow::compute::cast::cast(arr.as_ref(), &ArrowDataType::Float64).unwrap();
+        let arr = unsafe {
+            arr.as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap_unchecked_release()
+        };
+        for value in arr.iter().flatten() {
+            self.update(*value)
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        self.dtype.clone()
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            self.count = other.count;
+            self.mean = other.mean;
+            self.m2 = other.m2;
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let weight = other.count as f64 / count as f64;
+        self.mean += delta * weight;
+        self.m2 += other.m2 + delta * delta * self.count as f64 * weight;
+        self.count = count;
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        // like the default engine, there is no variance without more values than `ddof`
+        let var = match self.count {
+            n if n <= self.ddof as IdxSize => return AnyValue::Null,
+            n => self.m2 / (n - self.ddof as IdxSize) as f64,
+        };
+        let out = if self.std { var.sqrt() } else { var };
+        match self.dtype {
+            DataType::Float32 => AnyValue::Float32(out as f32),
+            _ => AnyValue::Float64(out),
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
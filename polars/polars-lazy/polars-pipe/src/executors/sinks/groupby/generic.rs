@@ -157,6 +157,26 @@ impl GenericGroupbySink {
     fn number_of_aggs(&self) -> usize {
         self.aggregation_columns.len()
     }
+
+    // the size of the heap allocations of the keys and aggregation states is not included
+    fn estimated_size(&self) -> usize {
+        let tables = self
+            .pre_agg_partitions
+            .iter()
+            .map(|ht| ht.capacity() * std::mem::size_of::<(Key, IdxSize)>())
+            .sum::<usize>();
+        let keys = self
+            .keys
+            .iter()
+            .map(|keys| keys.capacity() * std::mem::size_of::<AnyValue>())
+            .sum::<usize>();
+        let aggregators = self
+            .aggregators
+            .iter()
+            .map(|aggs| aggs.capacity() * std::mem::size_of::<AggregateFunction>())
+            .sum::<usize>();
+        tables + keys + aggregators
+    }
     #[inline]
     fn number_of_keys(&self) -> usize {
         self.key_columns.len()
@@ -438,7 +458,9 @@ impl Sink for GenericGroupbySink {
         self.aggregation_series.clear();
         self.keys_series.clear();
         self.hashes.clear();
-        self.ooc_state.check_memory_usage(&self.input_schema)?;
+        let estimated_size = self.estimated_size();
+        self.ooc_state
+            .check_memory_usage(context, &self.input_schema, estimated_size)?;
         Ok(SinkResult::CanHaveMoreInput)
     }
 
@@ -551,6 +573,7 @@ impl Sink for GenericGroupbySink {
 
     fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
         let dfs = self.pre_finalize()?;
+        self.ooc_state.release_memory();
         let payload = if self.ooc_state.ooc {
             let mut iot = self.ooc_state.io_thread.lock().unwrap();
             // make sure that we reset the shared states
@@ -2,11 +2,13 @@ use std::sync::Mutex;
 
 use polars_arrow::export::arrow::bitmap::utils::set_bit_unchecked;
 use polars_core::config::verbose;
+use polars_core::memory::QueryMemory;
 use polars_core::prelude::*;
 
 use crate::executors::sinks::groupby::MEMORY_FRACTION_THRESHOLD;
 use crate::executors::sinks::io::IOThread;
 use crate::executors::sinks::memory::MemTracker;
+use crate::operators::PExecutionContext;
 use crate::pipeline::{morsels_per_sink, PARTITION_SIZE};
 
 pub(super) struct OocState {
@@ -14,6 +16,9 @@ pub(super) struct OocState {
     // Stores available memory in the system at the start of this sink.
     // and stores the memory used by this this sink.
     mem_track: MemTracker,
+    // the estimated size of this sink that is registered with the query memory
+    registered: usize,
+    memory: Option<QueryMemory>,
     // sort in-memory or out-of-core
     pub(super) ooc: bool,
     // bitmap that indicates the rows that are processed ooc
@@ -27,6 +32,8 @@ impl OocState {
     pub(super) fn new(io_thread: Option<Arc<Mutex<Option<IOThread>>>>, ooc: bool) -> Self {
         Self {
             mem_track: MemTracker::new(morsels_per_sink()),
+            registered: 0,
+            memory: None,
             ooc,
             ooc_filter: vec![],
             io_thread: io_thread.unwrap_or_default(),
@@ -57,13 +64,39 @@ impl OocState {
         self.ooc_filter.resize_with(len / 8 + 1, || 0)
     }
 
-    pub(super) fn check_memory_usage(&mut self, schema: &SchemaRef) -> PolarsResult<()> {
-        if self.mem_track.free_memory_fraction_since_start() < MEMORY_FRACTION_THRESHOLD {
+    /// Register the `estimated_size` of the sink with the query memory and go out-of-core
+    /// if either the system runs low on memory or the query exceeds its memory budget.
+    pub(super) fn check_memory_usage(
+        &mut self,
+        context: &PExecutionContext,
+        schema: &SchemaRef,
+        estimated_size: usize,
+    ) -> PolarsResult<()> {
+        let memory = self.memory.get_or_insert_with(|| context.memory().clone());
+        if estimated_size > self.registered {
+            memory.grow(estimated_size - self.registered)
+        } else {
+            memory.shrink(self.registered - estimated_size)
+        }
+        self.registered = estimated_size;
+        let should_spill = memory.should_spill();
+
+        if self.mem_track.free_memory_fraction_since_start() < MEMORY_FRACTION_THRESHOLD
+            || should_spill
+        {
             self.init_ooc(schema.clone())?
         }
         Ok(())
     }
 
+    /// Release the memory that this sink registered with the query, e.g. once it is
+    /// finalized or combined into another sink.
+    pub(super) fn release_memory(&mut self) {
+        if let Some(memory) = &self.memory {
+            memory.shrink(std::mem::take(&mut self.registered))
+        }
+    }
+
     #[inline]
     pub(super) unsafe fn set_row_as_ooc(&mut self, idx: usize) {
         // safety: should set the length in `reset_in_memory_rows`
@@ -107,3 +140,9 @@ impl OocState {
         iot.dump_iter(part_idx, Box::new(partitioned.into_iter()))
     }
 }
+
+impl Drop for OocState {
+    fn drop(&mut self) {
+        self.release_memory()
+    }
+}
@@ -150,6 +150,16 @@ where
         self.aggregation_columns.len()
     }
 
+    // the size of the heap allocations of the aggregation states is not included
+    fn estimated_size(&self) -> usize {
+        let tables = self
+            .pre_agg_partitions
+            .iter()
+            .map(|ht| ht.capacity() * std::mem::size_of::<(Key<Option<K::Native>>, IdxSize)>())
+            .sum::<usize>();
+        tables + self.aggregators.capacity() * std::mem::size_of::<AggregateFunction>()
+    }
+
     fn pre_finalize(&mut self) -> PolarsResult<Vec<DataFrame>> {
         // we create a pointer to the aggregation functions buffer
         // we will deref *mut on every partition thread
@@ -216,7 +226,12 @@ where
         })
     }
 
-    fn sink_sorted(&mut self, ca: &ChunkedArray<K>, chunk: DataChunk) -> PolarsResult<SinkResult> {
+    fn sink_sorted(
+        &mut self,
+        context: &PExecutionContext,
+        ca: &ChunkedArray<K>,
+        chunk: DataChunk,
+    ) -> PolarsResult<SinkResult> {
         let arr = ca.downcast_iter().next().unwrap();
         let values = arr.values().as_slice();
         partition_to_groups_amortized(values, 0, false, 0, &mut self.sort_partitions);
@@ -249,7 +264,9 @@ where
             }
         }
         self.aggregation_series.clear();
-        self.ooc_state.check_memory_usage(&self.input_schema)?;
+        let estimated_size = self.estimated_size();
+        self.ooc_state
+            .check_memory_usage(context, &self.input_schema, estimated_size)?;
         Ok(SinkResult::CanHaveMoreInput)
     }
 
@@ -354,7 +371,7 @@ where
 
         // sorted fast path
         if matches!(ca.is_sorted_flag2(), IsSorted::Ascending) && ca.null_count() == 0 {
-            return self.sink_sorted(ca, chunk);
+            return self.sink_sorted(context, ca, chunk);
         }
 
         s.vec_hash(self.hb.clone(), &mut self.hashes).unwrap();
@@ -398,7 +415,9 @@ where
         );
 
         self.aggregation_series.clear();
-        self.ooc_state.check_memory_usage(&self.input_schema)?;
+        let estimated_size = self.estimated_size();
+        self.ooc_state
+            .check_memory_usage(context, &self.input_schema, estimated_size)?;
         Ok(SinkResult::CanHaveMoreInput)
     }
 
@@ -443,6 +462,7 @@ where
 
     fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
         let dfs = self.pre_finalize()?;
+        self.ooc_state.release_memory();
         let payload = if self.ooc_state.ooc {
             let mut iot = self.ooc_state.io_thread.lock().unwrap();
             // make sure that we reset the shared states
@@ -131,6 +131,18 @@ impl Utf8GroupbySink {
         self.aggregation_columns.len()
     }
 
+    // the size of the heap allocations of the keys and aggregation states is not included
+    fn estimated_size(&self) -> usize {
+        let tables = self
+            .pre_agg_partitions
+            .iter()
+            .map(|ht| ht.capacity() * std::mem::size_of::<(Key, IdxSize)>())
+            .sum::<usize>();
+        tables
+            + self.keys.capacity() * std::mem::size_of::<Option<smartstring::alias::String>>()
+            + self.aggregators.capacity() * std::mem::size_of::<AggregateFunction>()
+    }
+
     fn pre_finalize(&mut self) -> PolarsResult<Vec<DataFrame>> {
         // we create a pointer to the aggregation functions buffer
         // we will deref *mut on every partition thread
@@ -300,7 +312,9 @@ impl Utf8GroupbySink {
         self.agg_fns = agg_fns;
         self.aggregators = aggregators;
         self.hashes.clear();
-        self.ooc_state.check_memory_usage(&self.input_schema)?;
+        let estimated_size = self.estimated_size();
+        self.ooc_state
+            .check_memory_usage(context, &self.input_schema, estimated_size)?;
         Ok(SinkResult::CanHaveMoreInput)
     }
 }
@@ -375,7 +389,9 @@ impl Sink for Utf8GroupbySink {
         self.agg_fns = agg_fns;
         self.aggregators = aggregators;
         self.hashes.clear();
-        self.ooc_state.check_memory_usage(&self.input_schema)?;
+        let estimated_size = self.estimated_size();
+        self.ooc_state
+            .check_memory_usage(context, &self.input_schema, estimated_size)?;
         Ok(SinkResult::CanHaveMoreInput)
     }
 
@@ -475,6 +491,7 @@ impl Sink for Utf8GroupbySink {
 
     fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
         let dfs = self.pre_finalize()?;
+        self.ooc_state.release_memory();
         let payload = if self.ooc_state.ooc {
             let mut iot = self.ooc_state.io_thread.lock().unwrap();
             // make sure that we reset the shared states
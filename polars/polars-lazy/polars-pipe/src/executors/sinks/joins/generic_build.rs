@@ -1,9 +1,11 @@
 use std::any::Any;
 use std::hash::{Hash, Hasher};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 
 use hashbrown::hash_map::RawEntryMut;
 use polars_arrow::trusted_len::PushUnchecked;
+use polars_core::config::verbose;
 use polars_core::error::PolarsResult;
 use polars_core::export::ahash::RandomState;
 use polars_core::frame::hash_join::ChunkId;
@@ -13,11 +15,18 @@ use polars_utils::hash_to_partition;
 use polars_utils::slice::GetSaferUnchecked;
 use polars_utils::unwrap::UnwrapUncheckedRelease;
 
+use crate::executors::sinks::io::{block_thread_until_io_thread_done, IOThread};
 use crate::executors::sinks::joins::inner_left::GenericJoinProbe;
+use crate::executors::sinks::joins::ooc::{dump_partitioned, OocJoinProbe};
+use crate::executors::sinks::joins::outer::GenericOuterJoinProbe;
+#[cfg(feature = "semi_anti_join")]
+use crate::executors::sinks::joins::semi_anti::GenericSemiAntiJoinProbe;
+use crate::executors::sinks::memory::MemTracker;
 use crate::executors::sinks::utils::{hash_series, load_vec};
 use crate::executors::sinks::HASHMAP_INIT_SIZE;
 use crate::expressions::PhysicalPipedExpr;
-use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+use crate::operators::{DataChunk, FinalizedSink, Operator, PExecutionContext, Sink, SinkResult};
+use crate::pipeline::{morsels_per_sink, FORCE_OOC_JOIN};
 
 pub(super) type ChunkIdx = IdxSize;
 pub(super) type DfIdx = IdxSize;
@@ -48,6 +57,58 @@ impl Hash for Key {
     }
 }
 
+/// Tracks which rows of the build table found a match while probing, so that the
+/// (un)matched rows can be produced once all probe chunks are processed.
+/// This is shared by the probe operators of all threads.
+pub(super) struct MatchedRows {
+    // the row offset of every chunk of the build table
+    offsets: Vec<usize>,
+    matched: Vec<AtomicBool>,
+}
+
+impl MatchedRows {
+    pub(super) fn new(chunks: &[DataChunk]) -> Self {
+        let mut offsets = Vec::with_capacity(chunks.len());
+        let mut len = 0;
+        for chunk in chunks {
+            offsets.push(len);
+            len += chunk.data.height();
+        }
+        let matched = (0..len).map(|_| AtomicBool::new(false)).collect();
+        Self { offsets, matched }
+    }
+
+    /// Mark the rows of a build table key as matched.
+    #[inline]
+    pub(super) fn set_matched(&self, ids: &[ChunkId]) {
+        for [chunk_idx, df_idx] in ids {
+            let idx = unsafe { *self.offsets.get_unchecked_release(*chunk_idx as usize) }
+                + *df_idx as usize;
+            unsafe { self.matched.get_unchecked_release(idx) }.store(true, Ordering::Relaxed)
+        }
+    }
+
+    /// The [`ChunkId`]s of the rows that did (not) find a match.
+    pub(super) fn ids(&self, matched: bool) -> Vec<ChunkId> {
+        let mut ids = vec![];
+        for (chunk_idx, offset) in self.offsets.iter().enumerate() {
+            let end = self
+                .offsets
+                .get(chunk_idx + 1)
+                .copied()
+                .unwrap_or(self.matched.len());
+            ids.extend(
+                self.matched[*offset..end]
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, m)| m.load(Ordering::Relaxed) == matched)
+                    .map(|(df_idx, _)| [chunk_idx as IdxSize, df_idx as IdxSize]),
+            )
+        }
+        ids
+    }
+}
+
 pub struct GenericBuild {
     chunks: Vec<DataChunk>,
     // the join columns are all tightly packed
@@ -74,6 +135,21 @@ pub struct GenericBuild {
     join_type: JoinType,
     // the join order is swapped to ensure we hash the smaller table
     swapped: bool,
+    // schema of the table that is streamed through the probe operator
+    probe_schema: SchemaRef,
+    // schema of the build table, used when the build table is spilled to disk
+    build_schema: SchemaRef,
+
+    // OOC
+    // Stores available memory in the system at the start of this sink
+    // and stores the memory used by this sink.
+    mem_track: MemTracker,
+    // build in-memory or spill the partitioned build table to disk
+    ooc: bool,
+    // the in-memory join of a single spilled partition may not go out-of-core again
+    can_ooc: bool,
+    // when ooc, we write to disk using an IO thread
+    io_thread: Arc<Mutex<Option<IOThread>>>,
 }
 
 impl GenericBuild {
@@ -83,7 +159,14 @@ impl GenericBuild {
         swapped: bool,
         join_columns_left: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
         join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        probe_schema: SchemaRef,
+        build_schema: SchemaRef,
     ) -> Self {
+        // the probe table is partitioned on disk once the join goes out-of-core, so only
+        // the joins that don't keep the order of the probe table may do so
+        let can_ooc = matches!(join_type, JoinType::Inner | JoinType::Outer);
+        // for testing purposes
+        let ooc = can_ooc && std::env::var(FORCE_OOC_JOIN).is_ok();
         let hb: RandomState = Default::default();
         let partitions = _set_partition_size();
         let hash_tables = load_vec(partitions, || PlIdHashMap::with_capacity(HASHMAP_INIT_SIZE));
@@ -99,6 +182,12 @@ impl GenericBuild {
             materialized_join_cols: vec![],
             hash_tables,
             hashes: vec![],
+            probe_schema,
+            build_schema,
+            mem_track: MemTracker::new(morsels_per_sink()),
+            ooc,
+            can_ooc,
+            io_thread: Default::default(),
         }
     }
 }
@@ -181,6 +270,98 @@ impl GenericBuild {
                 .map(|arr| arr.get_unchecked(df_idx as usize)),
         )
     }
+
+    fn check_memory_usage(
+        &mut self,
+        context: &PExecutionContext,
+        chunk_bytes: usize,
+    ) -> PolarsResult<()> {
+        if self.can_ooc {
+            let used = self.mem_track.fetch_add(chunk_bytes, context.memory());
+            let free = self.mem_track.get_available();
+
+            // the hash tables and the output of the probe side need memory as well
+            // so we keep 3x the build table size before we go out of core
+            if used * 3 > free || context.memory().should_spill() {
+                self.init_ooc(context)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn init_ooc(&mut self, context: &PExecutionContext) -> PolarsResult<()> {
+        if verbose() {
+            eprintln!("OOC join started");
+        }
+        self.ooc = true;
+        // the hash tables are rebuilt per partition once all data is spilled
+        for hash_table in self.hash_tables.iter_mut() {
+            *hash_table = Default::default();
+        }
+        self.materialized_join_cols = vec![];
+        for chunk in std::mem::take(&mut self.chunks) {
+            self.spill(context, chunk)?;
+        }
+        self.mem_track.release(context.memory());
+        Ok(())
+    }
+
+    /// Write the chunk partitioned by the hashes of the join keys to disk.
+    fn spill(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<()> {
+        if chunk.is_empty() {
+            return Ok(());
+        }
+        self.join_series.clear();
+        for phys_e in self.join_columns_left.iter() {
+            let s = phys_e.evaluate(&chunk, context.execution_state.as_any())?;
+            self.join_series.push(s.to_physical_repr().rechunk());
+        }
+        hash_series(&self.join_series, &mut self.hashes, &self.hb);
+
+        let mut iot = self.io_thread.lock().unwrap();
+        if iot.is_none() {
+            *iot = Some(IOThread::try_new(self.build_schema.clone(), "join_build")?)
+        }
+        dump_partitioned(iot.as_ref().unwrap(), chunk.data, &self.hashes);
+        Ok(())
+    }
+
+    /// A new join build that never goes out-of-core, e.g. to join a single spilled partition.
+    fn split_in_memory(&self) -> Self {
+        let mut new = Self::new(
+            self.suffix.clone(),
+            self.join_type.clone(),
+            self.swapped,
+            self.join_columns_left.clone(),
+            self.join_columns_right.clone(),
+            self.probe_schema.clone(),
+            self.build_schema.clone(),
+        );
+        new.hb = self.hb.clone();
+        new.ooc = false;
+        new.can_ooc = false;
+        new
+    }
+
+    fn finalize_ooc(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        // the chunks of the threads that didn't go out-of-core yet
+        for chunk in std::mem::take(&mut self.chunks) {
+            self.spill(context, chunk)?;
+        }
+        let io_thread = self.io_thread.lock().unwrap().take().unwrap();
+        block_thread_until_io_thread_done(&io_thread);
+        let probe_io_thread = IOThread::try_new(self.probe_schema.clone(), "join_probe")?;
+
+        let probe_operator = OocJoinProbe::new(
+            io_thread,
+            probe_io_thread,
+            Box::new(self.split_in_memory()),
+            self.build_schema.clone(),
+            self.hb.clone(),
+            self.join_columns_right.clone(),
+        );
+        Ok(FinalizedSink::Operator(Box::new(probe_operator)))
+    }
 }
 
 impl Sink for GenericBuild {
@@ -198,6 +379,10 @@ impl Sink for GenericBuild {
             }
             return Ok(SinkResult::CanHaveMoreInput);
         }
+        if self.ooc {
+            self.spill(context, chunk)?;
+            return Ok(SinkResult::CanHaveMoreInput);
+        }
         let mut hashes = std::mem::take(&mut self.hashes);
         self.set_join_series(context, &chunk)?;
         hash_series(&self.join_series, &mut hashes, &self.hb);
@@ -240,7 +425,10 @@ impl Sink for GenericBuild {
 
             current_df_idx += 1;
         }
+        drop(keys_iter);
+        let chunk_bytes = chunk.data.estimated_size();
         self.chunks.push(chunk);
+        self.check_memory_usage(context, chunk_bytes)?;
         Ok(SinkResult::CanHaveMoreInput)
     }
 
@@ -252,10 +440,17 @@ impl Sink for GenericBuild {
             }
             return;
         }
-        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
         if other.is_empty() {
             return;
         }
+        if self.ooc || other.ooc || self.io_thread.lock().unwrap().is_some() {
+            // the hash tables are not needed as all chunks are spilled in `finalize`
+            self.ooc = true;
+            self.chunks.append(&mut other.chunks);
+            return;
+        }
+        let other = &*other;
         let mut tuple_buf = Vec::with_capacity(self.number_of_keys());
 
         let chunks_offset = self.chunks.len() as IdxSize;
@@ -312,61 +507,92 @@ impl Sink for GenericBuild {
     }
 
     fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
-        let mut new = Self::new(
-            self.suffix.clone(),
-            self.join_type.clone(),
-            self.swapped,
-            self.join_columns_left.clone(),
-            self.join_columns_right.clone(),
-        );
-        new.hb = self.hb.clone();
+        let mut new = self.split_in_memory();
+        new.mem_track = self.mem_track.clone();
+        new.ooc = self.ooc;
+        new.can_ooc = self.can_ooc;
+        new.io_thread = self.io_thread.clone();
         Box::new(new)
     }
 
     fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
-        match self.join_type {
-            JoinType::Inner | JoinType::Left => {
-                let chunks_len = self.chunks.len();
-                let left_df = accumulate_dataframes_vertical_unchecked(
-                    std::mem::take(&mut self.chunks)
-                        .into_iter()
-                        .map(|chunk| chunk.data),
-                );
-                if left_df.height() > 0 {
-                    assert_eq!(left_df.n_chunks(), chunks_len);
-                }
-                let materialized_join_cols =
-                    Arc::new(std::mem::take(&mut self.materialized_join_cols));
-                let suffix = self.suffix.clone();
-                let hb = self.hb.clone();
-                let hash_tables = Arc::new(std::mem::take(&mut self.hash_tables));
-                let join_columns_left = self.join_columns_left.clone();
-                let join_columns_right = self.join_columns_right.clone();
-
-                // take the buffers, this saves one allocation
-                let mut join_series = std::mem::take(&mut self.join_series);
-                join_series.clear();
-                let mut hashes = std::mem::take(&mut self.hashes);
-                hashes.clear();
-
-                let probe_operator = GenericJoinProbe::new(
-                    left_df,
-                    materialized_join_cols,
-                    suffix,
-                    hb,
-                    hash_tables,
-                    join_columns_left,
-                    join_columns_right,
-                    self.swapped,
-                    join_series,
-                    hashes,
-                    context,
-                    self.join_type.clone(),
-                );
-                Ok(FinalizedSink::Operator(Box::new(probe_operator)))
-            }
-            _ => unimplemented!(),
+        // the build side is handed over to the probe side, which doesn't spill
+        self.mem_track.release(context.memory());
+        if self.io_thread.lock().unwrap().is_some() {
+            return self.finalize_ooc(context);
+        }
+        let chunks_len = self.chunks.len();
+        let matched_rows = match self.join_type {
+            JoinType::Inner | JoinType::Left => None,
+            _ => Some(Arc::new(MatchedRows::new(&self.chunks))),
+        };
+        let left_df = accumulate_dataframes_vertical_unchecked(
+            std::mem::take(&mut self.chunks)
+                .into_iter()
+                .map(|chunk| chunk.data),
+        );
+        if left_df.height() > 0 {
+            assert_eq!(left_df.n_chunks(), chunks_len);
         }
+        let materialized_join_cols = Arc::new(std::mem::take(&mut self.materialized_join_cols));
+        let suffix = self.suffix.clone();
+        let hb = self.hb.clone();
+        let hash_tables = Arc::new(std::mem::take(&mut self.hash_tables));
+        let join_columns_left = self.join_columns_left.clone();
+        let join_columns_right = self.join_columns_right.clone();
+
+        // take the buffers, this saves one allocation
+        let mut join_series = std::mem::take(&mut self.join_series);
+        join_series.clear();
+        let mut hashes = std::mem::take(&mut self.hashes);
+        hashes.clear();
+
+        let probe_operator = match self.join_type {
+            JoinType::Inner | JoinType::Left => Box::new(GenericJoinProbe::new(
+                left_df,
+                materialized_join_cols,
+                suffix,
+                hb,
+                hash_tables,
+                join_columns_left,
+                join_columns_right,
+                self.swapped,
+                join_series,
+                hashes,
+                context,
+                self.join_type.clone(),
+            )) as Box<dyn Operator>,
+            JoinType::Outer => Box::new(GenericOuterJoinProbe::new(
+                left_df,
+                materialized_join_cols,
+                suffix,
+                hb,
+                hash_tables,
+                join_columns_left,
+                join_columns_right,
+                self.swapped,
+                join_series,
+                hashes,
+                context,
+                matched_rows.unwrap(),
+                self.probe_schema.clone(),
+            )?),
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Semi | JoinType::Anti => Box::new(GenericSemiAntiJoinProbe::new(
+                left_df,
+                materialized_join_cols,
+                hb,
+                hash_tables,
+                join_columns_right,
+                self.swapped,
+                join_series,
+                hashes,
+                matched_rows.unwrap(),
+                matches!(self.join_type, JoinType::Anti),
+            )),
+            _ => unimplemented!(),
+        };
+        Ok(FinalizedSink::Operator(probe_operator))
     }
 
     fn as_any(&mut self) -> &mut dyn Any {
@@ -0,0 +1,293 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use polars_core::config::verbose;
+use polars_core::error::PolarsResult;
+use polars_core::export::ahash::RandomState;
+use polars_core::prelude::*;
+use polars_io::ipc::IpcReader;
+use polars_io::SerReader;
+
+use crate::executors::sinks::io::{block_thread_until_io_thread_done, IOThread};
+use crate::executors::sinks::utils::hash_series;
+use crate::executors::sources::IpcSourceOneShot;
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::{
+    DataChunk, FinalizedSink, Operator, OperatorResult, PExecutionContext, Sink, Source,
+};
+use crate::pipeline::{morsels_per_sink, PipeLine, PARTITION_SIZE};
+
+// The output of a partition is numbered from `partition * CHUNK_IDX_STRIDE`, so that the
+// chunk indexes of all threads are unique and ordered by partition.
+const CHUNK_IDX_STRIDE: usize = IdxSize::MAX as usize / PARTITION_SIZE;
+
+/// Partition the rows of `df` by their hash and send the partitions to the IO thread.
+/// The build and probe table use the same partitioning, so that a partition of the
+/// build table only has to be joined with the same partition of the probe table.
+pub(super) fn dump_partitioned(io_thread: &IOThread, df: DataFrame, hashes: &[u64]) {
+    let mut partitions = vec![vec![]; PARTITION_SIZE];
+    for (i, h) in hashes.iter().enumerate() {
+        let partition = (*h & (PARTITION_SIZE as u64 - 1)) as usize;
+        partitions[partition].push(i as IdxSize);
+    }
+
+    let (part_idx, dfs): (Vec<_>, Vec<_>) = partitions
+        .iter()
+        .enumerate()
+        .filter(|(_, idx)| !idx.is_empty())
+        .map(|(partition, idx)| {
+            // indexes are in bounds
+            let df = unsafe { df._take_unchecked_slice(idx, false) };
+            (partition as IdxSize, df)
+        })
+        .unzip();
+
+    let part_idx = Some(IdxCa::from_vec("", part_idx));
+    io_thread.dump_iter(part_idx, Box::new(dfs.into_iter()))
+}
+
+/// The spilled files of a single partition in the order they were written.
+fn partition_files(dir: &Path, partition: usize) -> PolarsResult<Vec<PathBuf>> {
+    let mut dir = dir.to_path_buf();
+    dir.push(format!("{partition}"));
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+    let mut files = std::fs::read_dir(dir)?
+        .map(|e| e.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    files.sort_unstable();
+    Ok(files)
+}
+
+fn read_df(path: &Path) -> PolarsResult<DataFrame> {
+    let file = std::fs::File::open(path)?;
+    IpcReader::new(file).set_rechunk(false).finish()
+}
+
+/// The in-memory join of a single partition of the build and probe table.
+struct PartitionJoin {
+    partition: usize,
+    // the number of chunks this partition produced so far
+    n_chunks: usize,
+    probe_operator: Box<dyn Operator>,
+    // remaining files of the probe table, in reverse order
+    probe_files: Vec<PathBuf>,
+    // the probe chunk that is being joined
+    chunk: Option<DataChunk>,
+    flushed: bool,
+}
+
+impl PartitionJoin {
+    fn next_chunk_idx(&mut self) -> IdxSize {
+        let chunk_idx = self.partition * CHUNK_IDX_STRIDE + self.n_chunks;
+        self.n_chunks += 1;
+        chunk_idx as IdxSize
+    }
+
+    fn next_output(&mut self, context: &PExecutionContext) -> PolarsResult<Option<DataChunk>> {
+        loop {
+            if let Some(chunk) = &self.chunk {
+                match self.probe_operator.execute(context, chunk)? {
+                    OperatorResult::Finished(out) => {
+                        self.chunk = None;
+                        return Ok(Some(out));
+                    }
+                    OperatorResult::HaveMoreOutPut(out) => return Ok(Some(out)),
+                    OperatorResult::NeedsNewData => self.chunk = None,
+                }
+            } else if let Some(path) = self.probe_files.pop() {
+                self.chunk = Some(DataChunk::new(0, read_df(&path)?));
+            } else if !self.flushed && self.probe_operator.must_flush() {
+                match self.probe_operator.flush(context)? {
+                    OperatorResult::Finished(out) => {
+                        self.flushed = true;
+                        return Ok(Some(out));
+                    }
+                    OperatorResult::HaveMoreOutPut(out) => return Ok(Some(out)),
+                    OperatorResult::NeedsNewData => self.flushed = true,
+                }
+            } else {
+                return Ok(None);
+            }
+        }
+    }
+}
+
+/// The probe operator of a join that went out-of-core.
+///
+/// The build table is spilled to disk in hash partitions. The streamed probe table is
+/// spilled with the same partitioning and produces no output while streaming. Once all
+/// probe chunks are processed, the operator is flushed and every partition is joined in
+/// memory by the regular join build and probe operators.
+///
+/// The partitions are divided over the threads, which join one partition at a time. A
+/// partition is never split further, so a single partition of the build table must fit in
+/// memory. The order of the probe table is not maintained, so joins that have to keep it
+/// never go out-of-core.
+pub struct OocJoinProbe {
+    // holding these keeps the lockfiles in place
+    build_io_thread: Arc<IOThread>,
+    probe_io_thread: Arc<IOThread>,
+    // the in-memory join build that is split for every partition
+    build_sink: Box<dyn Sink>,
+    // an empty partition of the build table is joined as an empty dataframe
+    build_schema: SchemaRef,
+    // must be equal to the hasher that partitioned the build table
+    hb: RandomState,
+    // the columns of the streamed table that will be joined on
+    join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+
+    // amortize allocations
+    join_series: Vec<Series>,
+    hashes: Vec<u64>,
+
+    // the next partition that will be joined, this thread joins every
+    // `n_threads`th partition starting at `thread_no`
+    partition: usize,
+    current: Option<PartitionJoin>,
+    thread_no: usize,
+    n_threads: usize,
+    flushing: bool,
+}
+
+impl OocJoinProbe {
+    pub(super) fn new(
+        build_io_thread: IOThread,
+        probe_io_thread: IOThread,
+        build_sink: Box<dyn Sink>,
+        build_schema: SchemaRef,
+        hb: RandomState,
+        join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+    ) -> Self {
+        OocJoinProbe {
+            build_io_thread: Arc::new(build_io_thread),
+            probe_io_thread: Arc::new(probe_io_thread),
+            build_sink,
+            build_schema,
+            hb,
+            join_columns_right,
+            join_series: vec![],
+            hashes: vec![],
+            partition: 0,
+            current: None,
+            thread_no: 0,
+            n_threads: morsels_per_sink(),
+            flushing: false,
+        }
+    }
+
+    fn join_partition(
+        &self,
+        context: &PExecutionContext,
+        partition: usize,
+    ) -> PolarsResult<Option<PartitionJoin>> {
+        let build_files = partition_files(&self.build_io_thread.dir, partition)?;
+        let mut probe_files = partition_files(&self.probe_io_thread.dir, partition)?;
+        if build_files.is_empty() && probe_files.is_empty() {
+            return Ok(None);
+        }
+
+        let mut sink = self.build_sink.split(0);
+        let finalized = if build_files.is_empty() {
+            // the probe rows may still produce output, e.g. in an outer join
+            let empty = DataFrame::from(self.build_schema.as_ref());
+            sink.sink(context, DataChunk::new(0, empty))?;
+            sink.finalize(context)?
+        } else {
+            let sources = build_files
+                .iter()
+                .map(|path| Ok(Box::new(IpcSourceOneShot::new(path.as_path())?) as Box<dyn Source>))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let mut pipe = PipeLine::new_simple(sources, vec![], sink, verbose());
+            pipe.run_pipeline(context)?
+        };
+        let probe_operator = match finalized {
+            FinalizedSink::Operator(op) => op,
+            _ => unreachable!(),
+        };
+        probe_files.reverse();
+
+        Ok(Some(PartitionJoin {
+            partition,
+            n_chunks: 0,
+            probe_operator,
+            probe_files,
+            chunk: None,
+            flushed: false,
+        }))
+    }
+}
+
+impl Operator for OocJoinProbe {
+    fn execute(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        if !chunk.is_empty() {
+            self.join_series.clear();
+            for phys_e in self.join_columns_right.iter() {
+                let s = phys_e.evaluate(chunk, context.execution_state.as_any())?;
+                self.join_series.push(s.to_physical_repr().rechunk());
+            }
+            hash_series(&self.join_series, &mut self.hashes, &self.hb);
+            dump_partitioned(&self.probe_io_thread, chunk.data.clone(), &self.hashes);
+        }
+        Ok(OperatorResult::NeedsNewData)
+    }
+
+    fn split(&self, thread_no: usize) -> Box<dyn Operator> {
+        Box::new(OocJoinProbe {
+            build_io_thread: self.build_io_thread.clone(),
+            probe_io_thread: self.probe_io_thread.clone(),
+            build_sink: self.build_sink.split(0),
+            build_schema: self.build_schema.clone(),
+            hb: self.hb.clone(),
+            join_columns_right: self.join_columns_right.clone(),
+            join_series: vec![],
+            hashes: vec![],
+            partition: thread_no,
+            current: None,
+            thread_no,
+            n_threads: self.n_threads,
+            flushing: false,
+        })
+    }
+
+    fn fmt(&self) -> &str {
+        "ooc_join_probe"
+    }
+
+    fn must_flush(&self) -> bool {
+        true
+    }
+
+    fn flush(&mut self, context: &PExecutionContext) -> PolarsResult<OperatorResult> {
+        if !self.flushing {
+            if verbose() && self.thread_no == 0 {
+                eprintln!("OOC join: joining spilled partitions");
+            }
+            block_thread_until_io_thread_done(&self.build_io_thread);
+            block_thread_until_io_thread_done(&self.probe_io_thread);
+            self.flushing = true;
+        }
+
+        loop {
+            if let Some(current) = &mut self.current {
+                if let Some(out) = current.next_output(context)? {
+                    let chunk_idx = current.next_chunk_idx();
+                    return Ok(OperatorResult::HaveMoreOutPut(DataChunk::new(
+                        chunk_idx, out.data,
+                    )));
+                }
+                self.current = None;
+            }
+            if self.partition >= PARTITION_SIZE {
+                return Ok(OperatorResult::NeedsNewData);
+            }
+            self.current = self.join_partition(context, self.partition)?;
+            self.partition += self.n_threads;
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::export::ahash::RandomState;
+use polars_core::frame::hash_join::{ChunkId, _finish_join};
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_utils::hash_to_partition;
+use polars_utils::slice::GetSaferUnchecked;
+
+use crate::executors::sinks::joins::generic_build::*;
+use crate::executors::sinks::utils::hash_series;
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
+
+/// The probe operator of a full outer join.
+///
+/// The rows of the streamed table are joined with their matches in the build table, or
+/// with nulls if they don't have any. The rows of the build table that never found a match
+/// are produced when the operator is flushed.
+#[derive(Clone)]
+pub struct GenericOuterJoinProbe {
+    // all chunks of the build table, without the join columns, are stacked into a
+    // single dataframe. the dataframe is not rechunked.
+    df_a: Arc<DataFrame>,
+    // the join columns of the build table
+    df_a_keys: Arc<DataFrame>,
+    // the join columns are all tightly packed
+    // the values of a join column(s) can be found
+    // by:
+    // first get the offset of the chunks and multiply that with the number of join
+    // columns
+    //      * chunk_offset = (idx * n_join_keys)
+    //      * end = (offset + n_join_keys)
+    materialized_join_cols: Arc<Vec<ArrayRef>>,
+    suffix: Arc<str>,
+    hb: RandomState,
+    // partitioned tables that will be used for probing
+    // stores the key and the chunk_idx, df_idx of the build table
+    hash_tables: Arc<Vec<PlIdHashMap<Key, Vec<ChunkId>>>>,
+
+    // the columns of the streamed table that will be joined on
+    join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+    // names of the join columns of the streamed table
+    join_names_b: Arc<Vec<String>>,
+    // names of the join columns in the output, these are the names of the left table
+    join_names_out: Arc<Vec<String>>,
+    // position of the join column in the left table if there is a single join column.
+    // with multiple join columns they are the first columns of the output.
+    join_column_idx: Option<usize>,
+    // schema of the streamed table, used to create the null columns of unmatched build rows
+    probe_schema: SchemaRef,
+
+    // amortize allocations
+    join_series: Vec<Series>,
+    hashes: Vec<u64>,
+    join_tuples_a: Vec<Option<ChunkId>>,
+    join_tuples_b: Vec<IdxSize>,
+    matched_rows: Arc<MatchedRows>,
+    // the build table is the right table
+    swapped: bool,
+    thread_no: usize,
+}
+
+fn join_names(
+    join_columns: &[Arc<dyn PhysicalPipedExpr>],
+    df: &DataFrame,
+    context: &PExecutionContext,
+) -> PolarsResult<Vec<String>> {
+    let tmp = DataChunk {
+        data: df.slice(0, 0),
+        chunk_index: 0,
+    };
+    join_columns
+        .iter()
+        .map(|phys_e| {
+            phys_e
+                .evaluate(&tmp, context.execution_state.as_any())
+                .map(|s| s.name().to_string())
+        })
+        .collect()
+}
+
+impl GenericOuterJoinProbe {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn new(
+        df_a: DataFrame,
+        materialized_join_cols: Arc<Vec<ArrayRef>>,
+        suffix: Arc<str>,
+        hb: RandomState,
+        hash_tables: Arc<Vec<PlIdHashMap<Key, Vec<ChunkId>>>>,
+        join_columns_left: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        swapped: bool,
+        join_series: Vec<Series>,
+        hashes: Vec<u64>,
+        context: &PExecutionContext,
+        matched_rows: Arc<MatchedRows>,
+        probe_schema: SchemaRef,
+    ) -> PolarsResult<Self> {
+        let join_names_a = join_names(&join_columns_left, &df_a, context)?;
+        let join_names_b = join_names(
+            &join_columns_right,
+            &DataFrame::from(probe_schema.as_ref()),
+            context,
+        )?;
+        let (join_names_out, join_column_idx) = if swapped {
+            (
+                join_names_b.clone(),
+                probe_schema.index_of(&join_names_b[0]),
+            )
+        } else {
+            (
+                join_names_a.clone(),
+                df_a.find_idx_by_name(&join_names_a[0]),
+            )
+        };
+        let join_column_idx = join_column_idx.filter(|_| join_names_out.len() == 1);
+
+        let df_a_keys = df_a.select(&join_names_a)?;
+        let df_a = df_a.drop_many(&join_names_a);
+
+        Ok(GenericOuterJoinProbe {
+            df_a: Arc::new(df_a),
+            df_a_keys: Arc::new(df_a_keys),
+            materialized_join_cols,
+            suffix,
+            hb,
+            hash_tables,
+            join_columns_right,
+            join_names_b: Arc::new(join_names_b),
+            join_names_out: Arc::new(join_names_out),
+            join_column_idx,
+            probe_schema,
+            join_series,
+            hashes,
+            join_tuples_a: vec![],
+            join_tuples_b: vec![],
+            matched_rows,
+            swapped,
+            thread_no: 0,
+        })
+    }
+
+    fn set_join_series(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<&[Series]> {
+        self.join_series.clear();
+        for phys_e in self.join_columns_right.iter() {
+            let s = phys_e.evaluate(chunk, context.execution_state.as_any())?;
+            let s = s.to_physical_repr();
+            self.join_series.push(s.rechunk());
+        }
+        Ok(&self.join_series)
+    }
+
+    /// Combine the rows of the build table `a` and the streamed table `b`, both without
+    /// their join columns, with the coalesced join columns in the same layout as the
+    /// outer join of the default engine.
+    fn finish_join(&self, a: DataFrame, b: DataFrame, keys: DataFrame) -> PolarsResult<DataFrame> {
+        let mut keys = keys.get_columns().to_vec();
+        for (s, name) in keys.iter_mut().zip(self.join_names_out.iter()) {
+            s.rename(name);
+        }
+        let (mut left, right) = if self.swapped { (b, a) } else { (a, b) };
+        match self.join_column_idx {
+            Some(idx) => unsafe { left.get_columns_mut().insert(idx, keys.pop().unwrap()) },
+            None => {
+                keys.extend_from_slice(left.get_columns());
+                left = DataFrame::new_no_checks(keys);
+            }
+        }
+        _finish_join(left, right, Some(self.suffix.as_ref()))
+    }
+}
+
+impl Operator for GenericOuterJoinProbe {
+    fn execute(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        self.join_tuples_a.clear();
+        self.join_tuples_b.clear();
+        let mut hashes = std::mem::take(&mut self.hashes);
+        self.set_join_series(context, chunk)?;
+        hash_series(&self.join_series, &mut hashes, &self.hb);
+        self.hashes = hashes;
+        let mut keys_iter = KeysIter::new(&self.join_series);
+
+        for (i, h) in self.hashes.iter().enumerate() {
+            let df_idx_b = i as IdxSize;
+            let current_tuple = unsafe { keys_iter.lend_next() };
+            // get the hashtable belonging by this hash partition
+            let partition = hash_to_partition(*h, self.hash_tables.len());
+            let current_table = unsafe { self.hash_tables.get_unchecked_release(partition) };
+
+            let entry = current_table
+                .raw_entry()
+                .from_hash(*h, |key| {
+                    compare_fn(
+                        key,
+                        *h,
+                        &self.materialized_join_cols,
+                        current_tuple,
+                        current_tuple.len(),
+                    )
+                })
+                .map(|key_val| key_val.1);
+
+            match entry {
+                Some(indexes_a) => {
+                    self.matched_rows.set_matched(indexes_a);
+                    self.join_tuples_a
+                        .extend(indexes_a.iter().copied().map(Some));
+                    self.join_tuples_b
+                        .extend(std::iter::repeat(df_idx_b).take(indexes_a.len()));
+                }
+                None => {
+                    self.join_tuples_a.push(None);
+                    self.join_tuples_b.push(df_idx_b);
+                }
+            }
+        }
+
+        let a = unsafe {
+            self.df_a
+                ._take_opt_chunked_unchecked_seq(&self.join_tuples_a)
+        };
+        // the join columns of the streamed rows are equal to those of their match, if any
+        let (keys, b) = unsafe {
+            (
+                chunk
+                    .data
+                    .select(self.join_names_b.as_ref())?
+                    ._take_unchecked_slice(&self.join_tuples_b, false),
+                chunk
+                    .data
+                    .drop_many(self.join_names_b.as_slice())
+                    ._take_unchecked_slice(&self.join_tuples_b, false),
+            )
+        };
+        let out = self.finish_join(a, b, keys)?;
+        Ok(OperatorResult::Finished(chunk.with_data(out)))
+    }
+
+    fn split(&self, thread_no: usize) -> Box<dyn Operator> {
+        let mut new = self.clone();
+        new.thread_no = thread_no;
+        Box::new(new)
+    }
+
+    fn fmt(&self) -> &str {
+        "generic_outer_join_probe"
+    }
+
+    fn must_flush(&self) -> bool {
+        true
+    }
+
+    fn flush(&mut self, _context: &PExecutionContext) -> PolarsResult<OperatorResult> {
+        // the build table is shared, so only a single thread produces the unmatched rows
+        if self.thread_no != 0 {
+            return Ok(OperatorResult::NeedsNewData);
+        }
+        let idx = self.matched_rows.ids(false);
+        let (a, keys) = unsafe {
+            (
+                self.df_a._take_chunked_unchecked_seq(&idx, IsSorted::Not),
+                self.df_a_keys
+                    ._take_chunked_unchecked_seq(&idx, IsSorted::Not),
+            )
+        };
+        let b = self
+            .probe_schema
+            .iter()
+            .filter(|(name, _)| !self.join_names_b.iter().any(|key| key == name.as_str()))
+            .map(|(name, dtype)| Series::full_null(name, idx.len(), dtype))
+            .collect();
+        let out = self.finish_join(a, DataFrame::new_no_checks(b), keys)?;
+        // the unmatched rows come after all streamed rows
+        Ok(OperatorResult::Finished(DataChunk::new(IdxSize::MAX, out)))
+    }
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::export::ahash::RandomState;
+use polars_core::frame::hash_join::ChunkId;
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_utils::hash_to_partition;
+use polars_utils::slice::GetSaferUnchecked;
+
+use crate::executors::sinks::joins::generic_build::*;
+use crate::executors::sinks::utils::hash_series;
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
+
+/// The probe operator of a semi or anti join.
+///
+/// If the right table is the build table, the streamed left table is filtered by the
+/// existence of a match. Otherwise the left table is the build table: the streamed right
+/// table only marks the rows that have a match and the (un)matched rows of the left table
+/// are produced when the operator is flushed.
+#[derive(Clone)]
+pub struct GenericSemiAntiJoinProbe {
+    // all chunks of the build table are stacked into a single dataframe
+    // the dataframe is not rechunked.
+    df_a: Arc<DataFrame>,
+    // the join columns are all tightly packed
+    // the values of a join column(s) can be found
+    // by:
+    // first get the offset of the chunks and multiply that with the number of join
+    // columns
+    //      * chunk_offset = (idx * n_join_keys)
+    //      * end = (offset + n_join_keys)
+    materialized_join_cols: Arc<Vec<ArrayRef>>,
+    hb: RandomState,
+    // partitioned tables that will be used for probing
+    // stores the key and the chunk_idx, df_idx of the build table
+    hash_tables: Arc<Vec<PlIdHashMap<Key, Vec<ChunkId>>>>,
+
+    // the columns of the streamed table that will be joined on
+    join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+
+    // amortize allocations
+    join_series: Vec<Series>,
+    hashes: Vec<u64>,
+    take_idx: Vec<IdxSize>,
+    matched_rows: Arc<MatchedRows>,
+    // the build table is the right table
+    swapped: bool,
+    anti: bool,
+    thread_no: usize,
+}
+
+impl GenericSemiAntiJoinProbe {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn new(
+        df_a: DataFrame,
+        materialized_join_cols: Arc<Vec<ArrayRef>>,
+        hb: RandomState,
+        hash_tables: Arc<Vec<PlIdHashMap<Key, Vec<ChunkId>>>>,
+        join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        swapped: bool,
+        join_series: Vec<Series>,
+        hashes: Vec<u64>,
+        matched_rows: Arc<MatchedRows>,
+        anti: bool,
+    ) -> Self {
+        GenericSemiAntiJoinProbe {
+            df_a: Arc::new(df_a),
+            materialized_join_cols,
+            hb,
+            hash_tables,
+            join_columns_right,
+            join_series,
+            hashes,
+            take_idx: vec![],
+            matched_rows,
+            swapped,
+            anti,
+            thread_no: 0,
+        }
+    }
+
+    fn set_join_series(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<&[Series]> {
+        self.join_series.clear();
+        for phys_e in self.join_columns_right.iter() {
+            let s = phys_e.evaluate(chunk, context.execution_state.as_any())?;
+            let s = s.to_physical_repr();
+            self.join_series.push(s.rechunk());
+        }
+        Ok(&self.join_series)
+    }
+}
+
+impl Operator for GenericSemiAntiJoinProbe {
+    fn execute(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        self.take_idx.clear();
+        let mut hashes = std::mem::take(&mut self.hashes);
+        self.set_join_series(context, chunk)?;
+        hash_series(&self.join_series, &mut hashes, &self.hb);
+        self.hashes = hashes;
+        let mut keys_iter = KeysIter::new(&self.join_series);
+
+        for (i, h) in self.hashes.iter().enumerate() {
+            let current_tuple = unsafe { keys_iter.lend_next() };
+            // get the hashtable belonging by this hash partition
+            let partition = hash_to_partition(*h, self.hash_tables.len());
+            let current_table = unsafe { self.hash_tables.get_unchecked_release(partition) };
+
+            let entry = current_table
+                .raw_entry()
+                .from_hash(*h, |key| {
+                    compare_fn(
+                        key,
+                        *h,
+                        &self.materialized_join_cols,
+                        current_tuple,
+                        current_tuple.len(),
+                    )
+                })
+                .map(|key_val| key_val.1);
+
+            if self.swapped {
+                if entry.is_some() != self.anti {
+                    self.take_idx.push(i as IdxSize)
+                }
+            } else if let Some(indexes_a) = entry {
+                self.matched_rows.set_matched(indexes_a);
+            }
+        }
+
+        if self.swapped {
+            let out = unsafe { chunk.data._take_unchecked_slice(&self.take_idx, true) };
+            Ok(OperatorResult::Finished(chunk.with_data(out)))
+        } else {
+            Ok(OperatorResult::NeedsNewData)
+        }
+    }
+
+    fn split(&self, thread_no: usize) -> Box<dyn Operator> {
+        let mut new = self.clone();
+        new.thread_no = thread_no;
+        Box::new(new)
+    }
+
+    fn fmt(&self) -> &str {
+        "generic_semi_anti_join_probe"
+    }
+
+    fn must_flush(&self) -> bool {
+        !self.swapped
+    }
+
+    fn flush(&mut self, _context: &PExecutionContext) -> PolarsResult<OperatorResult> {
+        // the build table is shared, so only a single thread produces its rows
+        if self.thread_no != 0 {
+            return Ok(OperatorResult::NeedsNewData);
+        }
+        let idx = self.matched_rows.ids(!self.anti);
+        let out = unsafe { self.df_a._take_chunked_unchecked_seq(&idx, IsSorted::Not) };
+        Ok(OperatorResult::Finished(DataChunk::new(IdxSize::MAX, out)))
+    }
+}
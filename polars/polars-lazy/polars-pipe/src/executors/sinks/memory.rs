@@ -1,6 +1,7 @@
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
+use polars_core::memory::QueryMemory;
 use polars_utils::sys::MEMINFO;
 
 const TO_MB: usize = 2 << 19;
@@ -53,8 +54,15 @@ impl MemTracker {
         available / available_at_start
     }
 
-    /// Increment the used memory and return the previous value.
-    pub(super) fn fetch_add(&self, add: usize) -> usize {
+    /// Increment the used memory, register it with the query and return the previous value.
+    pub(super) fn fetch_add(&self, add: usize, memory: &QueryMemory) -> usize {
+        memory.grow(add);
         self.used_by_node.fetch_add(add, Ordering::Relaxed)
     }
+
+    /// Release the memory used by this node from the query, e.g. after it is spilled to disk.
+    pub(super) fn release(&self, memory: &QueryMemory) {
+        let used = self.used_by_node.swap(0, Ordering::Relaxed);
+        memory.shrink(used)
+    }
 }
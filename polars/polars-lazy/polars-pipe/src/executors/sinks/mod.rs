@@ -1,4 +1,9 @@
-#[cfg(feature = "parquet")]
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv-file",
+    feature = "json"
+))]
 mod file_sink;
 pub(crate) mod groupby;
 mod io;
@@ -10,7 +15,12 @@ mod slice;
 mod sort;
 mod utils;
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv-file",
+    feature = "json"
+))]
 pub(crate) use file_sink::*;
 pub(crate) use joins::*;
 pub(crate) use ordered::*;
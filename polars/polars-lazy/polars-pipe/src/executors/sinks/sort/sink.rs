@@ -70,16 +70,18 @@ impl SortSink {
         Ok(())
     }
 
-    fn store_chunk(&mut self, chunk: DataChunk) -> PolarsResult<()> {
+    fn store_chunk(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<()> {
         if !self.ooc {
             let chunk_bytes = chunk.data.estimated_size();
-            let used = self.mem_track.fetch_add(chunk_bytes);
+            let used = self.mem_track.fetch_add(chunk_bytes, context.memory());
             let free = self.mem_track.get_available();
 
             // we need some free memory to be able to sort
             // so we keep 3x the sort data size before we go out of core
-            if used * 3 > free {
+            if used * 3 > free || context.memory().should_spill() {
                 self.init_ooc()?;
+                // the chunks are dumped to disk from now on
+                self.mem_track.release(context.memory());
             }
         }
         self.chunks.push_back(chunk.data);
@@ -107,8 +109,8 @@ impl SortSink {
 }
 
 impl Sink for SortSink {
-    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
-        self.store_chunk(chunk)?;
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        self.store_chunk(context, chunk)?;
 
         if self.ooc {
             self.dump()?;
@@ -141,7 +143,7 @@ impl Sink for SortSink {
         })
     }
 
-    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
         if self.ooc {
             let lock = self.io_thread.read().unwrap();
             let io_thread = lock.as_ref().unwrap();
@@ -164,6 +166,7 @@ impl Sink for SortSink {
             )
         } else {
             let chunks = std::mem::take(&mut self.chunks);
+            self.mem_track.release(context.memory());
             let df = accumulate_dataframes_vertical_unchecked(chunks);
             let df = sort_accumulated(
                 df,
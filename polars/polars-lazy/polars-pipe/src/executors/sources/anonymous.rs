@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::POOL;
+use polars_plan::prelude::{AnonymousScan, AnonymousScanBatches, AnonymousScanOptions};
+use polars_utils::IdxSize;
+
+use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+use crate::pipeline::determine_chunk_size;
+
+/// Streams the batches of an [`AnonymousScan`] that allows streaming.
+pub struct AnonymousScanSource {
+    batches: Box<dyn AnonymousScanBatches>,
+    n_threads: usize,
+    chunk_index: IdxSize,
+}
+
+impl AnonymousScanSource {
+    pub(crate) fn new(
+        function: Arc<dyn AnonymousScan>,
+        options: AnonymousScanOptions,
+        verbose: bool,
+    ) -> PolarsResult<Self> {
+        let schema = options.output_schema.as_ref().unwrap_or(&options.schema);
+        let n_threads = POOL.current_num_threads();
+        let chunk_size = determine_chunk_size(schema.len(), n_threads)?;
+
+        if verbose {
+            eprintln!("STREAMING CHUNK SIZE: {chunk_size} rows")
+        }
+
+        let batches = function.scan_batched(options, chunk_size)?;
+        Ok(AnonymousScanSource {
+            batches,
+            n_threads,
+            chunk_index: 0,
+        })
+    }
+}
+
+impl Source for AnonymousScanSource {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        let batches = self.batches.next_batches(self.n_threads)?;
+        Ok(match batches {
+            None => SourceResult::Finished,
+            Some(batches) => SourceResult::GotMoreData(
+                batches
+                    .into_iter()
+                    .map(|data| {
+                        let chunk_index = self.chunk_index;
+                        self.chunk_index += 1;
+                        DataChunk { chunk_index, data }
+                    })
+                    .collect(),
+            ),
+        })
+    }
+    fn fmt(&self) -> &str {
+        "anonymous_scan"
+    }
+}
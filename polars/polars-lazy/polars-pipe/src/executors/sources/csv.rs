@@ -57,7 +57,7 @@ impl CsvSource {
             .unwrap()
             .has_header(options.has_header)
             .with_schema(schema.clone())
-            .with_delimiter(options.delimiter)
+            .with_multi_char_delimiter(&options.delimiter)
             .with_ignore_errors(options.ignore_errors)
             .with_skip_rows(options.skip_rows)
             .with_n_rows(n_rows)
@@ -68,6 +68,7 @@ impl CsvSource {
             .with_comment_char(options.comment_char)
             .with_quote_char(options.quote_char)
             .with_end_of_line_char(options.eol_char)
+            .with_truncated_rows(options.truncated_rows)
             .with_encoding(options.encoding)
             .with_rechunk(options.rechunk)
             .with_chunk_size(chunk_size)
@@ -0,0 +1,79 @@
+use std::fs::File;
+use std::path::PathBuf;
+
+use polars_core::error::PolarsResult;
+use polars_core::schema::*;
+use polars_core::POOL;
+use polars_io::ipc::{BatchedIpcReader, IpcReader};
+use polars_io::SerReader;
+use polars_plan::global::_set_n_rows_for_scan;
+use polars_plan::prelude::IpcScanOptionsInner;
+use polars_utils::IdxSize;
+
+use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+use crate::pipeline::determine_chunk_size;
+
+pub struct IpcSource {
+    batched_reader: BatchedIpcReader<File>,
+    n_threads: usize,
+    chunk_index: IdxSize,
+}
+
+impl IpcSource {
+    pub(crate) fn new(
+        path: PathBuf,
+        options: IpcScanOptionsInner,
+        schema: &Schema,
+        verbose: bool,
+    ) -> PolarsResult<Self> {
+        let projection: Option<Vec<_>> = options.with_columns.map(|with_columns| {
+            with_columns
+                .iter()
+                .map(|name| schema.index_of(name).unwrap())
+                .collect()
+        });
+
+        let n_cols = projection.as_ref().map(|v| v.len()).unwrap_or(schema.len());
+        let n_threads = POOL.current_num_threads();
+        let chunk_size = determine_chunk_size(n_cols, n_threads)?;
+
+        if verbose {
+            eprintln!("STREAMING CHUNK SIZE: {chunk_size} rows")
+        }
+
+        let file = File::open(path)?;
+        let batched_reader = IpcReader::new(file)
+            .with_n_rows(_set_n_rows_for_scan(options.n_rows))
+            .with_row_count(options.row_count)
+            .with_projection(projection)
+            .batched(chunk_size)?;
+
+        Ok(IpcSource {
+            batched_reader,
+            n_threads,
+            chunk_index: 0,
+        })
+    }
+}
+
+impl Source for IpcSource {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        let batches = self.batched_reader.next_batches(self.n_threads)?;
+        Ok(match batches {
+            None => SourceResult::Finished,
+            Some(batches) => SourceResult::GotMoreData(
+                batches
+                    .into_iter()
+                    .map(|data| {
+                        let chunk_index = self.chunk_index;
+                        self.chunk_index += 1;
+                        DataChunk { chunk_index, data }
+                    })
+                    .collect(),
+            ),
+        })
+    }
+    fn fmt(&self) -> &str {
+        "ipc"
+    }
+}
@@ -1,15 +1,21 @@
+mod anonymous;
 #[cfg(feature = "csv-file")]
 mod csv;
 mod frame;
+#[cfg(feature = "ipc")]
+mod ipc;
 mod ipc_one_shot;
 #[cfg(feature = "parquet")]
 mod parquet;
 mod reproject;
 mod union;
 
+pub(crate) use anonymous::*;
 #[cfg(feature = "csv-file")]
 pub(crate) use csv::CsvSource;
 pub(crate) use frame::*;
+#[cfg(feature = "ipc")]
+pub(crate) use ipc::*;
 pub(crate) use ipc_one_shot::*;
 #[cfg(feature = "parquet")]
 pub(crate) use parquet::*;
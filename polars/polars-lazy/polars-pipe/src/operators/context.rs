@@ -1,7 +1,16 @@
 use std::any::Any;
 
+use polars_core::error::PolarsResult;
+use polars_core::memory::QueryMemory;
+
 pub trait SExecutionContext: Send + Sync {
     fn as_any(&self) -> &dyn Any;
+
+    /// The memory of the query this pipeline belongs to.
+    fn memory(&self) -> &QueryMemory;
+
+    /// Return an error if the query is cancelled or timed out.
+    fn should_stop(&self) -> PolarsResult<()>;
 }
 
 pub struct PExecutionContext {
@@ -15,4 +24,12 @@ impl PExecutionContext {
             execution_state: state,
         }
     }
+
+    pub(crate) fn memory(&self) -> &QueryMemory {
+        self.execution_state.memory()
+    }
+
+    pub(crate) fn should_stop(&self) -> PolarsResult<()> {
+        self.execution_state.should_stop()
+    }
 }
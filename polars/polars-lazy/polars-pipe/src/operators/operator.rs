@@ -17,4 +17,17 @@ pub trait Operator: Send + Sync {
     fn split(&self, thread_no: usize) -> Box<dyn Operator>;
 
     fn fmt(&self) -> &str;
+
+    /// Whether this operator holds output that can only be produced once all input is
+    /// processed, e.g. the unmatched rows of the build table of an outer join.
+    /// Such an operator gets [`Operator::flush`] called at the end of the pipeline.
+    fn must_flush(&self) -> bool {
+        false
+    }
+
+    /// Produce the remaining output of an operator that [`Operator::must_flush`].
+    /// `HaveMoreOutPut` means `flush` should be called again.
+    fn flush(&mut self, _context: &PExecutionContext) -> PolarsResult<OperatorResult> {
+        Ok(OperatorResult::NeedsNewData)
+    }
 }
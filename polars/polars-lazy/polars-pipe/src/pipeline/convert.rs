@@ -106,6 +106,41 @@ where
             )?;
             Ok(Box::new(src) as Box<dyn Source>)
         }
+        #[cfg(feature = "ipc")]
+        IpcScan {
+            path,
+            file_info,
+            options,
+            predicate,
+            output_schema,
+        } => {
+            // add predicate to operators
+            if let (true, Some(predicate)) = (push_predicate, predicate) {
+                let predicate = to_physical(predicate, expr_arena, output_schema.as_ref())?;
+                let op = operators::FilterOperator { predicate };
+                let op = Box::new(op) as Box<dyn Operator>;
+                operator_objects.push(op)
+            }
+            let src = sources::IpcSource::new(path, options, &file_info.schema, verbose)?;
+            Ok(Box::new(src) as Box<dyn Source>)
+        }
+        AnonymousScan {
+            function,
+            options,
+            predicate,
+            output_schema,
+            ..
+        } => {
+            // add predicate to operators
+            if let (true, Some(predicate)) = (push_predicate, predicate) {
+                let predicate = to_physical(predicate, expr_arena, output_schema.as_ref())?;
+                let op = operators::FilterOperator { predicate };
+                let op = Box::new(op) as Box<dyn Operator>;
+                operator_objects.push(op)
+            }
+            let src = sources::AnonymousScanSource::new(function, options, verbose)?;
+            Ok(Box::new(src) as Box<dyn Source>)
+        }
         _ => todo!(),
     }
 }
@@ -121,21 +156,52 @@ where
 {
     use ALogicalPlan::*;
     let out = match lp_arena.get(node) {
-        #[cfg(any(feature = "parquet", feature = "ipc"))]
+        #[cfg(any(
+            feature = "parquet",
+            feature = "ipc",
+            feature = "csv-file",
+            feature = "json"
+        ))]
         FileSink { input, payload } => {
             let path = payload.path.as_ref().as_path();
             let input_schema = lp_arena.get(*input).schema(lp_arena);
-            match &payload.file_type {
+            let partitioning = payload.partitioning.as_ref();
+            let cloud_options = payload.cloud_options.as_ref();
+            let sink = match &payload.file_type {
                 #[cfg(feature = "parquet")]
-                FileType::Parquet(options) => {
-                    Box::new(ParquetSink::new(path, *options, input_schema.as_ref())?)
-                        as Box<dyn Sink>
-                }
+                FileType::Parquet(options) => ParquetSink::new(
+                    path,
+                    partitioning,
+                    options.clone(),
+                    input_schema.as_ref(),
+                    cloud_options,
+                )?,
                 #[cfg(feature = "ipc")]
-                FileType::Ipc(options) => {
-                    Box::new(IpcSink::new(path, *options, input_schema.as_ref())?) as Box<dyn Sink>
-                }
-            }
+                FileType::Ipc(options) => IpcSink::new(
+                    path,
+                    partitioning,
+                    *options,
+                    input_schema.as_ref(),
+                    cloud_options,
+                )?,
+                #[cfg(feature = "csv-file")]
+                FileType::Csv(options) => CsvSink::new(
+                    path,
+                    partitioning,
+                    options.clone(),
+                    input_schema.as_ref(),
+                    cloud_options,
+                )?,
+                #[cfg(feature = "json")]
+                FileType::Json(options) => JsonSink::new(
+                    path,
+                    partitioning,
+                    *options,
+                    input_schema.as_ref(),
+                    cloud_options,
+                )?,
+            };
+            Box::new(sink) as Box<dyn Sink>
         }
         Join {
             input_left,
@@ -153,7 +219,7 @@ where
                 JoinType::Cross => {
                     Box::new(CrossJoin::new(options.suffix.clone())) as Box<dyn Sink>
                 }
-                join_type @ JoinType::Inner | join_type @ JoinType::Left => {
+                join_type => {
                     let input_schema_left = lp_arena.get(*input_left).schema(lp_arena);
                     let join_columns_left = Arc::new(exprs_to_physical(
                         left_on,
@@ -171,11 +237,22 @@ where
 
                     let swapped = swap_join_order(options);
 
-                    let (join_columns_left, join_columns_right) = if swapped {
-                        (join_columns_right, join_columns_left)
-                    } else {
-                        (join_columns_left, join_columns_right)
-                    };
+                    let (join_columns_left, join_columns_right, probe_schema, build_schema) =
+                        if swapped {
+                            (
+                                join_columns_right,
+                                join_columns_left,
+                                input_schema_left,
+                                input_schema_right,
+                            )
+                        } else {
+                            (
+                                join_columns_left,
+                                join_columns_right,
+                                input_schema_right,
+                                input_schema_left,
+                            )
+                        };
 
                     Box::new(GenericBuild::new(
                         Arc::from(options.suffix.as_ref()),
@@ -183,9 +260,10 @@ where
                         swapped,
                         join_columns_left,
                         join_columns_right,
+                        probe_schema.into_owned(),
+                        build_schema.into_owned(),
                     ))
                 }
-                _ => unimplemented!(),
             }
         }
         Slice { offset, len, .. } => {
@@ -462,6 +540,23 @@ where
                 true,
                 verbose,
             )?,
+            #[cfg(feature = "ipc")]
+            lp @ IpcScan { .. } => get_source(
+                lp.clone(),
+                &mut operator_objects,
+                expr_arena,
+                &to_physical,
+                true,
+                verbose,
+            )?,
+            lp @ AnonymousScan { .. } => get_source(
+                lp.clone(),
+                &mut operator_objects,
+                expr_arena,
+                &to_physical,
+                true,
+                verbose,
+            )?,
             Union { inputs, .. } => {
                 let sources = inputs
                     .iter()
@@ -526,6 +621,11 @@ where
     ))
 }
 
+/// Whether the right table should be the build table of a streaming join.
+///
+/// We build the hash table on the smallest table and stream the larger one, *except* for a
+/// left join. In a left join we always build on the right table so that we maintain the
+/// order of the left table.
 pub fn swap_join_order(options: &JoinOptions) -> bool {
     matches!(options.how, JoinType::Left)
         || match (options.rows_left, options.rows_right) {
@@ -193,6 +193,51 @@ impl PipeLine {
         Ok(SinkResult::CanHaveMoreInput)
     }
 
+    /// Flush the operators that must produce output after all input is processed and push
+    /// that output through the remaining operators into the sink. The operators of every
+    /// thread are flushed in parallel.
+    fn flush_operators(
+        &mut self,
+        sink: &mut [Box<dyn Sink>],
+        ec: &PExecutionContext,
+        operator_start: usize,
+        operator_end: usize,
+    ) -> PolarsResult<()> {
+        let mut operators = std::mem::take(&mut self.operators);
+        let out = POOL.install(|| {
+            operators
+                .par_iter_mut()
+                .zip(sink.par_iter_mut())
+                .try_for_each(|(operator_pipe, sink)| {
+                    let operator_pipe = &mut operator_pipe[operator_start..operator_end];
+                    for op_i in 0..operator_pipe.len() {
+                        if !operator_pipe[op_i].must_flush() {
+                            continue;
+                        }
+                        loop {
+                            let (chunk, more) = match operator_pipe[op_i].flush(ec)? {
+                                OperatorResult::Finished(chunk) => (chunk, false),
+                                OperatorResult::HaveMoreOutPut(chunk) => (chunk, true),
+                                OperatorResult::NeedsNewData => break,
+                            };
+                            let remaining = &mut operator_pipe[op_i + 1..];
+                            let sink_result = if remaining.is_empty() {
+                                sink.sink(ec, chunk)?
+                            } else {
+                                self.push_operators(chunk, ec, remaining, sink)?
+                            };
+                            if !more || matches!(sink_result, SinkResult::Finished) {
+                                break;
+                            }
+                        }
+                    }
+                    Ok(())
+                })
+        });
+        self.operators = operators;
+        out
+    }
+
     fn set_df_as_sources(&mut self, df: DataFrame) {
         let src = Box::new(DataFrameSource::from_df(df)) as Box<dyn Source>;
         self.set_sources(src)
@@ -209,8 +254,10 @@ impl PipeLine {
         let last_i = self.sinks.len() - 1;
         for (i, (operator_end, mut sink)) in std::mem::take(&mut self.sinks).into_iter().enumerate()
         {
+            let mut sink_finished = false;
             for src in &mut std::mem::take(&mut self.sources) {
                 while let SourceResult::GotMoreData(chunks) = src.get_batches(ec)? {
+                    ec.should_stop()?;
                     let results = self.par_process_chunks(
                         chunks,
                         &mut sink,
@@ -223,10 +270,14 @@ impl PipeLine {
                         .iter()
                         .any(|sink_result| matches!(sink_result, SinkResult::Finished))
                     {
+                        sink_finished = true;
                         break;
                     }
                 }
             }
+            if !sink_finished {
+                self.flush_operators(&mut sink, ec, operator_start, operator_end)?;
+            }
 
             let mut reduced_sink = POOL
                 .install(|| {
@@ -236,6 +287,7 @@ impl PipeLine {
                     })
                 })
                 .unwrap();
+            ec.should_stop()?;
             let sink_result = reduced_sink.finalize(ec)?;
             operator_start = operator_end;
 
@@ -356,6 +408,7 @@ fn consume_source(src: &mut dyn Source, context: &PExecutionContext) -> PolarsRe
     let mut frames = Vec::with_capacity(32);
 
     while let SourceResult::GotMoreData(batch) = src.get_batches(context)? {
+        context.should_stop()?;
         frames.extend(batch.into_iter().map(|chunk| chunk.data))
     }
     Ok(accumulate_dataframes_vertical_unchecked(frames))
@@ -20,6 +20,7 @@ pub(crate) const PARTITION_SIZE: usize = 64;
 // env vars
 pub(crate) static FORCE_OOC_GROUPBY: &str = "POLARS_FORCE_OOC_GROUPBY";
 pub(crate) static FORCE_OOC_SORT: &str = "POLARS_FORCE_OOC_SORT";
+pub(crate) static FORCE_OOC_JOIN: &str = "POLARS_FORCE_OOC_JOIN";
 
 /// ideal chunk size we strive to have
 /// scale the chunk size depending on the number of
@@ -133,9 +133,10 @@ impl FunctionExpr {
                     ConcatVertical(_) | ConcatHorizontal(_) => with_dtype(DataType::Utf8),
                     #[cfg(feature = "regex")]
                     Replace { .. } => with_dtype(DataType::Utf8),
-                    Uppercase | Lowercase | Strip(_) | LStrip(_) | RStrip(_) => {
+                    Uppercase | Lowercase | Slice(_, _) | Strip(_) | LStrip(_) | RStrip(_) => {
                         with_dtype(DataType::Utf8)
                     }
+                    Lengths | NChars => with_dtype(DataType::UInt32),
                     #[cfg(feature = "string_from_radix")]
                     FromRadix { .. } => with_dtype(DataType::Int32),
                 }
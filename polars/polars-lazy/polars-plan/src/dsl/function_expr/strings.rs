@@ -56,6 +56,9 @@ pub enum StringFunction {
     },
     Uppercase,
     Lowercase,
+    Slice(i64, Option<u64>),
+    Lengths,
+    NChars,
     Strip(Option<String>),
     RStrip(Option<String>),
     LStrip(Option<String>),
@@ -90,6 +93,9 @@ impl Display for StringFunction {
             StringFunction::Replace { .. } => "replace",
             StringFunction::Uppercase => "uppercase",
             StringFunction::Lowercase => "lowercase",
+            StringFunction::Slice(_, _) => "slice",
+            StringFunction::Lengths => "lengths",
+            StringFunction::NChars => "n_chars",
             StringFunction::Strip(_) => "strip",
             StringFunction::LStrip(_) => "lstrip",
             StringFunction::RStrip(_) => "rstrip",
@@ -111,6 +117,21 @@ pub(super) fn lowercase(s: &Series) -> PolarsResult<Series> {
     Ok(ca.to_lowercase().into_series())
 }
 
+pub(super) fn slice(s: &Series, start: i64, length: Option<u64>) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.str_slice(start, length)?.into_series())
+}
+
+pub(super) fn lengths(s: &Series) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.str_lengths().into_series())
+}
+
+pub(super) fn n_chars(s: &Series) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.str_n_chars().into_series())
+}
+
 #[cfg(feature = "regex")]
 pub(super) fn contains(s: &[Series], literal: bool, strict: bool) -> PolarsResult<Series> {
     let ca = &s[0].utf8()?;
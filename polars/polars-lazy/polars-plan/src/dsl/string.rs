@@ -398,6 +398,26 @@ impl StringNameSpace {
             .map_private(FunctionExpr::StringExpr(StringFunction::Uppercase))
     }
 
+    /// Slice the string values, `start` may be negative to count from the end.
+    pub fn slice(self, start: i64, length: Option<u64>) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::Slice(
+                start, length,
+            )))
+    }
+
+    /// Get the length of the string values in bytes.
+    pub fn lengths(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::Lengths))
+    }
+
+    /// Get the length of the string values in characters.
+    pub fn n_chars(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::NChars))
+    }
+
     #[cfg(feature = "string_from_radix")]
     /// Parse string in base radix into decimal
     pub fn from_radix(self, radix: u32, strict: bool) -> Expr {
@@ -10,6 +10,8 @@ pub struct OptState {
     #[cfg(feature = "cse")]
     pub common_subplan_elimination: bool,
     pub streaming: bool,
+    // the number of bytes the query may register before its operators spill to disk
+    pub memory_budget: Option<usize>,
 }
 
 impl Default for OptState {
@@ -25,6 +27,7 @@ impl Default for OptState {
             #[cfg(feature = "cse")]
             common_subplan_elimination: true,
             streaming: false,
+            memory_budget: None,
         }
     }
 }
@@ -113,9 +113,9 @@ impl AExpr {
                         Ok(field)
                     }
                     List(expr) => {
-                        // default context because `col()` would return a list in aggregation context
-                        let mut field =
-                            arena.get(*expr).to_field(schema, Context::Default, arena)?;
+                        // not the default context, as a `col()` in aggregation context
+                        // is a list per group that is wrapped in yet another list
+                        let mut field = arena.get(*expr).to_field(schema, ctxt, arena)?;
                         field.coerce(DataType::List(field.data_type().clone().into()));
                         Ok(field)
                     }
@@ -33,6 +33,37 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_slice_pushdown(&self) -> bool {
         false
     }
+    /// specify if the scan provider can produce its data in batches, see [`AnonymousScan::scan_batched`].
+    /// This allows the scan to be used by the streaming engine.
+    ///
+    /// Defaults to `false`
+    fn allows_streaming(&self) -> bool {
+        false
+    }
+    /// Creates a reader that produces the data of the supplied scan options in batches of
+    /// at most `chunk_size` rows. Only called if [`AnonymousScan::allows_streaming`] is `true`.
+    fn scan_batched(
+        &self,
+        _scan_opts: AnonymousScanOptions,
+        _chunk_size: usize,
+    ) -> PolarsResult<Box<dyn AnonymousScanBatches>> {
+        polars_bail!(ComputeError: "this scan cannot be read in batches");
+    }
+}
+
+/// A batched reader created by [`AnonymousScan::scan_batched`].
+pub trait AnonymousScanBatches: Send + Sync {
+    /// Read the next `n` batches, returns `None` if all data is read.
+    fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>>;
+}
+
+impl<F> AnonymousScanBatches for F
+where
+    F: FnMut(usize) -> PolarsResult<Option<Vec<DataFrame>>> + Send + Sync,
+{
+    fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
+        self(n)
+    }
 }
 
 impl<F> AnonymousScan for F
@@ -38,6 +38,47 @@ pub(crate) fn prepare_projection(
 
 pub struct LogicalPlanBuilder(LogicalPlan);
 
+/// Add the partition keys that are not stored in a hive partitioned file to its schema.
+#[cfg(any(feature = "parquet", feature = "parquet_async", feature = "ipc"))]
+fn with_hive_partitions(
+    mut file_info: FileInfo,
+    hive_partitions: Option<Arc<HivePartitions>>,
+) -> FileInfo {
+    if let Some(hive_partitions) = hive_partitions {
+        let mut hive_partitions = (*hive_partitions).clone();
+        if hive_partitions.file_schema().is_none() {
+            hive_partitions.set_file_schema(file_info.schema.clone());
+        }
+        if !hive_partitions.missing_columns().is_empty() {
+            let mut schema = (*file_info.schema).clone();
+            for field in hive_partitions.schema().iter_fields() {
+                if hive_partitions
+                    .missing_columns()
+                    .contains(&field.name.to_string())
+                {
+                    schema.with_column(field.name, field.dtype);
+                }
+            }
+            file_info.schema = Arc::new(schema);
+        }
+        file_info.hive_parts = Some(Arc::new(hive_partitions));
+    }
+    file_info
+}
+
+/// The [FileInfo] of a hive partitioned file whose schema is already known from
+/// another file of the dataset. The file itself is not touched.
+#[cfg(any(feature = "parquet", feature = "parquet_async", feature = "ipc"))]
+fn known_file_info(hive_partitions: Option<&Arc<HivePartitions>>) -> Option<FileInfo> {
+    let schema = hive_partitions?.file_schema()?.clone();
+    Some(FileInfo {
+        schema,
+        // unknown until the file is read
+        row_estimation: (None, usize::MAX),
+        hive_parts: None,
+    })
+}
+
 impl From<LogicalPlan> for LogicalPlanBuilder {
     fn from(lp: LogicalPlan) -> Self {
         LogicalPlanBuilder(lp)
@@ -91,6 +132,7 @@ impl LogicalPlanBuilder {
         let file_info = FileInfo {
             schema: schema.clone(),
             row_estimation: (n_rows, n_rows.unwrap_or(usize::MAX)),
+            hive_parts: None,
         };
         Ok(LogicalPlan::AnonymousScan {
             function,
@@ -121,37 +163,43 @@ impl LogicalPlanBuilder {
         low_memory: bool,
         cloud_options: Option<CloudOptions>,
         use_statistics: bool,
+        hive_partitions: Option<Arc<HivePartitions>>,
     ) -> PolarsResult<Self> {
         use polars_io::{is_cloud_url, SerReader as _};
 
         let path = path.into();
-        let file_info: PolarsResult<FileInfo> = if is_cloud_url(&path) {
-            #[cfg(not(feature = "async"))]
-            panic!(
+        let file_info: PolarsResult<FileInfo> =
+            if let Some(file_info) = known_file_info(hive_partitions.as_ref()) {
+                Ok(file_info)
+            } else if is_cloud_url(&path) {
+                #[cfg(not(feature = "async"))]
+                panic!(
                 "One or more of the cloud storage features ('aws', 'gcp', ...) must be enabled."
             );
 
-            #[cfg(feature = "async")]
-            {
-                let uri = path.to_string_lossy();
-                let (schema, num_rows) =
-                    ParquetAsyncReader::file_info(&uri, cloud_options.as_ref())?;
+                #[cfg(feature = "async")]
+                {
+                    let uri = path.to_string_lossy();
+                    let (schema, num_rows) =
+                        ParquetAsyncReader::file_info(&uri, cloud_options.as_ref())?;
+                    Ok(FileInfo {
+                        schema: Arc::new(schema),
+                        row_estimation: (Some(num_rows), num_rows),
+                        hive_parts: None,
+                    })
+                }
+            } else {
+                let file = std::fs::File::open(&path)?;
+                let mut reader = ParquetReader::new(file);
+                let schema = Arc::new(reader.schema()?);
+                let num_rows = reader.num_rows()?;
                 Ok(FileInfo {
-                    schema: Arc::new(schema),
+                    schema,
                     row_estimation: (Some(num_rows), num_rows),
+                    hive_parts: None,
                 })
-            }
-        } else {
-            let file = std::fs::File::open(&path)?;
-            let mut reader = ParquetReader::new(file);
-            let schema = Arc::new(reader.schema()?);
-            let num_rows = reader.num_rows()?;
-            Ok(FileInfo {
-                schema,
-                row_estimation: (Some(num_rows), num_rows),
-            })
-        };
-        let file_info = file_info?;
+            };
+        let file_info = with_hive_partitions(file_info?, hive_partitions);
 
         Ok(LogicalPlan::ParquetScan {
             path,
@@ -177,19 +225,27 @@ impl LogicalPlanBuilder {
     pub fn scan_ipc<P: Into<std::path::PathBuf>>(
         path: P,
         options: IpcScanOptions,
+        hive_partitions: Option<Arc<HivePartitions>>,
     ) -> PolarsResult<Self> {
         use polars_io::SerReader as _;
 
         let path = path.into();
-        let file = std::fs::File::open(&path)?;
-        let mut reader = IpcReader::new(file);
-        let schema = Arc::new(reader.schema()?);
-
-        let num_rows = reader._num_rows()?;
-        let file_info = FileInfo {
-            schema,
-            row_estimation: (None, num_rows),
+        let file_info = match known_file_info(hive_partitions.as_ref()) {
+            Some(file_info) => file_info,
+            None => {
+                let file = std::fs::File::open(&path)?;
+                let mut reader = IpcReader::new(file);
+                let schema = Arc::new(reader.schema()?);
+
+                let num_rows = reader._num_rows()?;
+                FileInfo {
+                    schema,
+                    row_estimation: (None, num_rows),
+                    hive_parts: None,
+                }
+            }
         };
+        let file_info = with_hive_partitions(file_info, hive_partitions);
         Ok(LogicalPlan::IpcScan {
             path,
             file_info,
@@ -203,7 +259,7 @@ impl LogicalPlanBuilder {
     #[cfg(feature = "csv-file")]
     pub fn scan_csv<P: Into<std::path::PathBuf>>(
         path: P,
-        delimiter: u8,
+        delimiter: Vec<u8>,
         has_header: bool,
         ignore_errors: bool,
         mut skip_rows: usize,
@@ -222,14 +278,16 @@ impl LogicalPlanBuilder {
         encoding: CsvEncoding,
         row_count: Option<RowCount>,
         try_parse_dates: bool,
+        truncated_rows: bool,
     ) -> PolarsResult<Self> {
+        polars_ensure!(!delimiter.is_empty(), InvalidOperation: "CSV delimiter cannot be empty");
         let path = path.into();
         let mut file = std::fs::File::open(&path)?;
-        let mut magic_nr = [0u8; 2];
-        file.read_exact(&mut magic_nr)
-            .map_err(|_| polars_err!(NoData: "empty csv"))?;
+        let mut magic_nr = [0u8; 4];
+        let n = file.read(&mut magic_nr)?;
+        polars_ensure!(n > 0, NoData: "empty csv");
         polars_ensure!(
-            !is_compressed(&magic_nr),
+            !is_compressed(&magic_nr[..n]),
             ComputeError: "cannot scan compressed csv; use `read_csv` for compressed data",
         );
         file.rewind()?;
@@ -239,7 +297,7 @@ impl LogicalPlanBuilder {
         // this needs a way to estimated bytes/rows.
         let (inferred_schema, rows_read, bytes_read) = infer_file_schema(
             &reader_bytes,
-            delimiter,
+            &delimiter,
             infer_schema_length,
             has_header,
             schema_overwrite,
@@ -260,6 +318,7 @@ impl LogicalPlanBuilder {
         let file_info = FileInfo {
             schema,
             row_estimation: (None, estimated_n_rows),
+            hive_parts: None,
         };
         Ok(LogicalPlan::CsvScan {
             path,
@@ -281,6 +340,7 @@ impl LogicalPlanBuilder {
                 encoding,
                 row_count,
                 try_parse_dates,
+                truncated_rows,
                 file_counter: Default::default(),
             },
             predicate: None,
@@ -0,0 +1,361 @@
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+#[cfg(any(feature = "dtype-datetime", feature = "dtype-time"))]
+use polars_core::utils::arrow::temporal_conversions::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Directory value that hive uses for a partition with a null key.
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// The partition values of a file in a hive partitioned dataset.
+///
+/// Hive partitioning stores the values of the partition keys in the directory
+/// names of a file, e.g. `year=2023/month=1/data.parquet`, which is also the
+/// layout that `polars_io::partition::PartitionedWriter` writes. Every
+/// `key=value` directory is a column with a constant value for that file.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HivePartitions {
+    /// A single row with the value of every partition key.
+    values: DataFrame,
+    /// The schema of the data that is stored in the file, if it is known.
+    file_schema: Option<SchemaRef>,
+    /// The partition keys that are not stored in the file itself and
+    /// must be added to the data that is read from it.
+    missing: Vec<String>,
+}
+
+impl HivePartitions {
+    /// Parse the partitions of a set of files that are scanned together.
+    ///
+    /// The data type of every partition key is inferred from its values in all
+    /// paths, so that the files can be concatenated. Returns `None` for files
+    /// that are not partitioned.
+    pub fn try_from_paths(paths: &[PathBuf]) -> PolarsResult<Vec<Option<Arc<Self>>>> {
+        let parsed = paths.iter().map(|p| parse_path(p)).collect::<Vec<_>>();
+        let Some(first) = parsed.first() else {
+            return Ok(vec![]);
+        };
+        for (path, parts) in paths.iter().zip(&parsed) {
+            polars_ensure!(
+                parts.iter().map(|(k, _)| k).eq(first.iter().map(|(k, _)| k)),
+                ComputeError: "hive partition keys of {} differ from those of {}",
+                path.display(), paths[0].display(),
+            );
+        }
+        if first.is_empty() {
+            return Ok(vec![None; paths.len()]);
+        }
+
+        let dtypes = (0..first.len())
+            .map(|i| infer_dtype(parsed.iter().map(|parts| parts[i].1.as_deref())))
+            .collect::<Vec<_>>();
+        parsed
+            .iter()
+            .map(|parts| {
+                let columns = parts
+                    .iter()
+                    .zip(&dtypes)
+                    .map(|((key, value), dtype)| to_series(key, value.as_deref(), dtype))
+                    .collect();
+                Ok(Some(Arc::new(Self {
+                    values: DataFrame::new(columns)?,
+                    file_schema: None,
+                    missing: vec![],
+                })))
+            })
+            .collect()
+    }
+
+    /// The schema of the partition keys.
+    pub fn schema(&self) -> Schema {
+        self.values.schema()
+    }
+
+    /// A single row with the value of every partition key.
+    pub fn get_values(&self) -> &DataFrame {
+        &self.values
+    }
+
+    /// Register the schema of the partitioned file, the partition keys
+    /// that it does not contain are materialized when the file is read.
+    ///
+    /// The files of a dataset share their schema, so it only has to be read
+    /// from one of them. A scan with a known file schema does not touch the
+    /// file before it is executed, which allows it to be pruned first.
+    pub fn set_file_schema(&mut self, file_schema: SchemaRef) {
+        self.missing = self
+            .values
+            .get_column_names()
+            .into_iter()
+            .filter(|name| !file_schema.contains(name))
+            .map(|name| name.to_string())
+            .collect();
+        self.file_schema = Some(file_schema);
+    }
+
+    /// The schema of the data that is stored in the file, if it is known.
+    pub fn file_schema(&self) -> Option<&SchemaRef> {
+        self.file_schema.as_ref()
+    }
+
+    /// The partition keys that are not stored in the file.
+    pub fn missing_columns(&self) -> &[String] {
+        &self.missing
+    }
+
+    /// Add the partition keys that are not stored in the file to `df`, as
+    /// columns with the height of `df`. Only the keys in `with_columns` are
+    /// added if it is given.
+    pub fn materialize(
+        &self,
+        df: &mut DataFrame,
+        with_columns: Option<&[String]>,
+    ) -> PolarsResult<()> {
+        let height = df.height();
+        for name in &self.missing {
+            if df.find_idx_by_name(name).is_some()
+                || with_columns.map_or(false, |columns| !columns.contains(name))
+            {
+                continue;
+            }
+            let s = self.values.column(name)?;
+            df.with_column(s.new_from_index(0, height))?;
+        }
+        Ok(())
+    }
+}
+
+/// The name of the `key=value` directory of a partition, in the layout that is
+/// parsed by [`HivePartitions::try_from_paths`].
+///
+/// Null values are written as `__HIVE_DEFAULT_PARTITION__` and string values are
+/// quoted. Every value is written without loss, so that distinct values never share a
+/// directory: floats in their shortest form that parses back to the same value and
+/// datetimes and times in the basic ISO 8601 format, which has no `:`. Binary, list
+/// and struct values have no such form and return an error. The characters that
+/// cannot be part of a directory name, as well as `=` and `%`, are percent-encoded.
+pub fn hive_partition_dir(key: &str, value: &AnyValue) -> PolarsResult<String> {
+    let value = match value {
+        AnyValue::Null => return Ok(format!("{}={HIVE_DEFAULT_PARTITION}", escape(key))),
+        AnyValue::Boolean(_)
+        | AnyValue::UInt8(_)
+        | AnyValue::UInt16(_)
+        | AnyValue::UInt32(_)
+        | AnyValue::UInt64(_)
+        | AnyValue::Int8(_)
+        | AnyValue::Int16(_)
+        | AnyValue::Int32(_)
+        | AnyValue::Int64(_) => value.to_string(),
+        // the `Display` of `AnyValue` rounds floats
+        AnyValue::Float32(v) => v.to_string(),
+        AnyValue::Float64(v) => v.to_string(),
+        #[cfg(feature = "dtype-date")]
+        AnyValue::Date(_) => value.to_string(),
+        #[cfg(feature = "dtype-datetime")]
+        AnyValue::Datetime(v, tu, _) => {
+            let ndt = match tu {
+                TimeUnit::Nanoseconds => timestamp_ns_to_datetime(*v),
+                TimeUnit::Microseconds => timestamp_us_to_datetime(*v),
+                TimeUnit::Milliseconds => timestamp_ms_to_datetime(*v),
+            };
+            ndt.format("%Y%m%dT%H%M%S%.f").to_string()
+        }
+        #[cfg(feature = "dtype-duration")]
+        AnyValue::Duration(v, tu) => {
+            let unit = match tu {
+                TimeUnit::Nanoseconds => "ns",
+                TimeUnit::Microseconds => "us",
+                TimeUnit::Milliseconds => "ms",
+            };
+            format!("{v}{unit}")
+        }
+        #[cfg(feature = "dtype-time")]
+        AnyValue::Time(v) => time64ns_to_time(*v).format("%H%M%S%.f").to_string(),
+        #[cfg(feature = "dtype-decimal")]
+        AnyValue::Decimal(_, _) => value.to_string(),
+        value => match value.get_str() {
+            Some(s) => format!("\"{s}\""),
+            None => polars_bail!(
+                ComputeError: "cannot partition by '{}', values of dtype {} have no directory name",
+                key, value.dtype(),
+            ),
+        },
+    };
+    Ok(format!("{}={}", escape(key), escape(&value)))
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '%' | '/' | '\\' | '=' => out.push_str(&format!("%{:02X}", c as u8)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Decode the `%XX` escapes of [`escape`], other `%` characters are kept as is.
+fn unescape(s: &str) -> Cow<str> {
+    if !s.contains('%') {
+        return Cow::Borrowed(s);
+    }
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let decoded = (bytes[i] == b'%')
+            .then(|| s.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match decoded {
+            Some(byte) => {
+                out.push(byte);
+                i += 3;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    match String::from_utf8(out) {
+        Ok(out) => Cow::Owned(out),
+        Err(_) => Cow::Borrowed(s),
+    }
+}
+
+/// The `key=value` directories of `path`, with `None` for null values.
+fn parse_path(path: &Path) -> Vec<(Cow<str>, Option<Cow<str>>)> {
+    let mut parts: Vec<(Cow<str>, Option<Cow<str>>)> = vec![];
+    let dirs = path.parent().into_iter().flat_map(|p| p.components());
+    for dir in dirs {
+        let Some((key, value)) = dir.as_os_str().to_str().and_then(|d| d.split_once('=')) else {
+            continue;
+        };
+        if key.is_empty() {
+            continue;
+        }
+        let key = unescape(key);
+        // `PartitionedWriter` quotes string values
+        let value = match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+            Some(value) => Some(unescape(value)),
+            None if value == "null" || value == HIVE_DEFAULT_PARTITION => None,
+            None => Some(unescape(value)),
+        };
+        // the innermost directory wins if a key is repeated
+        match parts.iter_mut().find(|(k, _)| *k == key) {
+            Some(part) => part.1 = value,
+            None => parts.push((key, value)),
+        }
+    }
+    parts
+}
+
+/// The narrowest of `Int64`, `Float64`, `Boolean` and `Utf8` that can hold all values.
+fn infer_dtype<'a>(values: impl Iterator<Item = Option<&'a str>> + Clone) -> DataType {
+    let mut values = values.flatten();
+    if values.clone().all(|v| v.parse::<i64>().is_ok()) {
+        DataType::Int64
+    } else if values.clone().all(|v| v.parse::<f64>().is_ok()) {
+        DataType::Float64
+    } else if values.all(|v| v == "true" || v == "false") {
+        DataType::Boolean
+    } else {
+        DataType::Utf8
+    }
+}
+
+fn to_series(key: &str, value: Option<&str>, dtype: &DataType) -> Series {
+    // the values were checked by `infer_dtype`
+    match dtype {
+        DataType::Int64 => Series::new(key, &[value.map(|v| v.parse::<i64>().unwrap())]),
+        DataType::Float64 => Series::new(key, &[value.map(|v| v.parse::<f64>().unwrap())]),
+        DataType::Boolean => Series::new(key, &[value.map(|v| v == "true")]),
+        _ => Series::new(key, &[value]),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hive_partitions_from_paths() -> PolarsResult<()> {
+        let paths = [
+            "/data/year=2022/city=\"Utrecht\"/flag=true/data-0000.parquet",
+            "/data/year=2023/city=null/flag=false/data-0001.parquet",
+            "/data/year=2023/city=Amsterdam/flag=true/data-0002.parquet",
+        ]
+        .map(PathBuf::from);
+        let parts = HivePartitions::try_from_paths(&paths)?;
+        assert_eq!(parts.len(), 3);
+
+        let schema = parts[0].as_ref().unwrap().schema();
+        assert_eq!(
+            schema.iter_dtypes().cloned().collect::<Vec<_>>(),
+            &[DataType::Int64, DataType::Utf8, DataType::Boolean]
+        );
+        let values = parts[1].as_ref().unwrap().get_values();
+        let expected = df! {
+            "year" => [2023i64],
+            "city" => [None::<&str>],
+            "flag" => [false],
+        }?;
+        assert!(values.frame_equal_missing(&expected));
+
+        let mut parts = (**parts[2].as_ref().unwrap()).clone();
+        parts.set_file_schema(Arc::new(Schema::from_iter([Field::new(
+            "year",
+            DataType::Int64,
+        )])));
+        assert_eq!(parts.missing_columns(), &["city", "flag"]);
+        let mut df = df! { "year" => [2023i64, 2023], "x" => [1, 2] }?;
+        parts.materialize(&mut df, Some(&["x".to_string(), "city".to_string()]))?;
+        assert_eq!(df.get_column_names(), &["year", "x", "city"]);
+        assert_eq!(df.column("city")?.utf8()?.get(1), Some("Amsterdam"));
+
+        // directory names written by sinks are parsed back to their values
+        let dir = hive_partition_dir("city", &AnyValue::Utf8("a/b=50%"))?;
+        assert_eq!(dir, "city=\"a%2Fb%3D50%25\"");
+        let null_dir = hive_partition_dir("year", &AnyValue::Null)?;
+        assert_eq!(null_dir, "year=__HIVE_DEFAULT_PARTITION__");
+        let path = PathBuf::from(format!("/data/{null_dir}/{dir}/data-0000.parquet"));
+        let parts = HivePartitions::try_from_paths(&[path])?;
+        let values = parts[0].as_ref().unwrap().get_values();
+        assert_eq!(values.column("year")?.null_count(), 1);
+        assert_eq!(values.column("city")?.utf8()?.get(0), Some("a/b=50%"));
+
+        // files without partitions
+        let parts = HivePartitions::try_from_paths(&[PathBuf::from("/data/file.parquet")])?;
+        assert!(parts[0].is_none());
+        // keys must be the same in all files
+        let paths = ["/data/a=1/file.parquet", "/data/b=1/file.parquet"].map(PathBuf::from);
+        assert!(HivePartitions::try_from_paths(&paths).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hive_partition_dir_lossless() -> PolarsResult<()> {
+        // floats that only differ after the 6th significant digit
+        let a = hive_partition_dir("x", &AnyValue::Float64(1.0000001))?;
+        let b = hive_partition_dir("x", &AnyValue::Float64(1.0000002))?;
+        assert_ne!(a, b);
+        let paths = [a, b].map(|dir| PathBuf::from(format!("/data/{dir}/data.parquet")));
+        let parts = HivePartitions::try_from_paths(&paths)?;
+        let values = parts[1].as_ref().unwrap().get_values();
+        assert_eq!(values.column("x")?.f64()?.get(0), Some(1.0000002));
+
+        #[cfg(feature = "dtype-datetime")]
+        {
+            let dt = AnyValue::Datetime(1_500, TimeUnit::Milliseconds, &None);
+            assert_eq!(hive_partition_dir("t", &dt)?, "t=19700101T000001.500");
+        }
+        assert!(hive_partition_dir("b", &AnyValue::Binary(b"ab")).is_err());
+        Ok(())
+    }
+}
@@ -22,6 +22,7 @@ pub(crate) mod conversion;
 pub(crate) mod debug;
 mod format;
 mod functions;
+mod hive;
 pub(crate) mod iterator;
 mod lit;
 pub(crate) mod optimizer;
@@ -38,6 +39,7 @@ pub use apply::*;
 pub use builder::*;
 pub use conversion::*;
 pub use functions::*;
+pub use hive::*;
 pub use iterator::*;
 pub use lit::*;
 pub use optimizer::*;
@@ -1,12 +1,13 @@
 use std::path::PathBuf;
 
+use polars_core::cloud::CloudOptions;
 use polars_core::prelude::*;
 #[cfg(feature = "csv-file")]
-use polars_io::csv::{CsvEncoding, NullValues};
+use polars_io::csv::{CsvEncoding, NullValues, SerializeOptions};
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcCompression;
 #[cfg(feature = "parquet")]
-use polars_io::parquet::ParquetCompression;
+use polars_io::parquet::{ParquetColumnOptions, ParquetCompression};
 use polars_io::RowCount;
 #[cfg(feature = "dynamic_groupby")]
 use polars_time::{DynamicGroupOptions, RollingGroupOptions};
@@ -21,7 +22,7 @@ pub type FileCount = u32;
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CsvParserOptions {
-    pub delimiter: u8,
+    pub delimiter: Vec<u8>,
     pub comment_char: Option<u8>,
     pub quote_char: Option<u8>,
     pub eol_char: u8,
@@ -37,6 +38,7 @@ pub struct CsvParserOptions {
     pub encoding: CsvEncoding,
     pub row_count: Option<RowCount>,
     pub try_parse_dates: bool,
+    pub truncated_rows: bool,
     pub file_counter: FileCount,
 }
 
@@ -56,7 +58,7 @@ pub struct ParquetOptions {
 }
 
 #[cfg(feature = "parquet")]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetWriteOptions {
     /// Data page compression
@@ -69,6 +71,10 @@ pub struct ParquetWriteOptions {
     pub data_pagesize_limit: Option<usize>,
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// Encoding and compression that overrule the defaults of the named columns.
+    pub column_options: Vec<(String, ParquetColumnOptions)>,
+    /// Key-value pairs written to the file footer.
+    pub key_value_metadata: Vec<(String, String)>,
 }
 
 #[cfg(feature = "ipc")]
@@ -81,6 +87,40 @@ pub struct IpcWriterOptions {
     pub maintain_order: bool,
 }
 
+#[cfg(feature = "csv-file")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CsvWriterOptions {
+    /// Write the names of the columns as the first line
+    pub include_header: bool,
+    /// Number of rows that are serialized at once
+    pub batch_size: usize,
+    /// maintain the order the data was processed
+    pub maintain_order: bool,
+    /// delimiter, quoting character, null value and the formats of temporal and float values
+    pub serialize_options: SerializeOptions,
+}
+
+#[cfg(feature = "csv-file")]
+impl Default for CsvWriterOptions {
+    fn default() -> Self {
+        Self {
+            include_header: true,
+            batch_size: 1024,
+            maintain_order: false,
+            serialize_options: SerializeOptions::default(),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JsonWriterOptions {
+    /// maintain the order the data was processed
+    pub maintain_order: bool,
+}
+
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IpcScanOptions {
@@ -302,9 +342,34 @@ pub struct AnonymousScanOptions {
 pub struct FileSinkOptions {
     pub path: Arc<PathBuf>,
     pub file_type: FileType,
+    /// Write to a directory of partitioned files instead of to a single file at `path`.
+    pub partitioning: Option<SinkPartitionOptions>,
+    /// Options for the object store if `path` is a cloud url, e.g. `s3://bucket/file.parquet`.
+    pub cloud_options: Option<CloudOptions>,
+}
+
+/// Split the output of a sink over multiple files.
+///
+/// The files are written to `key=value` directories in the same layout as
+/// `polars_io::partition::PartitionedWriter`, e.g. `path/year=2023/data-0000.parquet`.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SinkPartitionOptions {
+    /// Columns whose values determine the directory a row is written to. If empty,
+    /// all files are written to the root directory.
+    pub by: Vec<String>,
+    /// Start a new file in a partition after this many rows are written to it.
+    pub max_rows_per_file: Option<usize>,
+    /// Start a new file in a partition after this many bytes are written to it.
+    pub max_bytes_per_file: Option<u64>,
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv-file",
+    feature = "json"
+))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug)]
 pub enum FileType {
@@ -312,7 +377,16 @@ pub enum FileType {
     Parquet(ParquetWriteOptions),
     #[cfg(feature = "ipc")]
     Ipc(IpcWriterOptions),
+    #[cfg(feature = "csv-file")]
+    Csv(CsvWriterOptions),
+    #[cfg(feature = "json")]
+    Json(JsonWriterOptions),
 }
 
-#[cfg(not(any(feature = "parquet", feature = "ipc")))]
+#[cfg(not(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv-file",
+    feature = "json"
+)))]
 pub type FileType = ();
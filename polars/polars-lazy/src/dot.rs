@@ -21,6 +21,8 @@ impl LazyFrame {
                 &mut lp_arena,
                 &mut expr_arena,
                 &mut vec![],
+                &Default::default(),
+                &Default::default(),
                 true,
             )?;
             logical_plan = node_to_lp(lp_top, &expr_arena, &mut lp_arena);
@@ -12,7 +12,7 @@ use crate::prelude::*;
 #[cfg(feature = "csv-file")]
 pub struct LazyCsvReader<'a> {
     path: PathBuf,
-    delimiter: u8,
+    delimiter: Vec<u8>,
     has_header: bool,
     ignore_errors: bool,
     skip_rows: usize,
@@ -32,6 +32,7 @@ pub struct LazyCsvReader<'a> {
     encoding: CsvEncoding,
     row_count: Option<RowCount>,
     try_parse_dates: bool,
+    truncated_rows: bool,
 }
 
 #[cfg(feature = "csv-file")]
@@ -39,7 +40,7 @@ impl<'a> LazyCsvReader<'a> {
     pub fn new(path: impl AsRef<Path>) -> Self {
         LazyCsvReader {
             path: path.as_ref().to_owned(),
-            delimiter: b',',
+            delimiter: vec![b','],
             has_header: true,
             ignore_errors: false,
             skip_rows: 0,
@@ -59,6 +60,7 @@ impl<'a> LazyCsvReader<'a> {
             encoding: CsvEncoding::Utf8,
             row_count: None,
             try_parse_dates: false,
+            truncated_rows: false,
         }
     }
 
@@ -132,7 +134,14 @@ impl<'a> LazyCsvReader<'a> {
     /// Set the CSV file's column delimiter as a byte character
     #[must_use]
     pub fn with_delimiter(mut self, delimiter: u8) -> Self {
-        self.delimiter = delimiter;
+        self.delimiter = vec![delimiter];
+        self
+    }
+
+    /// Set the CSV file's column delimiter as one or more bytes, e.g. `"||"`.
+    #[must_use]
+    pub fn with_multi_char_delimiter(mut self, delimiter: impl AsRef<[u8]>) -> Self {
+        self.delimiter = delimiter.as_ref().to_vec();
         self
     }
 
@@ -170,6 +179,13 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
+    /// Expect rows that have fewer fields than the schema. The missing fields are read as null.
+    #[must_use]
+    pub fn with_truncated_rows(mut self, toggle: bool) -> Self {
+        self.truncated_rows = toggle;
+        self
+    }
+
     /// Cache the DataFrame after reading.
     #[must_use]
     pub fn with_cache(mut self, cache: bool) -> Self {
@@ -219,7 +235,7 @@ impl<'a> LazyCsvReader<'a> {
 
         let (schema, _, _) = infer_file_schema(
             &reader_bytes,
-            self.delimiter,
+            &self.delimiter,
             self.infer_schema_length,
             self.has_header,
             // we set it to None and modify them after the schema is updated
@@ -268,6 +284,7 @@ impl LazyFileListReader for LazyCsvReader<'_> {
             self.encoding,
             self.row_count,
             self.try_parse_dates,
+            self.truncated_rows,
         )?
         .build()
         .into();
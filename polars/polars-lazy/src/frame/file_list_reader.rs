@@ -36,12 +36,34 @@ pub trait LazyFileListReader: Clone {
     /// Get the final [LazyFrame].
     fn finish(self) -> PolarsResult<LazyFrame> {
         if let Some(paths) = self.glob()? {
+            let paths = paths.collect::<PolarsResult<Vec<_>>>()?;
+            polars_ensure!(
+                !paths.is_empty(),
+                ComputeError: "no matching files found in {}", self.path().display()
+            );
+            let hive_parts = if self.hive_partitioning() {
+                let mut hive_parts = HivePartitions::try_from_paths(&paths)?;
+                if hive_parts[0].is_some() {
+                    // only the first file is read while building the plan, the others
+                    // are first opened when they are scanned, after partition pruning
+                    let file_schema = self.clone().with_path(paths[0].clone()).file_schema()?;
+                    for parts in hive_parts.iter_mut().flatten() {
+                        Arc::make_mut(parts).set_file_schema(file_schema.clone());
+                    }
+                }
+                hive_parts
+            } else {
+                vec![None; paths.len()]
+            };
+
             let lfs = paths
-                .map(|r| {
-                    let path = r?;
+                .into_iter()
+                .zip(hive_parts)
+                .map(|(path, hive_parts)| {
                     self.clone()
                         .with_path(path.clone())
                         .with_rechunk(false)
+                        .with_hive_partitions(hive_parts)
                         .finish_no_glob()
                         .map_err(|e| {
                             polars_err!(
@@ -51,11 +73,6 @@ pub trait LazyFileListReader: Clone {
                 })
                 .collect::<PolarsResult<Vec<_>>>()?;
 
-            polars_ensure!(
-                !lfs.is_empty(),
-                ComputeError: "no matching files found in {}", self.path().display()
-            );
-
             let mut lf = self.concat_impl(lfs)?;
             if let Some(n_rows) = self.n_rows() {
                 lf = lf.slice(0, n_rows as IdxSize)
@@ -65,6 +82,10 @@ pub trait LazyFileListReader: Clone {
             };
 
             Ok(lf)
+        } else if self.hive_partitioning() {
+            let path = self.path().to_path_buf();
+            let hive_parts = HivePartitions::try_from_paths(&[path])?.pop().flatten();
+            self.with_hive_partitions(hive_parts).finish_no_glob()
         } else {
             self.finish_no_glob()
         }
@@ -107,6 +128,35 @@ pub trait LazyFileListReader: Clone {
     /// Add a `row_count` column.
     fn row_count(&self) -> Option<&RowCount>;
 
+    /// Parse the partition keys of hive partitioned files from their paths.
+    fn hive_partitioning(&self) -> bool {
+        false
+    }
+
+    /// Set the hive partitions of the scanned file.
+    #[must_use]
+    fn with_hive_partitions(self, _hive_partitions: Option<Arc<HivePartitions>>) -> Self {
+        self
+    }
+
+    /// The schema of the data that is stored in the scanned file.
+    fn file_schema(&self) -> PolarsResult<SchemaRef> {
+        let schema = self
+            .clone()
+            .with_hive_partitions(None)
+            .finish_no_glob()?
+            .schema()?;
+        Ok(match self.row_count() {
+            Some(rc) => Arc::new(
+                schema
+                    .iter_fields()
+                    .filter(|field| field.name() != rc.name.as_str())
+                    .collect(),
+            ),
+            None => schema,
+        })
+    }
+
     /// [CloudOptions] used to list files.
     fn cloud_options(&self) -> Option<&CloudOptions> {
         None
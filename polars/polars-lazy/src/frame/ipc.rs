@@ -12,6 +12,7 @@ pub struct ScanArgsIpc {
     pub rechunk: bool,
     pub row_count: Option<RowCount>,
     pub memmap: bool,
+    pub hive_partitioning: bool,
 }
 
 impl Default for ScanArgsIpc {
@@ -22,6 +23,7 @@ impl Default for ScanArgsIpc {
             rechunk: true,
             row_count: None,
             memmap: true,
+            hive_partitioning: false,
         }
     }
 }
@@ -30,11 +32,16 @@ impl Default for ScanArgsIpc {
 struct LazyIpcReader {
     args: ScanArgsIpc,
     path: PathBuf,
+    hive_partitions: Option<Arc<HivePartitions>>,
 }
 
 impl LazyIpcReader {
     fn new(path: PathBuf, args: ScanArgsIpc) -> Self {
-        Self { args, path }
+        Self {
+            args,
+            path,
+            hive_partitions: None,
+        }
     }
 }
 
@@ -51,7 +58,9 @@ impl LazyFileListReader for LazyIpcReader {
             memmap: args.memmap,
         };
         let row_count = args.row_count;
-        let mut lf: LazyFrame = LogicalPlanBuilder::scan_ipc(path, options)?.build().into();
+        let mut lf: LazyFrame = LogicalPlanBuilder::scan_ipc(path, options, self.hive_partitions)?
+            .build()
+            .into();
         lf.opt_state.file_caching = true;
 
         // it is a bit hacky, but this row_count function updates the schema
@@ -87,6 +96,15 @@ impl LazyFileListReader for LazyIpcReader {
     fn row_count(&self) -> Option<&RowCount> {
         self.args.row_count.as_ref()
     }
+
+    fn hive_partitioning(&self) -> bool {
+        self.args.hive_partitioning
+    }
+
+    fn with_hive_partitions(mut self, hive_partitions: Option<Arc<HivePartitions>>) -> Self {
+        self.hive_partitions = hive_partitions;
+        self
+    }
 }
 
 impl LazyFrame {
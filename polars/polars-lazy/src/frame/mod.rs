@@ -1,4 +1,6 @@
 //! Lazy variant of a [DataFrame](polars_core::frame::DataFrame).
+#[cfg(feature = "avro")]
+mod avro;
 #[cfg(feature = "csv-file")]
 mod csv;
 #[cfg(feature = "ipc")]
@@ -16,11 +18,19 @@ mod file_list_reader;
 pub mod pivot;
 
 use std::borrow::Cow;
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv-file",
+    feature = "json"
+))]
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 pub use anonymous_scan::*;
+#[cfg(feature = "avro")]
+pub use avro::*;
 #[cfg(feature = "csv-file")]
 pub use csv::*;
 pub use file_list_reader::*;
@@ -31,8 +41,12 @@ pub use ndjson::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
 use polars_arrow::prelude::QuantileInterpolOptions;
+use polars_core::cancel::CancellationToken;
+#[cfg(feature = "async")]
+use polars_core::cloud::CloudOptions;
 use polars_core::frame::explode::MeltArgs;
 use polars_core::frame::hash_join::JoinType;
+use polars_core::memory::QueryMemory;
 use polars_core::prelude::*;
 use polars_io::RowCount;
 pub use polars_plan::frame::{AllowedOptimizations, OptState};
@@ -116,6 +130,7 @@ impl LazyFrame {
 
     /// Turn off all optimizations
     pub fn without_optimizations(self) -> Self {
+        let memory_budget = self.opt_state.memory_budget;
         self.with_optimizations(OptState {
             projection_pushdown: false,
             predicate_pushdown: false,
@@ -127,6 +142,7 @@ impl LazyFrame {
             #[cfg(feature = "cse")]
             common_subplan_elimination: false,
             streaming: false,
+            memory_budget,
         })
     }
 
@@ -173,6 +189,16 @@ impl LazyFrame {
         self
     }
 
+    /// Set the number of bytes this query may hold in memory. Once the sinks of the streaming
+    /// engine and the caches together exceed the budget, the sinks will spill to disk.
+    ///
+    /// The limit of all queries in the process can be set on the
+    /// [`MEMORY_MANAGER`](polars_core::memory::MEMORY_MANAGER).
+    pub fn with_memory_budget(mut self, budget: Option<usize>) -> Self {
+        self.opt_state.memory_budget = budget;
+        self
+    }
+
     /// Describe the logical plan.
     pub fn describe_plan(&self) -> String {
         self.logical_plan.describe()
@@ -186,6 +212,8 @@ impl LazyFrame {
             &mut lp_arena,
             &mut expr_arena,
             &mut vec![],
+            &Default::default(),
+            &Default::default(),
             true,
         )?;
         let logical_plan = node_to_lp(lp_top, &expr_arena, &mut lp_arena);
@@ -434,7 +462,14 @@ impl LazyFrame {
         lp_arena: &mut Arena<ALogicalPlan>,
         expr_arena: &mut Arena<AExpr>,
     ) -> PolarsResult<Node> {
-        self.optimize_with_scratch(lp_arena, expr_arena, &mut vec![], false)
+        self.optimize_with_scratch(
+            lp_arena,
+            expr_arena,
+            &mut vec![],
+            &Default::default(),
+            &Default::default(),
+            false,
+        )
     }
 
     pub(crate) fn optimize_with_scratch(
@@ -442,6 +477,8 @@ impl LazyFrame {
         lp_arena: &mut Arena<ALogicalPlan>,
         expr_arena: &mut Arena<AExpr>,
         scratch: &mut Vec<Node>,
+        _memory: &QueryMemory,
+        _cancel_token: &CancellationToken,
         _fmt: bool,
     ) -> PolarsResult<Node> {
         #[allow(unused_mut)]
@@ -457,7 +494,15 @@ impl LazyFrame {
         if streaming {
             #[cfg(feature = "streaming")]
             {
-                insert_streaming_nodes(lp_top, lp_arena, expr_arena, scratch, _fmt)?;
+                insert_streaming_nodes(
+                    lp_top,
+                    lp_arena,
+                    expr_arena,
+                    scratch,
+                    _memory,
+                    _cancel_token,
+                    _fmt,
+                )?;
             }
             #[cfg(not(feature = "streaming"))]
             {
@@ -468,16 +513,24 @@ impl LazyFrame {
     }
 
     #[allow(unused_mut)]
-    fn prepare_collect(
+    pub(crate) fn prepare_collect(
         mut self,
         check_sink: bool,
+        cancel_token: &CancellationToken,
     ) -> PolarsResult<(ExecutionState, Box<dyn Executor>, bool)> {
         let file_caching = self.opt_state.file_caching;
         let mut expr_arena = Arena::with_capacity(256);
         let mut lp_arena = Arena::with_capacity(128);
         let mut scratch = vec![];
-        let lp_top =
-            self.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut scratch, false)?;
+        let memory = QueryMemory::new(self.opt_state.memory_budget);
+        let lp_top = self.optimize_with_scratch(
+            &mut lp_arena,
+            &mut expr_arena,
+            &mut scratch,
+            &memory,
+            cancel_token,
+            false,
+        )?;
 
         let finger_prints = if file_caching {
             #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv-file"))]
@@ -500,9 +553,17 @@ impl LazyFrame {
         } else {
             true
         };
-        let physical_plan = create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?;
+        let physical_plan = create_physical_plan(
+            lp_top,
+            &mut lp_arena,
+            &mut expr_arena,
+            &memory,
+            cancel_token,
+        )?;
 
-        let state = ExecutionState::with_finger_prints(finger_prints);
+        let mut state = ExecutionState::with_finger_prints(finger_prints);
+        state.memory = memory;
+        state.cancel_token = cancel_token.clone();
         Ok((state, physical_plan, no_file_sink))
     }
 
@@ -523,16 +584,48 @@ impl LazyFrame {
     /// }
     /// ```
     pub fn collect(self) -> PolarsResult<DataFrame> {
-        let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
+        self.collect_with_token(&Default::default())
+    }
+
+    /// Execute the query like [`LazyFrame::collect`], but stop with an error once the
+    /// `cancel_token` is cancelled from another thread or times out.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::cancel::CancellationToken;
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> PolarsResult<DataFrame> {
+    ///     let token = CancellationToken::new();
+    ///     // e.g. hand a clone to a thread that calls `token.cancel()`
+    ///     df.lazy()
+    ///       .groupby([col("foo")])
+    ///       .agg([col("bar").sum()])
+    ///       .collect_with_token(&token)
+    /// }
+    /// ```
+    pub fn collect_with_token(self, cancel_token: &CancellationToken) -> PolarsResult<DataFrame> {
+        let (mut state, mut physical_plan, _) = self.prepare_collect(false, cancel_token)?;
         let out = physical_plan.execute(&mut state);
         #[cfg(debug_assertions)]
         {
+            // a stopped query may not have read all its cached files
             #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv-file"))]
-            state.file_cache.assert_empty();
+            if out.is_ok() {
+                state.file_cache.assert_empty();
+            }
         }
         out
     }
 
+    /// Execute the query like [`LazyFrame::collect`], but stop with a
+    /// [`PolarsError::Timeout`] if it doesn't finish within `timeout`.
+    pub fn collect_with_timeout(self, timeout: Duration) -> PolarsResult<DataFrame> {
+        self.collect_with_token(&CancellationToken::with_timeout(timeout))
+    }
+
     //// Profile a LazyFrame.
     ////
     //// This will run the query and return a tuple
@@ -541,7 +634,7 @@ impl LazyFrame {
     ////
     //// The units of the timings are microseconds.
     pub fn profile(self) -> PolarsResult<(DataFrame, DataFrame)> {
-        let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
+        let (mut state, mut physical_plan, _) = self.prepare_collect(false, &Default::default())?;
         state.time_nodes();
         let out = physical_plan.execute(&mut state)?;
         let timer_df = state.finish_timer()?;
@@ -552,43 +645,217 @@ impl LazyFrame {
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
     #[cfg(feature = "parquet")]
-    pub fn sink_parquet(mut self, path: PathBuf, options: ParquetWriteOptions) -> PolarsResult<()> {
-        self.opt_state.streaming = true;
-        self.logical_plan = LogicalPlan::FileSink {
-            input: Box::new(self.logical_plan),
-            payload: FileSinkOptions {
+    pub fn sink_parquet(self, path: PathBuf, options: ParquetWriteOptions) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
                 path: Arc::new(path),
                 file_type: FileType::Parquet(options),
+                partitioning: None,
+                cloud_options: None,
             },
-        };
-        let (mut state, mut physical_plan, is_streaming) = self.prepare_collect(true)?;
-        polars_ensure!(
-            is_streaming,
-            ComputeError: "cannot run the whole query in a streaming order; \
-            use `collect().write_parquet()` instead"
-        );
-        let _ = physical_plan.execute(&mut state)?;
-        Ok(())
+            "`collect().write_parquet()`",
+            &Default::default(),
+        )
+    }
+
+    /// Stream a query result into a directory of parquet files that are partitioned by the
+    /// values of the `partitioning.by` columns, see [`SinkPartitionOptions`]. This methods will
+    /// return an error if the query cannot be completely done in a streaming fashion.
+    #[cfg(feature = "parquet")]
+    pub fn sink_parquet_partitioned(
+        self,
+        path: PathBuf,
+        partitioning: SinkPartitionOptions,
+        options: ParquetWriteOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
+                path: Arc::new(path),
+                file_type: FileType::Parquet(options),
+                partitioning: Some(partitioning),
+                cloud_options: None,
+            },
+            "`collect()` and a `PartitionedWriter`",
+            &Default::default(),
+        )
     }
 
     //// Stream a query result into an ipc/arrow file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
     #[cfg(feature = "ipc")]
-    pub fn sink_ipc(mut self, path: PathBuf, options: IpcWriterOptions) -> PolarsResult<()> {
-        self.opt_state.streaming = true;
-        self.logical_plan = LogicalPlan::FileSink {
-            input: Box::new(self.logical_plan),
-            payload: FileSinkOptions {
+    pub fn sink_ipc(self, path: PathBuf, options: IpcWriterOptions) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
+                path: Arc::new(path),
+                file_type: FileType::Ipc(options),
+                partitioning: None,
+                cloud_options: None,
+            },
+            "`collect().write_ipc()`",
+            &Default::default(),
+        )
+    }
+
+    /// Stream a query result into a directory of ipc/arrow files that are partitioned by the
+    /// values of the `partitioning.by` columns, see [`SinkPartitionOptions`]. This methods will
+    /// return an error if the query cannot be completely done in a streaming fashion.
+    #[cfg(feature = "ipc")]
+    pub fn sink_ipc_partitioned(
+        self,
+        path: PathBuf,
+        partitioning: SinkPartitionOptions,
+        options: IpcWriterOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
+                path: Arc::new(path),
+                file_type: FileType::Ipc(options),
+                partitioning: Some(partitioning),
+                cloud_options: None,
+            },
+            "`collect()` and a `PartitionedWriter`",
+            &Default::default(),
+        )
+    }
+
+    /// Stream a query result into a csv file. This is useful if the final result doesn't fit
+    /// into memory. This methods will return an error if the query cannot be completely done in a
+    /// streaming fashion.
+    #[cfg(feature = "csv-file")]
+    pub fn sink_csv(self, path: PathBuf, options: CsvWriterOptions) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
+                path: Arc::new(path),
+                file_type: FileType::Csv(options),
+                partitioning: None,
+                cloud_options: None,
+            },
+            "`collect().write_csv()`",
+            &Default::default(),
+        )
+    }
+
+    /// Stream a query result into a newline delimited json file. This is useful if the final
+    /// result doesn't fit into memory. This methods will return an error if the query cannot be
+    /// completely done in a streaming fashion.
+    #[cfg(feature = "json")]
+    pub fn sink_ndjson(self, path: PathBuf, options: JsonWriterOptions) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
                 path: Arc::new(path),
+                file_type: FileType::Json(options),
+                partitioning: None,
+                cloud_options: None,
+            },
+            "`collect().write_ndjson()`",
+            &Default::default(),
+        )
+    }
+
+    /// Stream a query result into a parquet file in an object store, e.g. `s3://bucket/file.parquet`.
+    /// The file is uploaded with a multipart upload while the query runs. This methods will return
+    /// an error if the query cannot be completely done in a streaming fashion.
+    #[cfg(all(feature = "async", feature = "parquet"))]
+    pub fn sink_parquet_cloud(
+        self,
+        uri: String,
+        cloud_options: Option<CloudOptions>,
+        options: ParquetWriteOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
+                path: Arc::new(uri.into()),
+                file_type: FileType::Parquet(options),
+                partitioning: None,
+                cloud_options,
+            },
+            "`collect().write_parquet()`",
+            &Default::default(),
+        )
+    }
+
+    /// Stream a query result into an ipc/arrow file in an object store, see
+    /// [`LazyFrame::sink_parquet_cloud`].
+    #[cfg(all(feature = "async", feature = "ipc"))]
+    pub fn sink_ipc_cloud(
+        self,
+        uri: String,
+        cloud_options: Option<CloudOptions>,
+        options: IpcWriterOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
+                path: Arc::new(uri.into()),
                 file_type: FileType::Ipc(options),
+                partitioning: None,
+                cloud_options,
+            },
+            "`collect().write_ipc()`",
+            &Default::default(),
+        )
+    }
+
+    /// Stream a query result into a csv file in an object store, see
+    /// [`LazyFrame::sink_parquet_cloud`].
+    #[cfg(all(feature = "async", feature = "csv-file"))]
+    pub fn sink_csv_cloud(
+        self,
+        uri: String,
+        cloud_options: Option<CloudOptions>,
+        options: CsvWriterOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
+                path: Arc::new(uri.into()),
+                file_type: FileType::Csv(options),
+                partitioning: None,
+                cloud_options,
             },
+            "`collect().write_csv()`",
+            &Default::default(),
+        )
+    }
+
+    /// Stream a query result into the file(s) described by `payload`, like the `sink_*` methods,
+    /// but stop with an error once the `cancel_token` is cancelled or times out.
+    #[cfg(any(
+        feature = "parquet",
+        feature = "ipc",
+        feature = "csv-file",
+        feature = "json"
+    ))]
+    pub fn sink_with_token(
+        self,
+        payload: FileSinkOptions,
+        cancel_token: &CancellationToken,
+    ) -> PolarsResult<()> {
+        self.sink(payload, "`collect_with_token()`", cancel_token)
+    }
+
+    #[cfg(any(
+        feature = "parquet",
+        feature = "ipc",
+        feature = "csv-file",
+        feature = "json"
+    ))]
+    fn sink(
+        mut self,
+        payload: FileSinkOptions,
+        alternative: &str,
+        cancel_token: &CancellationToken,
+    ) -> PolarsResult<()> {
+        self.opt_state.streaming = true;
+        self.logical_plan = LogicalPlan::FileSink {
+            input: Box::new(self.logical_plan),
+            payload,
         };
-        let (mut state, mut physical_plan, is_streaming) = self.prepare_collect(true)?;
+        let (mut state, mut physical_plan, is_streaming) =
+            self.prepare_collect(true, cancel_token)?;
         polars_ensure!(
             is_streaming,
             ComputeError: "cannot run the whole query in a streaming order; \
-            use `collect().write_ipc()` instead"
+            use {} instead", alternative
         );
         let _ = physical_plan.execute(&mut state)?;
         Ok(())
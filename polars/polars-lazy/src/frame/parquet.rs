@@ -17,6 +17,7 @@ pub struct ScanArgsParquet {
     pub low_memory: bool,
     pub cloud_options: Option<CloudOptions>,
     pub use_statistics: bool,
+    pub hive_partitioning: bool,
 }
 
 impl Default for ScanArgsParquet {
@@ -30,6 +31,7 @@ impl Default for ScanArgsParquet {
             low_memory: false,
             cloud_options: None,
             use_statistics: true,
+            hive_partitioning: false,
         }
     }
 }
@@ -38,11 +40,16 @@ impl Default for ScanArgsParquet {
 struct LazyParquetReader {
     args: ScanArgsParquet,
     path: PathBuf,
+    hive_partitions: Option<Arc<HivePartitions>>,
 }
 
 impl LazyParquetReader {
     fn new(path: PathBuf, args: ScanArgsParquet) -> Self {
-        Self { args, path }
+        Self {
+            args,
+            path,
+            hive_partitions: None,
+        }
     }
 }
 
@@ -60,6 +67,7 @@ impl LazyFileListReader for LazyParquetReader {
             self.args.low_memory,
             self.args.cloud_options,
             self.args.use_statistics,
+            self.hive_partitions,
         )?
         .build()
         .into();
@@ -102,6 +110,15 @@ impl LazyFileListReader for LazyParquetReader {
     fn row_count(&self) -> Option<&RowCount> {
         self.args.row_count.as_ref()
     }
+
+    fn hive_partitioning(&self) -> bool {
+        self.args.hive_partitioning
+    }
+
+    fn with_hive_partitions(mut self, hive_partitions: Option<Arc<HivePartitions>>) -> Self {
+        self.hive_partitions = hive_partitions;
+        self
+    }
 }
 
 impl LazyFrame {
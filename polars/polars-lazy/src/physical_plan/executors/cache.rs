@@ -13,14 +13,14 @@ impl Executor for CacheExec {
                 if state.verbose() {
                     println!("CACHE HIT: cache id: {:x}", self.id);
                 }
-                self.count -= 0;
                 return Ok(df);
             }
         }
 
-        // cache miss
+        // cache miss, the other branches that share this cache are expected to hit it
+        // `count` times
         let df = self.input.execute(state)?;
-        state.store_cache(self.id, df.clone());
+        state.store_cache(self.id, df.clone(), self.count);
         if state.verbose() {
             println!("CACHE SET: cache id: {:x}", self.id);
         }
@@ -14,6 +14,7 @@ impl Executor for ExternalContext {
             }
         }
         let df = self.input.execute(state)?;
+        state.should_stop()?;
         let contexts = self
             .contexts
             .iter_mut()
@@ -20,6 +20,7 @@ impl Executor for FilterExec {
             }
         }
         let df = self.input.execute(state)?;
+        state.should_stop()?;
         let s = self.predicate.evaluate(&df, state)?;
         let mask = s.bool().map_err(|_| {
             polars_err!(
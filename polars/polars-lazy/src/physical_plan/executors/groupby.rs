@@ -46,6 +46,8 @@ pub(super) fn groupby_helper(
 ) -> PolarsResult<DataFrame> {
     df.as_single_chunk_par();
     let gb = df.groupby_with_series(keys, true, maintain_order)?;
+    // computing the groups can take long, so check again before aggregating
+    state.should_stop()?;
 
     if let Some(f) = apply {
         return gb.apply(move |df| f.call_udf(df));
@@ -68,6 +70,7 @@ pub(super) fn groupby_helper(
         let get_agg = || {
             aggs.par_iter()
                 .map(|expr| {
+                    state.should_stop()?;
                     let agg = expr.evaluate_on_groups(&df, groups, state)?.finalize();
                     polars_ensure!(agg.len() == groups.len(), agg_len = agg.len(), groups.len());
                     Ok(agg)
@@ -118,6 +121,7 @@ impl Executor for GroupByExec {
             eprintln!("keys/aggregates are not partitionable: running default HASH AGGREGATION")
         }
         let df = self.input.execute(state)?;
+        state.should_stop()?;
 
         let profile_name = if state.has_node_timer() {
             let by = self
@@ -86,6 +86,7 @@ impl Executor for GroupByDynamicExec {
             }
         }
         let df = self.input.execute(state)?;
+        state.should_stop()?;
 
         let profile_name = if state.has_node_timer() {
             let by = self
@@ -70,6 +70,7 @@ fn run_partitions(
     POOL.install(|| {
         dfs.into_par_iter()
             .map(|df| {
+                state.should_stop()?;
                 let keys = compute_keys(keys, &df, state)?;
                 let gb = df.groupby_with_series(keys, false, maintain_order)?;
                 let groups = gb.get_groups();
@@ -80,6 +81,7 @@ fn run_partitions(
                 let agg_columns = phys_aggs
                     .iter()
                     .map(|expr| {
+                        state.should_stop()?;
                         let agg_expr = expr.as_partitioned_aggregator().unwrap();
                         let agg = agg_expr.evaluate_partitioned(&df, groups, state)?;
                         Ok(if agg.len() != groups.len() {
@@ -283,6 +285,7 @@ impl PartitionGroupByExec {
                 // we slice the keys off and finalize every aggregation
                 .zip(&df.get_columns()[self.keys.len()..])
                 .map(|(expr, partitioned_s)| {
+                    state.should_stop()?;
                     let agg_expr = expr.as_partitioned_aggregator().unwrap();
                     agg_expr.finalize(partitioned_s.clone(), groups, state)
                 })
@@ -309,6 +312,7 @@ impl Executor for PartitionGroupByExec {
             }
         }
         let original_df = self.input.execute(state)?;
+        state.should_stop()?;
 
         let profile_name = if state.has_node_timer() {
             let by = self
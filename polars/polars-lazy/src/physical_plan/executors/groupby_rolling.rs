@@ -102,6 +102,7 @@ impl Executor for GroupByRollingExec {
             }
         }
         let df = self.input.execute(state)?;
+        state.should_stop()?;
         let profile_name = if state.has_node_timer() {
             let by = self
                 .keys
@@ -73,6 +73,7 @@ impl Executor for JoinExec {
 
         let mut df_left = df_left?;
         let mut df_right = df_right?;
+        state.should_stop()?;
 
         let profile_name = if state.has_node_timer() {
             let by = self
@@ -0,0 +1,70 @@
+use std::fs::File;
+
+use polars_io::avro::AvroReader;
+
+use super::*;
+use crate::physical_plan::planner::create_physical_expr;
+use crate::prelude::{AnonymousScan, AnonymousScanBatches, AnonymousScanOptions, LazyAvroReader};
+
+impl LazyAvroReader {
+    fn reader(&self, scan_opts: AnonymousScanOptions) -> PolarsResult<AvroReader<File>> {
+        let schema = scan_opts.output_schema.unwrap_or(scan_opts.schema);
+        // the predicate is applied to every block while reading
+        let predicate = scan_opts
+            .predicate
+            .map(|predicate| {
+                let mut expr_arena = Arena::with_capacity(16);
+                let node = to_aexpr(predicate, &mut expr_arena);
+                create_physical_expr(node, Context::Default, &expr_arena, Some(&schema))
+                    .map(|expr| Arc::new(PhysicalIoHelper { expr }) as Arc<dyn PhysicalIoExpr>)
+            })
+            .transpose()?;
+
+        let file = File::open(resolve_homedir(&self.path))?;
+        Ok(AvroReader::new(file)
+            .with_columns(scan_opts.with_columns.map(|columns| (*columns).clone()))
+            .with_n_rows(scan_opts.n_rows)
+            .with_predicate(predicate)
+            .set_rechunk(self.args.rechunk))
+    }
+}
+
+impl AnonymousScan for LazyAvroReader {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanOptions) -> PolarsResult<DataFrame> {
+        self.reader(scan_opts)?.finish()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<Schema> {
+        let file = File::open(resolve_homedir(&self.path))?;
+        AvroReader::new(file).schema()
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_streaming(&self) -> bool {
+        true
+    }
+
+    fn scan_batched(
+        &self,
+        scan_opts: AnonymousScanOptions,
+        chunk_size: usize,
+    ) -> PolarsResult<Box<dyn AnonymousScanBatches>> {
+        let mut reader = self.reader(scan_opts)?.batched(chunk_size)?;
+        Ok(Box::new(move |n| reader.next_batches(n)))
+    }
+}
@@ -33,7 +33,7 @@ impl CsvExec {
             .unwrap()
             .has_header(self.options.has_header)
             .with_dtypes(Some(self.schema.clone()))
-            .with_delimiter(self.options.delimiter)
+            .with_multi_char_delimiter(&self.options.delimiter)
             .with_ignore_errors(self.options.ignore_errors)
             .with_skip_rows(self.options.skip_rows)
             .with_n_rows(n_rows)
@@ -45,6 +45,7 @@ impl CsvExec {
             .with_comment_char(self.options.comment_char)
             .with_quote_char(self.options.quote_char)
             .with_end_of_line_char(self.options.eol_char)
+            .with_truncated_rows(self.options.truncated_rows)
             .with_encoding(self.options.encoding)
             .with_rechunk(self.options.rechunk)
             .with_row_count(std::mem::take(&mut self.options.row_count))
@@ -55,6 +56,7 @@ impl CsvExec {
 
 impl Executor for CsvExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
         let finger_print = FileFingerPrint {
             path: self.path.clone(),
             predicate: self
@@ -7,10 +7,20 @@ pub struct IpcExec {
     pub(crate) schema: SchemaRef,
     pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
     pub(crate) options: IpcScanOptionsInner,
+    pub(crate) hive_parts: Option<Arc<HivePartitions>>,
 }
 
 impl IpcExec {
     fn read(&mut self, verbose: bool) -> PolarsResult<DataFrame> {
+        let hive_scan = self.hive_parts.clone().map(|hive_parts| {
+            let (projection, predicate) = prepare_hive_scan(
+                &hive_parts,
+                &mut self.options.with_columns,
+                &mut self.predicate,
+                self.options.row_count.as_ref(),
+            );
+            (hive_parts, projection, predicate)
+        });
         let (file, projection, n_rows, predicate) = prepare_scan_args(
             &self.path,
             &self.predicate,
@@ -18,18 +28,26 @@ impl IpcExec {
             &mut self.schema,
             self.options.n_rows,
         );
-        IpcReader::new(file)
+        let df = IpcReader::new(file)
             .with_n_rows(n_rows)
             .with_row_count(std::mem::take(&mut self.options.row_count))
             .set_rechunk(self.options.rechunk)
             .with_projection(projection)
             .memory_mapped(self.options.memmap)
-            .finish_with_scan_ops(predicate, verbose)
+            .finish_with_scan_ops(predicate, verbose)?;
+
+        match hive_scan {
+            Some((hive_parts, projection, predicate)) => {
+                finish_hive_scan(&hive_parts, df, projection, predicate)
+            }
+            None => Ok(df),
+        }
     }
 }
 
 impl Executor for IpcExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
         let finger_print = FileFingerPrint {
             path: self.path.clone(),
             predicate: self
@@ -1,3 +1,5 @@
+#[cfg(feature = "avro")]
+mod avro;
 #[cfg(feature = "csv-file")]
 mod csv;
 #[cfg(feature = "ipc")]
@@ -15,9 +17,11 @@ pub(crate) use csv::CsvExec;
 pub(crate) use ipc::IpcExec;
 #[cfg(feature = "parquet")]
 pub(crate) use parquet::ParquetExec;
-#[cfg(any(feature = "ipc", feature = "parquet"))]
+#[cfg(any(feature = "ipc", feature = "parquet", feature = "avro"))]
 use polars_io::predicates::PhysicalIoExpr;
 use polars_io::prelude::*;
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+use polars_io::RowCount;
 use polars_plan::global::_set_n_rows_for_scan;
 #[cfg(any(
     feature = "parquet",
@@ -36,6 +40,8 @@ type Projection = Option<Vec<usize>>;
 type StopNRows = Option<usize>;
 #[cfg(any(feature = "ipc", feature = "parquet"))]
 type Predicate = Option<Arc<dyn PhysicalIoExpr>>;
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+type ScanProjection = Option<Arc<Vec<String>>>;
 
 #[cfg(any(feature = "ipc", feature = "parquet"))]
 fn prepare_scan_args(
@@ -65,6 +71,72 @@ fn prepare_scan_args(
     (file, projection, n_rows, predicate)
 }
 
+/// Remove the hive partition keys that are not stored in the file from the projection
+/// of a scan. If the predicate refers to these keys it is taken as well, as it can
+/// only be applied once they are materialized by [finish_hive_scan].
+///
+/// The returned projection is the final selection of the scan, which starts with the
+/// row count column if the reader adds one.
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+fn prepare_hive_scan(
+    hive_parts: &HivePartitions,
+    with_columns: &mut ScanProjection,
+    predicate: &mut Option<Arc<dyn PhysicalExpr>>,
+    row_count: Option<&RowCount>,
+) -> (ScanProjection, Option<Arc<dyn PhysicalExpr>>) {
+    let missing = hive_parts.missing_columns();
+    let projection = match (with_columns.as_ref(), row_count) {
+        (Some(columns), Some(rc)) => Some(Arc::new(
+            std::iter::once(rc.name.clone())
+                .chain(columns.iter().cloned())
+                .collect(),
+        )),
+        _ => with_columns.clone(),
+    };
+    if let Some(columns) = with_columns.as_mut() {
+        if columns.iter().any(|name| missing.contains(name)) {
+            Arc::make_mut(columns).retain(|name| !missing.contains(name));
+        }
+    }
+    let refers_to_missing = predicate
+        .as_ref()
+        .and_then(|pred| pred.as_expression())
+        .map_or(false, |expr| {
+            expr_to_leaf_column_names(expr)
+                .iter()
+                .any(|name| missing.iter().any(|m| m.as_str() == name.as_ref()))
+        });
+    let predicate = if refers_to_missing {
+        predicate.take()
+    } else {
+        None
+    };
+    (projection, predicate)
+}
+
+/// Add the hive partition keys to the data that is read from a file and apply the
+/// predicate that was taken by [prepare_hive_scan].
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+fn finish_hive_scan(
+    hive_parts: &HivePartitions,
+    mut df: DataFrame,
+    projection: ScanProjection,
+    predicate: Option<Arc<dyn PhysicalExpr>>,
+) -> PolarsResult<DataFrame> {
+    hive_parts.materialize(
+        &mut df,
+        projection.as_ref().map(|columns| columns.as_slice()),
+    )?;
+    if let Some(projection) = projection {
+        df = df.select(projection.iter())?;
+    }
+    if let Some(predicate) = predicate {
+        let mask = predicate.evaluate(&df, &Default::default())?;
+        df = df.filter(mask.bool()?)?;
+    }
+    Ok(df)
+}
+
 /// Producer of an in memory DataFrame
 pub struct DataFrameExec {
     pub(crate) df: Arc<DataFrame>,
@@ -74,6 +146,7 @@ pub struct DataFrameExec {
 
 impl Executor for DataFrameExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
         let df = mem::take(&mut self.df);
         let mut df = Arc::try_unwrap(df).unwrap_or_else(|df| (*df).clone());
 
@@ -106,6 +179,7 @@ pub(crate) struct AnonymousScanExec {
 
 impl Executor for AnonymousScanExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
         state.record(
             || match (self.function.allows_predicate_pushdown(), &self.predicate) {
                 (true, Some(predicate)) => {
@@ -1,7 +1,9 @@
 use polars_core::error::to_compute_err;
 
 use super::*;
-use crate::prelude::{AnonymousScan, AnonymousScanOptions, LazyJsonLineReader};
+use crate::prelude::{
+    AnonymousScan, AnonymousScanBatches, AnonymousScanOptions, LazyJsonLineReader,
+};
 
 impl AnonymousScan for LazyJsonLineReader {
     fn as_any(&self) -> &dyn std::any::Any {
@@ -32,4 +34,24 @@ impl AnonymousScan for LazyJsonLineReader {
     fn allows_projection_pushdown(&self) -> bool {
         true
     }
+
+    fn allows_streaming(&self) -> bool {
+        true
+    }
+
+    fn scan_batched(
+        &self,
+        scan_opts: AnonymousScanOptions,
+        chunk_size: usize,
+    ) -> PolarsResult<Box<dyn AnonymousScanBatches>> {
+        let schema = scan_opts.output_schema.unwrap_or(scan_opts.schema);
+        let f = std::fs::File::open(resolve_homedir(&self.path))?;
+        let mut reader = BatchedJsonLineReader::new(
+            std::io::BufReader::new(f),
+            schema,
+            chunk_size,
+            scan_opts.n_rows,
+        );
+        Ok(Box::new(move |n| reader.next_batches(n)))
+    }
 }
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use polars_core::cancel::CancellationToken;
 use polars_core::cloud::CloudOptions;
 
 use super::*;
@@ -11,6 +12,7 @@ pub struct ParquetExec {
     predicate: Option<Arc<dyn PhysicalExpr>>,
     options: ParquetOptions,
     cloud_options: Option<CloudOptions>,
+    hive_parts: Option<Arc<HivePartitions>>,
 }
 
 impl ParquetExec {
@@ -20,6 +22,7 @@ impl ParquetExec {
         predicate: Option<Arc<dyn PhysicalExpr>>,
         options: ParquetOptions,
         cloud_options: Option<CloudOptions>,
+        hive_parts: Option<Arc<HivePartitions>>,
     ) -> Self {
         ParquetExec {
             path,
@@ -27,10 +30,20 @@ impl ParquetExec {
             predicate,
             options,
             cloud_options,
+            hive_parts,
         }
     }
 
-    fn read(&mut self) -> PolarsResult<DataFrame> {
+    fn read(&mut self, cancel_token: &CancellationToken) -> PolarsResult<DataFrame> {
+        let hive_scan = self.hive_parts.clone().map(|hive_parts| {
+            let (projection, predicate) = prepare_hive_scan(
+                &hive_parts,
+                &mut self.options.with_columns,
+                &mut self.predicate,
+                self.options.row_count.as_ref(),
+            );
+            (hive_parts, projection, predicate)
+        });
         let (file, projection, n_rows, predicate) = prepare_scan_args(
             &self.path,
             &self.predicate,
@@ -39,19 +52,28 @@ impl ParquetExec {
             self.options.n_rows,
         );
 
-        ParquetReader::new(file)
+        let df = ParquetReader::new(file)
             .with_n_rows(n_rows)
             .read_parallel(self.options.parallel)
             .with_row_count(mem::take(&mut self.options.row_count))
             .set_rechunk(self.options.rechunk)
             .set_low_memory(self.options.low_memory)
             .use_statistics(self.options.use_statistics)
-            ._finish_with_scan_ops(predicate, projection.as_ref().map(|v| v.as_ref()))
+            .with_cancel_token(Some(cancel_token.clone()))
+            ._finish_with_scan_ops(predicate, projection.as_ref().map(|v| v.as_ref()))?;
+
+        match hive_scan {
+            Some((hive_parts, projection, predicate)) => {
+                finish_hive_scan(&hive_parts, df, projection, predicate)
+            }
+            None => Ok(df),
+        }
     }
 }
 
 impl Executor for ParquetExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
         let finger_print = FileFingerPrint {
             path: self.path.clone(),
             predicate: self
@@ -72,11 +94,14 @@ impl Executor for ParquetExec {
             Cow::Borrowed("")
         };
 
+        let cancel_token = state.cancel_token.clone();
         state.record(
             || {
                 state
                     .file_cache
-                    .read(finger_print, self.options.file_counter, &mut || self.read())
+                    .read(finger_print, self.options.file_counter, &mut || {
+                        self.read(&cancel_token)
+                    })
             },
             profile_name,
         )
@@ -15,6 +15,7 @@ impl Executor for SliceExec {
             }
         }
         let df = self.input.execute(state)?;
+        state.should_stop()?;
 
         state.record(
             || Ok(df.slice(self.offset, self.len as usize)),
@@ -43,6 +43,7 @@ impl Executor for StackExec {
             }
         }
         let df = self.input.execute(state)?;
+        state.should_stop()?;
 
         let profile_name = if state.has_node_timer() {
             let by = self
@@ -14,6 +14,7 @@ impl Executor for UdfExec {
             }
         }
         let df = self.input.execute(state)?;
+        state.should_stop()?;
 
         let profile_name = if state.has_node_timer() {
             Cow::Owned(format!("{}", self.function))
@@ -14,6 +14,7 @@ impl Executor for UniqueExec {
             }
         }
         let df = self.input.execute(state)?;
+        state.should_stop()?;
         let subset = self.options.subset.as_ref().map(|v| &***v);
         let keep = self.options.keep_strategy;
 
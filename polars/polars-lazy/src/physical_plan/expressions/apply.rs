@@ -434,37 +434,31 @@ impl ApplyExpr {
 
                 match stats.get_stats(&root).ok() {
                     Some(st) => {
-                        let min = match st.to_min() {
-                            Some(min) => min,
-                            None => return Ok(true),
-                        };
-
-                        let max = match st.to_max() {
-                            Some(max) => max,
-                            None => return Ok(true),
-                        };
-
-                        // all wanted values are smaller than minimum
-                        // don't need to read
-                        if ChunkCompare::<&Series>::lt(input, &min)
-                            .ok()
-                            .map(|ca| ca.all())
-                            == Some(true)
-                        {
-                            return Ok(false);
+                        if let (Some(min), Some(max)) = (st.to_min(), st.to_max()) {
+                            // all wanted values are smaller than minimum
+                            // don't need to read
+                            if ChunkCompare::<&Series>::lt(input, &min)
+                                .ok()
+                                .map(|ca| ca.all())
+                                == Some(true)
+                            {
+                                return Ok(false);
+                            }
+
+                            // all wanted values are bigger than maximum
+                            // don't need to read
+                            if ChunkCompare::<&Series>::gt(input, &max)
+                                .ok()
+                                .map(|ca| ca.all())
+                                == Some(true)
+                            {
+                                return Ok(false);
+                            }
                         }
 
-                        // all wanted values are bigger than maximum
+                        // none of the wanted values are in the bloom filter
                         // don't need to read
-                        if ChunkCompare::<&Series>::gt(input, &max)
-                            .ok()
-                            .map(|ca| ca.all())
-                            == Some(true)
-                        {
-                            return Ok(false);
-                        }
-
-                        Ok(true)
+                        Ok(st.bloom_filter_may_contain(input) != Some(false))
                     }
                     None => Ok(true),
                 }
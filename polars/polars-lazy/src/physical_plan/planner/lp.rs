@@ -1,11 +1,81 @@
+use polars_core::cancel::CancellationToken;
+use polars_core::memory::QueryMemory;
 use polars_core::prelude::*;
 
 use super::super::executors::{self, Executor};
 use super::*;
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+use crate::physical_plan::state::ExecutionState;
 #[cfg(feature = "streaming")]
 use crate::physical_plan::streaming::insert_streaming_nodes;
 use crate::utils::*;
 
+/// Evaluate the conjunctions of a scan predicate that only refer to hive partition keys
+/// on the partition values of the file.
+///
+/// Returns `None` if the file can be skipped, otherwise the part of the predicate that
+/// must still be applied to the data of the file.
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+fn prune_hive_partitions(
+    hive_parts: &HivePartitions,
+    predicate: Node,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Option<Option<Node>>> {
+    let mut conjunctions = vec![];
+    let mut stack = vec![predicate];
+    while let Some(node) = stack.pop() {
+        match expr_arena.get(node) {
+            AExpr::BinaryExpr {
+                left,
+                op: Operator::And,
+                right,
+            } => {
+                stack.push(*right);
+                stack.push(*left);
+            }
+            _ => conjunctions.push(node),
+        }
+    }
+
+    let values = hive_parts.get_values();
+    let schema = Arc::new(values.schema());
+    let state = ExecutionState::new();
+    let mut remaining = vec![];
+    for node in conjunctions {
+        let mut leaves = aexpr_to_leaf_names_iter(node, expr_arena).peekable();
+        let on_partition_keys =
+            leaves.peek().is_some() && leaves.all(|name| schema.contains(&name));
+        if !on_partition_keys {
+            remaining.push(node);
+            continue;
+        }
+        let phys_expr = create_physical_expr(node, Context::Default, expr_arena, Some(&schema))?;
+        let mask = phys_expr.evaluate(values, &state)?;
+        if mask.bool()?.get(0) != Some(true) {
+            return Ok(None);
+        }
+    }
+
+    let predicate = remaining.into_iter().reduce(|left, right| {
+        expr_arena.add(AExpr::BinaryExpr {
+            left,
+            op: Operator::And,
+            right,
+        })
+    });
+    Ok(Some(predicate))
+}
+
+/// A scan of a file that is skipped because of its hive partition values.
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+fn skipped_scan(schema: SchemaRef) -> Box<dyn Executor> {
+    Box::new(executors::DataFrameExec {
+        df: Arc::new(DataFrame::from(schema.as_ref())),
+        selection: None,
+        projection: None,
+    })
+}
+
 fn partitionable_gb(
     keys: &[Node],
     aggs: &[Node],
@@ -144,6 +214,8 @@ pub fn create_physical_plan(
     root: Node,
     lp_arena: &mut Arena<ALogicalPlan>,
     expr_arena: &mut Arena<AExpr>,
+    memory: &QueryMemory,
+    cancel_token: &CancellationToken,
 ) -> PolarsResult<Box<dyn Executor>> {
     use ALogicalPlan::*;
 
@@ -157,16 +229,16 @@ pub fn create_physical_plan(
         Union { inputs, options } => {
             let inputs = inputs
                 .into_iter()
-                .map(|node| create_physical_plan(node, lp_arena, expr_arena))
+                .map(|node| create_physical_plan(node, lp_arena, expr_arena, memory, cancel_token))
                 .collect::<PolarsResult<Vec<_>>>()?;
             Ok(Box::new(executors::UnionExec { inputs, options }))
         }
         Slice { input, offset, len } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan(input, lp_arena, expr_arena, memory, cancel_token)?;
             Ok(Box::new(executors::SliceExec { input, offset, len }))
         }
         Selection { input, predicate } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan(input, lp_arena, expr_arena, memory, cancel_token)?;
             let predicate = create_physical_expr(predicate, Context::Default, expr_arena, None)?;
             Ok(Box::new(executors::FilterExec::new(predicate, input)))
         }
@@ -198,6 +270,13 @@ pub fn create_physical_plan(
             predicate,
             options,
         } => {
+            let mut predicate = predicate;
+            if let (Some(hive_parts), Some(pred)) = (&file_info.hive_parts, predicate) {
+                match prune_hive_partitions(hive_parts, pred, expr_arena)? {
+                    Some(pred) => predicate = pred,
+                    None => return Ok(skipped_scan(output_schema.unwrap_or(file_info.schema))),
+                }
+            }
             let predicate = predicate
                 .map(|pred| {
                     create_physical_expr(pred, Context::Default, expr_arena, output_schema.as_ref())
@@ -209,6 +288,7 @@ pub fn create_physical_plan(
                 schema: file_info.schema,
                 predicate,
                 options,
+                hive_parts: file_info.hive_parts,
             }))
         }
         #[cfg(feature = "parquet")]
@@ -220,6 +300,13 @@ pub fn create_physical_plan(
             options,
             cloud_options,
         } => {
+            let mut predicate = predicate;
+            if let (Some(hive_parts), Some(pred)) = (&file_info.hive_parts, predicate) {
+                match prune_hive_partitions(hive_parts, pred, expr_arena)? {
+                    Some(pred) => predicate = pred,
+                    None => return Ok(skipped_scan(output_schema.unwrap_or(file_info.schema))),
+                }
+            }
             let predicate = predicate
                 .map(|pred| {
                     create_physical_expr(pred, Context::Default, expr_arena, output_schema.as_ref())
@@ -232,6 +319,7 @@ pub fn create_physical_plan(
                 predicate,
                 options,
                 cloud_options,
+                file_info.hive_parts,
             )))
         }
         Projection {
@@ -242,7 +330,7 @@ pub fn create_physical_plan(
         } => {
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
             let has_windows = expr.iter().any(|node| has_aexpr_window(*node, expr_arena));
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan(input, lp_arena, expr_arena, memory, cancel_token)?;
             let phys_expr = create_physical_expressions(
                 &expr,
                 Context::Default,
@@ -267,7 +355,7 @@ pub fn create_physical_plan(
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
 
             let has_windows = expr.iter().any(|node| has_aexpr_window(*node, expr_arena));
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan(input, lp_arena, expr_arena, memory, cancel_token)?;
             let phys_expr = create_physical_expressions(
                 &expr,
                 Context::Default,
@@ -329,7 +417,7 @@ pub fn create_physical_plan(
                 expr_arena,
                 Some(input_schema.as_ref()),
             )?;
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan(input, lp_arena, expr_arena, memory, cancel_token)?;
             Ok(Box::new(executors::SortExec {
                 input,
                 by_column,
@@ -337,11 +425,11 @@ pub fn create_physical_plan(
             }))
         }
         Cache { input, id, count } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan(input, lp_arena, expr_arena, memory, cancel_token)?;
             Ok(Box::new(executors::CacheExec { id, input, count }))
         }
         Distinct { input, options } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan(input, lp_arena, expr_arena, memory, cancel_token)?;
             Ok(Box::new(executors::UniqueExec { input, options }))
         }
         Aggregate {
@@ -370,7 +458,8 @@ pub fn create_physical_plan(
             let _slice = options.slice;
             #[cfg(feature = "dynamic_groupby")]
             if let Some(options) = options.dynamic {
-                let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let input =
+                    create_physical_plan(input, lp_arena, expr_arena, memory, cancel_token)?;
                 return Ok(Box::new(executors::GroupByDynamicExec {
                     input,
                     keys: phys_keys,
@@ -383,7 +472,8 @@ pub fn create_physical_plan(
 
             #[cfg(feature = "dynamic_groupby")]
             if let Some(options) = options.rolling {
-                let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let input =
+                    create_physical_plan(input, lp_arena, expr_arena, memory, cancel_token)?;
                 return Ok(Box::new(executors::GroupByRollingExec {
                     input,
                     keys: phys_keys,
@@ -433,9 +523,23 @@ pub fn create_physical_plan(
                     if allowed_key
                         && allowed_aggs
                         && !has_joins_or_distinct
-                        && insert_streaming_nodes(root, lp_arena, expr_arena, &mut vec![], false)?
+                        && insert_streaming_nodes(
+                            root,
+                            lp_arena,
+                            expr_arena,
+                            &mut vec![],
+                            memory,
+                            cancel_token,
+                            false,
+                        )?
                     {
-                        return create_physical_plan(root, lp_arena, expr_arena);
+                        return create_physical_plan(
+                            root,
+                            lp_arena,
+                            expr_arena,
+                            memory,
+                            cancel_token,
+                        );
                     }
                 }
 
@@ -446,7 +550,8 @@ pub fn create_physical_plan(
                         false
                     }
                 });
-                let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let input =
+                    create_physical_plan(input, lp_arena, expr_arena, memory, cancel_token)?;
                 Ok(Box::new(executors::PartitionGroupByExec::new(
                     input,
                     phys_keys,
@@ -458,7 +563,8 @@ pub fn create_physical_plan(
                     from_partitioned_ds,
                 )))
             } else {
-                let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let input =
+                    create_physical_plan(input, lp_arena, expr_arena, memory, cancel_token)?;
                 Ok(Box::new(executors::GroupByExec::new(
                     input,
                     phys_keys,
@@ -493,8 +599,10 @@ pub fn create_physical_plan(
                 false
             };
 
-            let input_left = create_physical_plan(input_left, lp_arena, expr_arena)?;
-            let input_right = create_physical_plan(input_right, lp_arena, expr_arena)?;
+            let input_left =
+                create_physical_plan(input_left, lp_arena, expr_arena, memory, cancel_token)?;
+            let input_right =
+                create_physical_plan(input_right, lp_arena, expr_arena, memory, cancel_token)?;
             let left_on =
                 create_physical_expressions(&left_on, Context::Default, expr_arena, None)?;
             let right_on =
@@ -513,7 +621,7 @@ pub fn create_physical_plan(
         HStack { input, exprs, .. } => {
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
             let has_windows = exprs.iter().any(|node| has_aexpr_window(*node, expr_arena));
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan(input, lp_arena, expr_arena, memory, cancel_token)?;
             let phys_expr = create_physical_expressions(
                 &exprs,
                 Context::Default,
@@ -530,16 +638,16 @@ pub fn create_physical_plan(
         MapFunction {
             input, function, ..
         } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan(input, lp_arena, expr_arena, memory, cancel_token)?;
             Ok(Box::new(executors::UdfExec { input, function }))
         }
         ExtContext {
             input, contexts, ..
         } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan(input, lp_arena, expr_arena, memory, cancel_token)?;
             let contexts = contexts
                 .into_iter()
-                .map(|node| create_physical_plan(node, lp_arena, expr_arena))
+                .map(|node| create_physical_plan(node, lp_arena, expr_arena, memory, cancel_token))
                 .collect::<PolarsResult<_>>()?;
             Ok(Box::new(executors::ExternalContext { input, contexts }))
         }
@@ -3,9 +3,11 @@ use std::sync::atomic::{AtomicU8, Ordering};
 use std::sync::{Mutex, RwLock};
 
 use bitflags::bitflags;
+use polars_core::cancel::CancellationToken;
 use polars_core::config::verbose;
 use polars_core::frame::groupby::GroupsProxy;
 use polars_core::frame::hash_join::JoinOptIds;
+use polars_core::memory::QueryMemory;
 use polars_core::prelude::*;
 #[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
 use polars_plan::logical_plan::FileFingerPrint;
@@ -62,8 +64,9 @@ impl From<u8> for StateFlags {
 
 /// State/ cache that is maintained during the Execution of the physical plan.
 pub struct ExecutionState {
-    // cached by a `.cache` call and kept in memory for the duration of the plan.
-    df_cache: Arc<Mutex<PlHashMap<usize, DataFrame>>>,
+    // cached by a `.cache` call and kept in memory until all its hits are consumed,
+    // together with the number of hits that are left.
+    df_cache: Arc<Mutex<PlHashMap<usize, (DataFrame, usize)>>>,
     // cache file reads until all branches got there file, then we delete it
     #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv-file"))]
     pub(crate) file_cache: FileCache,
@@ -77,6 +80,10 @@ pub struct ExecutionState {
     pub(super) flags: AtomicU8,
     pub(super) ext_contexts: Arc<Vec<DataFrame>>,
     node_timer: Option<NodeTimer>,
+    // memory registered by this query
+    pub(crate) memory: QueryMemory,
+    // checked by the executors to stop the query early
+    pub(crate) cancel_token: CancellationToken,
 }
 
 impl ExecutionState {
@@ -106,6 +113,11 @@ impl ExecutionState {
         }
     }
 
+    /// Return an error if the query is cancelled or timed out.
+    pub(crate) fn should_stop(&self) -> PolarsResult<()> {
+        self.cancel_token.check()
+    }
+
     /// Partially clones and partially clears state
     pub(super) fn split(&self) -> Self {
         Self {
@@ -119,6 +131,8 @@ impl ExecutionState {
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
+            memory: self.memory.clone(),
+            cancel_token: self.cancel_token.clone(),
         }
     }
 
@@ -135,6 +149,8 @@ impl ExecutionState {
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
+            memory: self.memory.clone(),
+            cancel_token: self.cancel_token.clone(),
         }
     }
 
@@ -155,6 +171,8 @@ impl ExecutionState {
             flags: AtomicU8::new(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
+            memory: Default::default(),
+            cancel_token: Default::default(),
         }
     }
 
@@ -174,6 +192,8 @@ impl ExecutionState {
             flags: AtomicU8::new(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
+            memory: Default::default(),
+            cancel_token: Default::default(),
         }
     }
     pub(crate) fn set_schema(&self, schema: SchemaRef) {
@@ -193,16 +213,30 @@ impl ExecutionState {
         lock.clone()
     }
 
-    /// Check if we have DataFrame in cache
+    /// Check if we have DataFrame in cache. The cache is taken out and its memory is
+    /// released on the last expected hit.
     pub(crate) fn cache_hit(&self, key: &usize) -> Option<DataFrame> {
-        let guard = self.df_cache.lock().unwrap();
-        guard.get(key).cloned()
+        let mut guard = self.df_cache.lock().unwrap();
+        let (df, hits) = guard.get_mut(key)?;
+        *hits -= 1;
+        if *hits > 0 {
+            return Some(df.clone());
+        }
+        let (df, _) = guard.remove(key).unwrap();
+        self.memory.shrink(df.estimated_size());
+        Some(df)
     }
 
-    /// Store DataFrame in cache.
-    pub(crate) fn store_cache(&self, key: usize, df: DataFrame) {
+    /// Store DataFrame in cache, it is kept until it is hit `hits` times.
+    pub(crate) fn store_cache(&self, key: usize, df: DataFrame, hits: usize) {
+        if hits == 0 {
+            return;
+        }
+        self.memory.grow(df.estimated_size());
         let mut guard = self.df_cache.lock().unwrap();
-        guard.insert(key, df);
+        if let Some((prev, _)) = guard.insert(key, (df, hits)) {
+            self.memory.shrink(prev.estimated_size());
+        }
     }
 
     /// Clear the cache used by the Window expressions
@@ -2,9 +2,11 @@ use std::any::Any;
 use std::collections::VecDeque;
 use std::sync::Arc;
 
+use polars_core::cancel::CancellationToken;
 use polars_core::config::verbose;
 use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
+use polars_core::memory::QueryMemory;
 use polars_core::prelude::*;
 use polars_core::schema::Schema;
 use polars_pipe::expressions::PhysicalPipedExpr;
@@ -94,6 +96,22 @@ fn is_streamable(node: Node, expr_arena: &Arena<AExpr>, context: Context) -> boo
     false
 }
 
+/// Check if a scan can be the source of a pipeline.
+fn is_streamable_scan(lp: &ALogicalPlan) -> bool {
+    use ALogicalPlan::*;
+    match lp {
+        #[cfg(feature = "csv-file")]
+        CsvScan { .. } => true,
+        // hive partitions are materialized by the default engine
+        #[cfg(feature = "parquet")]
+        ParquetScan { file_info, .. } => file_info.hive_parts.is_none(),
+        #[cfg(feature = "ipc")]
+        IpcScan { file_info, .. } => file_info.hive_parts.is_none(),
+        AnonymousScan { function, .. } => function.allows_streaming(),
+        _ => false,
+    }
+}
+
 fn all_streamable(exprs: &[Node], expr_arena: &Arena<AExpr>, context: Context) -> bool {
     exprs
         .iter()
@@ -107,11 +125,21 @@ fn all_column(exprs: &[Node], expr_arena: &Arena<AExpr>) -> bool {
         .all(|node| matches!(expr_arena.get(*node), AExpr::Column(_)))
 }
 
-fn streamable_join(join_type: &JoinType) -> bool {
+fn streamable_join(
+    join_type: &JoinType,
+    left_on: &[Node],
+    right_on: &[Node],
+    expr_arena: &Arena<AExpr>,
+) -> bool {
     match join_type {
         #[cfg(feature = "cross_join")]
         JoinType::Cross => true,
         JoinType::Inner | JoinType::Left => true,
+        // the join columns are coalesced, so they must exist in both tables
+        JoinType::Outer => all_column(left_on, expr_arena) && all_column(right_on, expr_arena),
+        #[cfg(feature = "semi_anti_join")]
+        JoinType::Semi | JoinType::Anti => true,
+        #[allow(unreachable_patterns)]
         _ => false,
     }
 }
@@ -145,6 +173,8 @@ pub(crate) fn insert_streaming_nodes(
     lp_arena: &mut Arena<ALogicalPlan>,
     expr_arena: &mut Arena<AExpr>,
     scratch: &mut Vec<Node>,
+    memory: &QueryMemory,
+    cancel_token: &CancellationToken,
     fmt: bool,
 ) -> PolarsResult<bool> {
     // this is needed to determine which side of the joins should be
@@ -240,15 +270,7 @@ pub(crate) fn insert_streaming_nodes(
                     )
                 }
             }
-            #[cfg(feature = "csv-file")]
-            CsvScan { .. } => {
-                if state.streamable {
-                    state.sources.push(root);
-                    pipeline_trees[current_idx].push(state)
-                }
-            }
-            #[cfg(feature = "parquet")]
-            ParquetScan { .. } => {
+            lp if is_streamable_scan(lp) => {
                 if state.streamable {
                     state.sources.push(root);
                     pipeline_trees[current_idx].push(state)
@@ -264,8 +286,10 @@ pub(crate) fn insert_streaming_nodes(
                 input_left,
                 input_right,
                 options,
+                left_on,
+                right_on,
                 ..
-            } if streamable_join(&options.how) => {
+            } if streamable_join(&options.how, left_on, right_on, expr_arena) => {
                 let input_left = *input_left;
                 let input_right = *input_right;
                 state.streamable = true;
@@ -277,6 +301,8 @@ pub(crate) fn insert_streaming_nodes(
                 // *except* for a left join. In a left join we use the right
                 // table as build table and we stream the left table. This way
                 // we maintain order in the left join.
+                // The rows of the build table that are only known to be (un)matched
+                // after streaming, e.g. in an outer join, are flushed by the probe operator.
                 let (input_left, input_right) = if swap_join_order(options) {
                     (input_right, input_left)
                 } else {
@@ -304,19 +330,14 @@ pub(crate) fn insert_streaming_nodes(
                 stack.push((input_left, state_left, current_idx));
             }
             // add globbing patterns
-            #[cfg(all(feature = "csv-file", feature = "parquet"))]
             Union { inputs, .. } => {
                 if state.streamable
                     && inputs.iter().all(|node| match lp_arena.get(*node) {
-                        ParquetScan { .. } => true,
-                        CsvScan { .. } => true,
                         MapFunction {
                             input,
                             function: FunctionNode::Rechunk,
-                        } => {
-                            matches!(lp_arena.get(*input), ParquetScan { .. } | CsvScan { .. })
-                        }
-                        _ => false,
+                        } => is_streamable_scan(lp_arena.get(*input)),
+                        lp => is_streamable_scan(lp),
                     })
                 {
                     state.sources.push(root);
@@ -503,7 +524,14 @@ pub(crate) fn insert_streaming_nodes(
                     };
 
                     // replace the part of the logical plan with a `MapFunction` that will execute the pipeline.
-                    let pipeline_node = get_pipeline_node(lp_arena, most_left, schema, original_lp);
+                    let pipeline_node = get_pipeline_node(
+                        lp_arena,
+                        most_left,
+                        schema,
+                        original_lp,
+                        memory,
+                        cancel_token,
+                    );
                     lp_arena.replace(latest, pipeline_node);
                     inserted = true;
                 } else {
@@ -520,6 +548,14 @@ impl SExecutionContext for ExecutionState {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn memory(&self) -> &QueryMemory {
+        &self.memory
+    }
+
+    fn should_stop(&self) -> PolarsResult<()> {
+        self.cancel_token.check()
+    }
 }
 
 fn get_pipeline_node(
@@ -527,6 +563,8 @@ fn get_pipeline_node(
     mut pipeline: PipeLine,
     schema: SchemaRef,
     original_lp: Option<LogicalPlan>,
+    memory: &QueryMemory,
+    cancel_token: &CancellationToken,
 ) -> ALogicalPlan {
     // create a dummy input as the map function will call the input
     // so we just create a scan that returns an empty df
@@ -538,10 +576,14 @@ fn get_pipeline_node(
         selection: None,
     });
 
+    let memory = memory.clone();
+    let cancel_token = cancel_token.clone();
     ALogicalPlan::MapFunction {
         function: FunctionNode::Pipeline {
             function: Arc::new(move |_df: DataFrame| {
-                let state = ExecutionState::new();
+                let mut state = ExecutionState::new();
+                state.memory = memory.clone();
+                state.cancel_token = cancel_token.clone();
                 if state.verbose() {
                     eprintln!("RUN STREAMING PIPELINE")
                 }
@@ -1,11 +1,23 @@
 pub(crate) use polars_ops::prelude::*;
 pub use polars_plan::logical_plan::{
-    AnonymousScan, AnonymousScanOptions, Literal, LiteralValue, LogicalPlan, Null, NULL,
+    AnonymousScan, AnonymousScanBatches, AnonymousScanOptions, Literal, LiteralValue, LogicalPlan,
+    Null, NULL,
 };
+#[cfg(feature = "csv-file")]
+pub use polars_plan::prelude::CsvWriterOptions;
 #[cfg(feature = "ipc")]
 pub use polars_plan::prelude::IpcWriterOptions;
+#[cfg(feature = "json")]
+pub use polars_plan::prelude::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 pub use polars_plan::prelude::ParquetWriteOptions;
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv-file",
+    feature = "json"
+))]
+pub use polars_plan::prelude::SinkPartitionOptions;
 pub(crate) use polars_plan::prelude::*;
 #[cfg(feature = "rolling_window")]
 pub use polars_time::{prelude::RollingOptions, Duration};
@@ -51,6 +51,23 @@ fn test_agg_unique_first() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_agg_list_schema() -> PolarsResult<()> {
+    let df = df![
+        "g"=> [1, 1, 2],
+        "v"=> [1, 2, 3],
+    ]?;
+
+    // every group is collected into a list that holds a single list
+    let lf = df.lazy().groupby_stable([col("g")]).agg([col("v").list()]);
+    let schema = lf.schema()?;
+    let expected = DataType::List(Box::new(DataType::List(Box::new(DataType::Int32))));
+    assert_eq!(schema.get("v"), Some(&expected));
+    let out = lf.collect()?;
+    assert_eq!(out.column("v")?.dtype(), &expected);
+    Ok(())
+}
+
 #[test]
 fn test_lazy_agg_scan() {
     let lf = scan_foods_csv;
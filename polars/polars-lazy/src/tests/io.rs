@@ -1,4 +1,5 @@
 use polars_io::RowCount;
+use tempdir::TempDir;
 
 use super::*;
 
@@ -181,6 +182,7 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             rechunk: false,
             row_count: None,
             memmap: true,
+            hive_partitioning: false,
         },
     )?
     .collect()?;
@@ -411,6 +413,35 @@ fn scan_predicate_on_set_null_values() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn scan_csv_multi_char_delimiter() -> PolarsResult<()> {
+    let tempdir = TempDir::new("scan_csv_multi_char_delimiter")?;
+    let path = tempdir.path().join("data.csv");
+    std::fs::write(&path, "a||b||c\n1||\"x||y\"||3\n2||z\n3||w||4\n")?;
+
+    let lf = LazyCsvReader::new(&path)
+        .with_multi_char_delimiter("||")
+        .with_truncated_rows(true)
+        .finish()?
+        .filter(col("a").gt(lit(1)))
+        .select([col("b"), col("c")]);
+    let expected = df![
+        "b" => ["z", "w"],
+        "c" => [None, Some(4i64)],
+    ]?;
+    assert!(lf.clone().collect()?.frame_equal_missing(&expected));
+    assert!(lf
+        .with_streaming(true)
+        .collect()?
+        .frame_equal_missing(&expected));
+
+    let res = LazyCsvReader::new(&path)
+        .with_multi_char_delimiter("")
+        .finish();
+    assert!(matches!(res, Err(PolarsError::InvalidOperation(_))));
+    Ok(())
+}
+
 #[test]
 fn scan_anonymous_fn() -> PolarsResult<()> {
     let function = Arc::new(|_scan_opts: AnonymousScanOptions| Ok(fruits_cars()));
@@ -449,3 +480,281 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+/// Overwrite the given byte ranges of the file at `path` with garbage, such that reading
+/// them fails.
+fn corrupt_file(path: &std::path::Path, ranges: impl Iterator<Item = std::ops::Range<usize>>) {
+    let mut bytes = std::fs::read(path).unwrap();
+    for range in ranges {
+        bytes[range].fill(0xFF);
+    }
+    std::fs::write(path, bytes).unwrap();
+}
+
+#[test]
+fn test_parquet_page_index_pushdown() -> PolarsResult<()> {
+    use polars_core::export::arrow::io::parquet::read::{read_metadata, read_pages_locations};
+
+    let tmp = TempDir::new("polars_test_parquet_page_index_pushdown")?;
+    let path = tmp.path().join("data.parquet");
+    let n = 10_000i64;
+    let mut df = df![
+        "a" => (0..n).collect::<Vec<_>>(),
+        "b" => (0..n).map(|i| format!("v{i}")).collect::<Vec<_>>()
+    ]?;
+    // small pages, such that every column consists of many pages
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_data_pagesize_limit(Some(1024))
+        .finish(&mut df)?;
+
+    for parallel in [ParallelStrategy::None, ParallelStrategy::Auto] {
+        let args = ScanArgsParquet {
+            parallel,
+            ..Default::default()
+        };
+        let lf = LazyFrame::scan_parquet(&path, args)?;
+
+        let out = lf.clone().filter(col("a").eq(lit(4321i64))).collect()?;
+        assert_eq!(Vec::from(out.column("b")?.utf8()?), &[Some("v4321")]);
+
+        let out = lf
+            .clone()
+            .filter(col("a").gt(lit(n - 4)).or(col("a").lt(lit(2i64))))
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i64()?),
+            &[Some(0), Some(1), Some(n - 3), Some(n - 2), Some(n - 1)]
+        );
+
+        // the row count must not be affected by skipped pages
+        let out = lf
+            .with_row_count("idx", None)
+            .filter(col("b").eq(lit("v9000")))
+            .collect()?;
+        assert_eq!(Vec::from(out.column("idx")?.idx()?), &[Some(9000)]);
+    }
+
+    // the pages that cannot hold the row are not read at all, so corrupting them doesn't
+    // affect the result
+    let row = 4321;
+    let mut file = std::fs::File::open(&path)?;
+    let metadata = read_metadata(&mut file)?;
+    assert_eq!(metadata.row_groups.len(), 1);
+    let locations = read_pages_locations(&mut file, metadata.row_groups[0].columns())?;
+    let mut skipped = vec![];
+    for column in &locations {
+        assert!(column.len() > 10);
+        for (i, page) in column.iter().enumerate() {
+            let end = column.get(i + 1).map_or(n, |next| next.first_row_index);
+            if !(page.first_row_index..end).contains(&row) {
+                let start = page.offset as usize;
+                skipped.push(start..start + page.compressed_page_size as usize);
+            }
+        }
+    }
+    corrupt_file(&path, skipped.into_iter());
+
+    for parallel in [ParallelStrategy::None, ParallelStrategy::Auto] {
+        let args = ScanArgsParquet {
+            parallel,
+            ..Default::default()
+        };
+        let lf = LazyFrame::scan_parquet(&path, args)?;
+        let out = lf.clone().filter(col("a").eq(lit(row))).collect()?;
+        assert_eq!(Vec::from(out.column("b")?.utf8()?), &[Some("v4321")]);
+        // without a predicate all pages are read
+        assert!(lf.collect().is_err());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_parquet_bloom_filter_pushdown() -> PolarsResult<()> {
+    use polars_core::export::arrow::io::parquet::bloom_filter;
+    use polars_core::export::arrow::io::parquet::read::read_metadata;
+    use polars_core::export::arrow::io::parquet::write::write_metadata_sidecar;
+
+    let tmp = TempDir::new("polars_test_parquet_bloom_filter_pushdown")?;
+    let path = tmp.path().join("data.parquet");
+    // even numbers, such that the min/max statistics cannot rule out odd numbers
+    let values = (0..1000i64).map(|i| i * 2).collect::<Vec<_>>();
+    let mut df = df!["a" => &values]?;
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+
+    // our writer doesn't write bloom filters, so we append one to the file and point the
+    // metadata of the column to it
+    let mut bytes = std::fs::read(&path)?;
+    let metadata_len =
+        u32::from_le_bytes(bytes[bytes.len() - 8..bytes.len() - 4].try_into().unwrap());
+    let metadata = read_metadata(&mut std::io::Cursor::new(&bytes))?;
+    bytes.truncate(bytes.len() - 8 - metadata_len as usize);
+
+    let mut bitset = vec![0u8; 1024];
+    for v in &values {
+        bloom_filter::insert(&mut bitset, bloom_filter::hash_native(*v));
+    }
+    let bloom_filter_offset = bytes.len() as i64;
+    // the thrift compact encoding of the header of a block split bloom filter of 1024 bytes
+    // that is uncompressed and hashed with xxhash
+    bytes.extend_from_slice(&[
+        0x15, 0x80, 0x10, 0x1C, 0x1C, 0x00, 0x00, 0x1C, 0x1C, 0x00, 0x00, 0x1C, 0x1C, 0x00, 0x00,
+        0x00,
+    ]);
+    bytes.extend_from_slice(&bitset);
+
+    let column_range = {
+        let (start, len) = metadata.row_groups[0].columns()[0].byte_range();
+        start as usize..(start + len) as usize
+    };
+    let mut metadata = metadata.into_thrift();
+    metadata.row_groups[0].columns[0]
+        .meta_data
+        .as_mut()
+        .unwrap()
+        .bloom_filter_offset = Some(bloom_filter_offset);
+    let mut footer = vec![];
+    write_metadata_sidecar(&mut footer, &metadata)?;
+    // skip the magic bytes at the start of the sidecar
+    bytes.extend_from_slice(&footer[4..]);
+    std::fs::write(&path, bytes)?;
+
+    let lf = LazyFrame::scan_parquet(&path, Default::default())?;
+    let out = lf.clone().filter(col("a").eq(lit(500i64))).collect()?;
+    assert_eq!(out.height(), 1);
+
+    // once the column is corrupted only the row groups that are skipped can be scanned
+    corrupt_file(&path, std::iter::once(column_range));
+    let out = lf.clone().filter(col("a").eq(lit(501i64))).collect()?;
+    assert_eq!(out.height(), 0);
+    assert_eq!(out.get_column_names(), &["a"]);
+    assert!(lf.filter(col("a").eq(lit(500i64))).collect().is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn test_hive_partitioned_scan() -> PolarsResult<()> {
+    let tmp = TempDir::new("polars_test_hive_partitioned_scan")?;
+    let root = tmp.path();
+    for (year, city, values) in [
+        (2022, "Utrecht", [1, 2]),
+        (2023, "Utrecht", [3, 4]),
+        (2023, "Amsterdam", [5, 6]),
+    ] {
+        let dir = root.join(format!("year={year}/city=\"{city}\""));
+        std::fs::create_dir_all(&dir)?;
+        let mut df = df!["value" => values]?;
+        ParquetWriter::new(std::fs::File::create(dir.join("data-0000.parquet"))?)
+            .finish(&mut df)?;
+    }
+    let glob = root.join("**/*.parquet");
+    let args = ScanArgsParquet {
+        hive_partitioning: true,
+        ..Default::default()
+    };
+    let lf = LazyFrame::scan_parquet(&glob, args)?;
+    assert_eq!(
+        lf.schema()?.iter_dtypes().cloned().collect::<Vec<_>>(),
+        &[DataType::Int32, DataType::Int64, DataType::Utf8]
+    );
+
+    // the row count is kept when partition keys are projected
+    let args = ScanArgsParquet {
+        hive_partitioning: true,
+        row_count: Some(RowCount {
+            name: "idx".into(),
+            offset: 0,
+        }),
+        ..Default::default()
+    };
+    let df = LazyFrame::scan_parquet(&glob, args)?
+        .select([col("idx"), col("city")])
+        .collect()?;
+    assert_eq!(df.get_column_names(), &["idx", "city"]);
+    assert_eq!(df.height(), 6);
+
+    let df = lf.clone().sort("value", Default::default()).collect()?;
+    assert_eq!(df.shape(), (6, 3));
+    assert_eq!(
+        Vec::from(df.column("city")?.utf8()?),
+        &[
+            Some("Utrecht"),
+            Some("Utrecht"),
+            Some("Utrecht"),
+            Some("Utrecht"),
+            Some("Amsterdam"),
+            Some("Amsterdam")
+        ]
+    );
+
+    // filters on partition keys prune whole files, the other filters are still applied
+    let df = lf
+        .clone()
+        .filter(
+            col("year")
+                .eq(lit(2023))
+                .and(col("city").eq(lit("Utrecht")).or(col("value").gt(lit(5)))),
+        )
+        .select([col("value"), col("city")])
+        .sort("value", Default::default())
+        .collect()?;
+    assert_eq!(
+        Vec::from(df.column("value")?.i32()?),
+        &[Some(3), Some(4), Some(6)]
+    );
+    assert_eq!(df.get_column_names(), &["value", "city"]);
+
+    let df = lf.filter(col("year").lt(lit(2020))).collect()?;
+    assert_eq!(df.shape(), (0, 3));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "avro")]
+#[cfg(not(target_os = "windows"))]
+fn test_avro_scan() -> PolarsResult<()> {
+    use polars_core::utils::accumulate_dataframes_vertical;
+    use polars_io::avro::AvroWriter;
+
+    let root = std::env::temp_dir().join("polars_test_avro_scan");
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root)?;
+    let mut dfs = vec![];
+    for i in 0..2 {
+        let mut df = df![
+            "a" => (0..1000i64).map(|v| v % 5 + i).collect::<Vec<_>>(),
+            "b" => (0..1000i64).collect::<Vec<_>>(),
+            "c" => (0..1000).map(|v| format!("s{v}")).collect::<Vec<_>>(),
+        ]?;
+        AvroWriter::new(std::fs::File::create(root.join(format!("data-{i}.avro")))?)
+            .finish(&mut df)?;
+        dfs.push(df);
+    }
+    let expected = accumulate_dataframes_vertical(dfs)?;
+    let lf = LazyFrame::scan_avro(root.join("*.avro"), Default::default())?;
+
+    let q = |lf: LazyFrame| {
+        lf.filter(col("a").eq(lit(2i64)))
+            .select([col("c"), col("b")])
+            .collect()
+    };
+    assert!(q(lf.clone())?.frame_equal(&q(expected.clone().lazy())?));
+
+    let out = lf.clone().select([col("c")]).limit(3).collect()?;
+    assert_eq!(out.shape(), (3, 1));
+
+    let args = ScanArgsAvro {
+        n_rows: Some(10),
+        row_count: Some(RowCount {
+            name: "row_nr".into(),
+            offset: 0,
+        }),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_avro(root.join("data-1.avro"), args)?.collect()?;
+    assert_eq!(out.shape(), (10, 4));
+    assert_eq!(out.get_column_names(), &["row_nr", "a", "b", "c"]);
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
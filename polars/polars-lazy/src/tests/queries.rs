@@ -1892,3 +1892,87 @@ fn test_partitioned_gb_ternary() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_collect_cancelled() -> PolarsResult<()> {
+    let q = fruits_cars()
+        .lazy()
+        .groupby([col("fruits")])
+        .agg([col("B").sum()]);
+
+    for streaming in [false, true] {
+        let q = q.clone().with_streaming(streaming);
+        let out = q.clone().collect_with_timeout(std::time::Duration::ZERO);
+        assert!(matches!(out, Err(PolarsError::Timeout(_))));
+
+        let token = polars_core::cancel::CancellationToken::new();
+        assert!(q.clone().collect_with_token(&token).is_ok());
+        token.cancel();
+        assert!(matches!(
+            q.collect_with_token(&token),
+            Err(PolarsError::Cancelled(_))
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_collect_cancelled_while_running() -> PolarsResult<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    // every aggregation takes a while and the token is checked between them, there are
+    // enough aggregations to keep every thread busy for at least a second
+    let n_aggs = 10 * polars_core::POOL.current_num_threads();
+    let n_ran = Arc::new(AtomicUsize::new(0));
+    let aggs = (0..n_aggs)
+        .map(|i| {
+            let n_ran = n_ran.clone();
+            col("B")
+                .map(
+                    move |s| {
+                        n_ran.fetch_add(1, Ordering::Relaxed);
+                        std::thread::sleep(Duration::from_millis(100));
+                        Ok(Some(s))
+                    },
+                    GetOutput::same_type(),
+                )
+                .sum()
+                .alias(&format!("b_{i}"))
+        })
+        .collect::<Vec<_>>();
+    let q = fruits_cars().lazy().groupby([col("fruits")]).agg(aggs);
+
+    let token = polars_core::cancel::CancellationToken::new();
+    let handle = {
+        let token = token.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(100));
+            token.cancel();
+        })
+    };
+    let out = q.collect_with_token(&token);
+    handle.join().unwrap();
+    assert!(matches!(out, Err(PolarsError::Cancelled(_))));
+    // the remaining aggregations are skipped once the token is cancelled
+    assert!(n_ran.load(Ordering::Relaxed) < n_aggs);
+    Ok(())
+}
+
+#[test]
+fn test_cache_memory_released() -> PolarsResult<()> {
+    use crate::physical_plan::state::ExecutionState;
+
+    let state = ExecutionState::new();
+    let df = fruits_cars();
+    state.store_cache(0, df.clone(), 2);
+    assert_eq!(state.memory.used(), df.estimated_size());
+
+    assert!(state.cache_hit(&0).is_some());
+    assert_eq!(state.memory.used(), df.estimated_size());
+    // the last expected hit takes the cache and releases its memory
+    assert!(state.cache_hit(&0).unwrap().frame_equal(&df));
+    assert_eq!(state.memory.used(), 0);
+    assert!(state.cache_hit(&0).is_none());
+    Ok(())
+}
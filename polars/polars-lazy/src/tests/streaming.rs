@@ -1,3 +1,5 @@
+use tempdir::TempDir;
+
 use super::*;
 
 fn get_csv_file() -> LazyFrame {
@@ -66,6 +68,111 @@ fn test_streaming_glob() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "ipc")]
+fn test_streaming_ipc() -> PolarsResult<()> {
+    let q = scan_foods_ipc();
+
+    let q = q
+        .filter(col("sugars_g").gt(lit(10)))
+        .groupby([col("category")])
+        .agg([col("calories").sum()])
+        .sort("category", Default::default());
+
+    assert_streaming_with_default(q);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn test_streaming_ndjson() -> PolarsResult<()> {
+    init_files();
+    let q = LazyJsonLineReader::new("../../examples/datasets/foods1.ndjson".into()).finish()?;
+
+    let q = q
+        .select([col("sugars_g"), col("calories")])
+        .groupby([col("sugars_g")])
+        .agg([col("calories").sum()])
+        .sort("sugars_g", Default::default());
+
+    assert_streaming_with_default(q);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "avro")]
+fn test_streaming_avro_globbing() -> PolarsResult<()> {
+    use polars_io::avro::AvroWriter;
+
+    let root = std::env::temp_dir().join("polars_test_streaming_avro");
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root)?;
+    for i in 0..2 {
+        let mut df = df![
+            "a" => (0..1000i64).map(|v| v % 5 + i).collect::<Vec<_>>(),
+            "b" => (0..1000i64).collect::<Vec<_>>(),
+        ]?;
+        AvroWriter::new(std::fs::File::create(root.join(format!("data-{i}.avro")))?)
+            .finish(&mut df)?;
+    }
+    // the filter must be applied to every file of the union
+    let q = LazyFrame::scan_avro(root.join("*.avro"), Default::default())?
+        .filter(col("b").gt(lit(100i64)))
+        .groupby([col("a")])
+        .agg([col("b").sum()])
+        .sort("a", Default::default());
+
+    assert_streaming_with_default(q);
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
+
+struct BatchedScan(DataFrame);
+
+impl AnonymousScan for BatchedScan {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn scan(&self, _scan_opts: AnonymousScanOptions) -> PolarsResult<DataFrame> {
+        Ok(self.0.clone())
+    }
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<Schema> {
+        Ok(self.0.schema())
+    }
+    fn allows_streaming(&self) -> bool {
+        true
+    }
+    fn scan_batched(
+        &self,
+        _scan_opts: AnonymousScanOptions,
+        _chunk_size: usize,
+    ) -> PolarsResult<Box<dyn AnonymousScanBatches>> {
+        // a batch of 3 rows per call
+        let df = self.0.clone();
+        let mut offset = 0;
+        Ok(Box::new(move |_n| {
+            let batch = df.slice(offset, 3);
+            offset += 3;
+            Ok((batch.height() > 0).then(|| vec![batch]))
+        }))
+    }
+}
+
+#[test]
+fn test_streaming_anonymous_scan() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..10).map(|i| i % 3).collect::<Vec<i32>>(),
+        "b" => (0..10).collect::<Vec<i32>>(),
+    ]?;
+    let q = LazyFrame::anonymous_scan(Arc::new(BatchedScan(df)), Default::default())?
+        .groupby([col("a")])
+        .agg([col("b").sum()])
+        .sort("a", Default::default());
+
+    assert_streaming_with_default(q);
+    Ok(())
+}
+
 #[test]
 fn test_streaming_multiple_keys_aggregate() -> PolarsResult<()> {
     let q = get_csv_glob();
@@ -100,6 +207,91 @@ fn test_streaming_first_sum() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "dtype-date")]
+fn test_streaming_groupby_temporal_list() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 2],
+        "d" => [0i32, 1, 2],
+    ]?;
+    let q = df
+        .lazy()
+        .with_column(col("d").cast(DataType::Date))
+        .groupby([col("g")])
+        .agg([col("d").list()])
+        .sort("g", Default::default());
+
+    let out = q.clone().with_streaming(true).collect()?;
+    let expected = DataType::List(Box::new(DataType::List(Box::new(DataType::Date))));
+    assert_eq!(out.column("d")?.dtype(), &expected);
+    assert_streaming_with_default(q);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_groupby_var_ddof() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 2, 2, 2],
+        "v" => [1.0, 2.0, 3.0, 4.0, 6.0],
+    ]?;
+    let q = df
+        .lazy()
+        .groupby([col("g")])
+        .agg([
+            col("v").var(2).alias("var"),
+            col("v").n_unique().alias("n_unique"),
+        ])
+        .sort("g", Default::default());
+
+    // there is no variance if a group has no more values than `ddof`
+    let out = q.clone().with_streaming(true).collect()?;
+    let var = out.column("var")?.f64()?;
+    assert_eq!(var.get(0), None);
+    assert!((var.get(1).unwrap() - 14.0 / 3.0).abs() < 1e-9);
+    assert_eq!(out.column("n_unique")?.dtype(), &IDX_DTYPE);
+    assert_streaming_with_default(q);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_statistical_aggregates() -> PolarsResult<()> {
+    let q = get_csv_file();
+
+    let q = q
+        .groupby([col("sugars_g")])
+        .agg([
+            col("calories").median().alias("median"),
+            col("calories")
+                .quantile(lit(0.3), QuantileInterpolOptions::Lower)
+                .alias("quantile"),
+            col("calories").n_unique().alias("n_unique"),
+            col("calories").list().alias("list"),
+        ])
+        .sort("sugars_g", Default::default());
+
+    assert_streaming_with_default(q);
+
+    // the floating point results may differ in the last bits
+    let q = get_csv_file()
+        .groupby([col("sugars_g")])
+        .agg([
+            col("calories").var(1).alias("var"),
+            col("fats_g").std(0).alias("std"),
+        ])
+        .sort("sugars_g", Default::default());
+    let out = q.clone().with_streaming(true).collect()?;
+    let expected = q.with_streaming(false).collect()?;
+    assert_eq!(
+        out.column("var")?.null_count(),
+        expected.column("var")?.null_count()
+    );
+    for name in ["var", "std"] {
+        let diff = out.column(name)? - expected.column(name)?;
+        assert!(diff.f64()?.into_iter().flatten().all(|d| d.abs() < 1e-6));
+    }
+    Ok(())
+}
+
 #[test]
 fn test_streaming_aggregate_slice() -> PolarsResult<()> {
     let q = get_parquet_file();
@@ -212,6 +404,159 @@ fn test_streaming_left_join() -> PolarsResult<()> {
     Ok(())
 }
 
+fn get_join_tables() -> PolarsResult<(LazyFrame, LazyFrame)> {
+    let lf_left = df![
+           "a"=> [0, 0, 0, 3, 0, 1, 3, 3, 3, 1, 4, 4, 2, 1, 1, 3, 1, 4, 2, 2],
+    "b"=> [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19]
+       ]?
+    .lazy();
+
+    let lf_right = df![
+           "a"=> [10, 18, 13, 9, 1, 13, 14, 12, 15, 11, 4],
+    "b"=> [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]
+       ]?
+    .lazy();
+    Ok((lf_left, lf_right))
+}
+
+fn assert_streaming_with_default_unordered(q: LazyFrame) {
+    let q_streaming = q.clone().with_streaming(true);
+    let q_expected = q.with_streaming(false);
+
+    let out = q_streaming.collect().unwrap();
+    let expected = q_expected.collect().unwrap();
+    let by = expected.get_column_names_owned();
+    let descending = vec![false; by.len()];
+    let out = out.sort(&by, descending.clone()).unwrap();
+    let expected = expected.sort(&by, descending).unwrap();
+    assert!(out.frame_equal_missing(&expected));
+}
+
+#[test]
+fn test_streaming_outer_join() -> PolarsResult<()> {
+    let (lf_left, lf_right) = get_join_tables()?;
+
+    // the right table is the smallest and becomes the build table
+    let q = lf_left
+        .clone()
+        .outer_join(lf_right.clone(), col("a"), col("a"));
+    assert_streaming_with_default_unordered(q);
+
+    // the left table is the smallest and becomes the build table
+    let q = lf_right
+        .clone()
+        .outer_join(lf_left.clone(), col("a"), col("a"));
+    assert_streaming_with_default_unordered(q);
+
+    let q = lf_left.join(
+        lf_right,
+        [col("a"), col("b")],
+        [col("a"), col("b")],
+        JoinType::Outer,
+    );
+    assert_streaming_with_default_unordered(q);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_streaming_semi_anti_join() -> PolarsResult<()> {
+    let (lf_left, lf_right) = get_join_tables()?;
+
+    for how in [JoinType::Semi, JoinType::Anti] {
+        for (left, right) in [
+            (lf_left.clone(), lf_right.clone()),
+            (lf_right.clone(), lf_left.clone()),
+        ] {
+            let q = left.join(right, [col("a")], [col("a")], how.clone());
+            assert_streaming_with_default_unordered(q);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_streaming_memory_budget() -> PolarsResult<()> {
+    let (lf_left, lf_right) = get_join_tables()?;
+
+    // a budget of a single byte forces every sink out-of-core
+    let q = lf_left
+        .clone()
+        .groupby([col("a")])
+        .agg([col("b").sum()])
+        .with_memory_budget(Some(1));
+    assert_streaming_with_default_unordered(q);
+
+    let q = lf_left
+        .clone()
+        .sort("b", Default::default())
+        .with_memory_budget(Some(1));
+    assert_streaming_with_default(q);
+
+    let q = lf_left
+        .inner_join(lf_right, col("a"), col("a"))
+        .with_memory_budget(Some(1));
+    assert_streaming_with_default_unordered(q);
+    Ok(())
+}
+
+#[test]
+fn test_partitioned_groupby_memory_budget() -> PolarsResult<()> {
+    use crate::physical_plan::executors::Executor;
+
+    let (lf_left, _) = get_join_tables()?;
+    // the default engine runs this groupby in a streaming pipeline,
+    // which must register with the budget of this query
+    let q = lf_left
+        .groupby([col("a")])
+        .agg([col("b").sum()])
+        .with_memory_budget(Some(1));
+    let (mut state, mut physical_plan, _) =
+        q.clone().prepare_collect(false, &Default::default())?;
+    let memory = state.memory.clone();
+    let out = physical_plan.execute(&mut state)?;
+    assert!(memory.spilled());
+
+    let expected = q.with_memory_budget(None).collect()?;
+    assert!(out
+        .sort(["a"], false)?
+        .frame_equal(&expected.sort(["a"], false)?));
+    Ok(())
+}
+
+#[test]
+fn test_streaming_ooc_join() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let lf_left = df![
+        "a" => (0..10_000).map(|i| i % 1000).collect::<Vec<i32>>(),
+        "b" => (0..10_000).collect::<Vec<i32>>(),
+    ]?
+    .lazy();
+    // not all keys of the probe table are in the build table
+    let lf_right = df![
+        "a" => (0..2_000).map(|i| i * 3).collect::<Vec<i32>>(),
+        "c" => (0..2_000).map(|i| i % 7).collect::<Vec<i32>>(),
+    ]?
+    .lazy();
+
+    std::env::set_var("POLARS_FORCE_OOC_JOIN", "1");
+    for how in [JoinType::Inner, JoinType::Outer] {
+        let q = lf_left
+            .clone()
+            .join(lf_right.clone(), [col("a")], [col("a")], how.clone());
+        assert_streaming_with_default_unordered(q);
+        let q = lf_right
+            .clone()
+            .join(lf_left.clone(), [col("a")], [col("a")], how);
+        assert_streaming_with_default_unordered(q);
+    }
+    // a left join keeps the order of the left table, so it stays in memory
+    let q = lf_left.left_join(lf_right, col("a"), col("a"));
+    assert_streaming_with_default(q);
+    std::env::remove_var("POLARS_FORCE_OOC_JOIN");
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "cross_join")]
 fn test_streaming_slice() -> PolarsResult<()> {
@@ -330,3 +675,153 @@ fn test_streaming_double_left_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_sink_parquet_partitioned() -> PolarsResult<()> {
+    let tempdir = TempDir::new("sink_parquet_partitioned")?;
+    let root = tempdir.path().join("out");
+    let df = df![
+        "key" => (0..1000).map(|i| i % 3).collect::<Vec<i32>>(),
+        "value" => (0..1000).collect::<Vec<i32>>(),
+    ]?;
+    let partitioning = SinkPartitionOptions {
+        by: vec!["key".into()],
+        max_rows_per_file: Some(150),
+        max_bytes_per_file: None,
+    };
+    df.clone()
+        .lazy()
+        .sink_parquet_partitioned(root.clone(), partitioning, Default::default())?;
+
+    // 333 or 334 rows per partition in files of at most 150 rows
+    for key in 0..3 {
+        let dir = root.join(format!("key={key}"));
+        assert_eq!(std::fs::read_dir(dir)?.count(), 3);
+    }
+    let args = ScanArgsParquet {
+        hive_partitioning: true,
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_parquet(root.join("*/*.parquet"), args)?
+        .sort("value", Default::default())
+        .collect()?;
+    assert!(out.frame_equal(&df));
+    Ok(())
+}
+
+#[test]
+fn test_sink_partitioned_utf8_and_null_keys() -> PolarsResult<()> {
+    let tempdir = TempDir::new("sink_partitioned_utf8_and_null_keys")?;
+    let root = tempdir.path().join("out");
+    let df = df![
+        "city" => [Some("NY"), None, Some("a/b=c"), Some("NY"), None],
+        "value" => [1, 2, 3, 4, 5],
+    ]?;
+    let partitioning = SinkPartitionOptions {
+        by: vec!["city".into()],
+        ..Default::default()
+    };
+    df.clone()
+        .lazy()
+        .sink_parquet_partitioned(root.clone(), partitioning, Default::default())?;
+
+    let mut dirs = std::fs::read_dir(&root)?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    dirs.sort();
+    assert_eq!(
+        dirs,
+        &[
+            "city=\"NY\"",
+            "city=\"a%2Fb%3Dc\"",
+            "city=__HIVE_DEFAULT_PARTITION__"
+        ]
+    );
+
+    let args = ScanArgsParquet {
+        hive_partitioning: true,
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_parquet(root.join("*/*.parquet"), args)?
+        .sort("value", Default::default())
+        .collect()?;
+    assert!(out.frame_equal_missing(&df));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc")]
+fn test_sink_ipc_partitioned_max_bytes() -> PolarsResult<()> {
+    let tempdir = TempDir::new("sink_ipc_partitioned_max_bytes")?;
+    let root = tempdir.path().join("out");
+    let df = df![
+        "value" => (0..100_000i64).collect::<Vec<_>>(),
+    ]?;
+    let max_bytes = 64 * 1024;
+    let partitioning = SinkPartitionOptions {
+        by: vec![],
+        max_rows_per_file: None,
+        max_bytes_per_file: Some(max_bytes),
+    };
+    df.clone()
+        .lazy()
+        .sink_ipc_partitioned(root.clone(), partitioning, Default::default())?;
+
+    // 800kB of values in files that are split at 64kB
+    let sizes = std::fs::read_dir(&root)?
+        .map(|entry| Ok(entry?.metadata()?.len()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    assert!(sizes.len() > 10);
+    assert!(sizes.iter().all(|&size| size < 2 * max_bytes));
+
+    let out = LazyFrame::scan_ipc(root.join("*.ipc"), Default::default())?
+        .sort("value", Default::default())
+        .collect()?;
+    assert!(out.frame_equal(&df));
+    Ok(())
+}
+
+#[test]
+fn test_sink_csv() -> PolarsResult<()> {
+    let tmp = TempDir::new("polars_test_sink_csv")?;
+    let path = tmp.path().join("data.csv");
+    let df = df![
+        "a" => [Some(1.125f64), None, Some(3.0)],
+        "b" => ["x", "y;z", "w"],
+    ]?;
+    let options = CsvWriterOptions {
+        maintain_order: true,
+        serialize_options: polars_io::csv::SerializeOptions {
+            delimiter: b';',
+            float_precision: Some(2),
+            null: "NA".into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    df.lazy().sink_csv(path.clone(), options)?;
+    assert_eq!(
+        std::fs::read_to_string(&path)?,
+        "a;b\n1.12;x\nNA;\"y;z\"\n3.00;w\n"
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn test_sink_ndjson() -> PolarsResult<()> {
+    let tmp = TempDir::new("polars_test_sink_ndjson")?;
+    let path = tmp.path().join("data.jsonl");
+    let df = df![
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| (i % 3 != 0).then(|| format!("v{i}"))).collect::<Vec<_>>(),
+    ]?;
+    let options = JsonWriterOptions {
+        maintain_order: true,
+    };
+    df.clone().lazy().sink_ndjson(path.clone(), options)?;
+
+    let out = JsonLineReader::from_path(path)?.finish()?;
+    assert!(out.frame_equal_missing(&df));
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+/// A source of tables for schema-qualified names such as `catalog.schema.table`.
+///
+/// A catalog is registered on a [`SQLContext`](crate::SQLContext) under the first
+/// part of such a name and is asked for the table whenever a query refers to it,
+/// so implementations can create their [`LazyFrame`]s on demand.
+pub trait TableCatalog: Send + Sync {
+    /// Get the table at `path`, the qualified name without the catalog part,
+    /// e.g. `["schema", "table"]`. Returns `None` if there is no such table.
+    fn get_table(&self, path: &[&str]) -> PolarsResult<Option<LazyFrame>>;
+}
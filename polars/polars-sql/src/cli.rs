@@ -1,9 +1,18 @@
 use std::ffi::OsStr;
-use std::io::{self, BufRead, Read, Write};
-use std::path::Path;
+use std::fs::File;
+use std::io::{self, BufRead, BufWriter, Read, Write};
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::time::{Duration, Instant};
 
 use polars_core::prelude::*;
+use polars_io::csv::CsvWriter;
+#[cfg(feature = "json")]
+use polars_io::json::{JsonFormat, JsonWriter};
+#[cfg(feature = "parquet")]
+use polars_io::parquet::ParquetWriter;
+use polars_io::SerWriter;
 use polars_lazy::frame::LazyFrame;
 #[cfg(feature = "ipc")]
 use polars_lazy::frame::ScanArgsIpc;
@@ -15,7 +24,7 @@ use polars_sql::SQLContext;
 use rustyline::completion::FilenameCompleter;
 use rustyline::error::ReadlineError;
 use rustyline::{DefaultEditor, Result};
-use sqlparser::ast::{Select, SetExpr, Statement, TableFactor, TableWithJoins};
+use sqlparser::ast::{ObjectName, Query, Select, SetExpr, Statement, TableFactor, TableWithJoins};
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::Parser;
 
@@ -28,38 +37,388 @@ const SUPPORTED_FILE_EXTENSIONS: &[&str] = &[
     "ipc",
 ];
 
-// Command: /dd | dataframes
-fn print_dataframes(dataframes: &Vec<(String, String)>) {
-    println!(
-        "{} dataframes registered{}",
-        dataframes.len(),
-        if dataframes.is_empty() { "." } else { ":" }
-    );
-    for (name, file) in dataframes.iter() {
-        println!("{}:\t {}", name, file);
+const USAGE: &str = "\
+Usage: polars-sql [OPTIONS] [FILE]
+
+Runs the SQL statements in FILE, the -c argument or stdin, or starts an
+interactive session if none are given and stdin is a terminal.
+
+Options:
+  -c, --command <SQL>    Run the given SQL statements and exit
+  -f, --file <FILE>      Run the SQL statements in FILE and exit
+  -o, --output <FILE>    Write results to FILE instead of stdout; the file
+                         holds the result of the last statement
+      --format <FORMAT>  Output format: table, csv, ndjson, parquet or markdown;
+                         inferred from the --output extension by default
+      --timing           Report the row count and execution time of every statement
+  -h, --help             Print this help";
+
+/// Format in which query results are written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum OutputFormat {
+    Table,
+    Csv,
+    #[cfg(feature = "json")]
+    Ndjson,
+    #[cfg(feature = "parquet")]
+    Parquet,
+    Markdown,
+}
+
+impl OutputFormat {
+    fn from_path(path: &Path) -> Option<Self> {
+        match path.extension().and_then(OsStr::to_str)? {
+            "csv" => Some(OutputFormat::Csv),
+            #[cfg(feature = "json")]
+            "ndjson" | "jsonl" => Some(OutputFormat::Ndjson),
+            #[cfg(feature = "parquet")]
+            "parquet" => Some(OutputFormat::Parquet),
+            "md" => Some(OutputFormat::Markdown),
+            _ => None,
+        }
     }
 }
-// Command: /rd | register
-fn register_dataframe(
-    context: &mut SQLContext,
-    dataframes: &mut Vec<(String, String)>,
-    command: Vec<&str>,
-) {
-    if command.len() < 3 {
-        println!("Usage: \\rd <name> <file>");
-        return;
+
+impl FromStr for OutputFormat {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> PolarsResult<Self> {
+        Ok(match s {
+            "table" => OutputFormat::Table,
+            "csv" => OutputFormat::Csv,
+            #[cfg(feature = "json")]
+            "ndjson" => OutputFormat::Ndjson,
+            #[cfg(feature = "parquet")]
+            "parquet" => OutputFormat::Parquet,
+            "markdown" => OutputFormat::Markdown,
+            _ => polars_bail!(ComputeError: "unsupported output format: \"{}\"", s),
+        })
+    }
+}
+
+fn write_frame(
+    df: &mut DataFrame,
+    format: OutputFormat,
+    mut writer: impl Write,
+) -> PolarsResult<()> {
+    match format {
+        OutputFormat::Table => writeln!(writer, "{}", df)?,
+        OutputFormat::Csv => CsvWriter::new(writer).finish(df)?,
+        #[cfg(feature = "json")]
+        OutputFormat::Ndjson => JsonWriter::new(writer)
+            .with_json_format(JsonFormat::JsonLines)
+            .finish(df)?,
+        #[cfg(feature = "parquet")]
+        OutputFormat::Parquet => {
+            ParquetWriter::new(writer).finish(df)?;
+        }
+        OutputFormat::Markdown => write_markdown(df, writer)?,
+    }
+    Ok(())
+}
+
+fn write_markdown(df: &DataFrame, mut writer: impl Write) -> PolarsResult<()> {
+    let escape = |s: &str| s.replace('|', "\\|");
+    let names = df
+        .get_column_names()
+        .into_iter()
+        .map(escape)
+        .collect::<Vec<_>>();
+    writeln!(writer, "| {} |", names.join(" | "))?;
+    writeln!(writer, "|{}", " --- |".repeat(df.width()))?;
+    for i in 0..df.height() {
+        let row = df
+            .get_columns()
+            .iter()
+            .map(|s| Ok(escape(&s.str_value(i)?)))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        writeln!(writer, "| {} |", row.join(" | "))?;
+    }
+    Ok(())
+}
+
+/// Options given on the command line.
+#[derive(Default)]
+struct Args {
+    command: Option<String>,
+    file: Option<String>,
+    output: Option<PathBuf>,
+    format: Option<OutputFormat>,
+    timing: bool,
+    help: bool,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> PolarsResult<Self> {
+        let mut out = Args::default();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| polars_err!(ComputeError: "option {} requires a value", arg))
+            };
+            match arg.as_str() {
+                "-c" | "--command" => out.command = Some(value()?),
+                "-f" | "--file" => out.file = Some(value()?),
+                "-o" | "--output" => out.output = Some(value()?.into()),
+                "--format" => out.format = Some(value()?.parse()?),
+                "--timing" => out.timing = true,
+                "-h" | "--help" => out.help = true,
+                _ if arg.starts_with('-') => {
+                    polars_bail!(ComputeError: "unknown option: {}", arg)
+                }
+                _ => {
+                    polars_ensure!(
+                        out.file.is_none(),
+                        ComputeError: "unexpected argument: {}", arg
+                    );
+                    out.file = Some(arg)
+                }
+            }
+        }
+        polars_ensure!(
+            out.command.is_none() || out.file.is_none(),
+            ComputeError: "a command and a file cannot be run at the same time"
+        );
+        Ok(out)
+    }
+}
+
+/// Finds the ends of the statements of a script, which is scanned line by line.
+///
+/// A `;` only ends a statement outside of quoted strings, identifiers and comments,
+/// which may span multiple lines.
+#[derive(Default)]
+struct StatementSplitter {
+    // the quote character of the string or identifier that is still open
+    quote: Option<char>,
+    block_comment: bool,
+}
+
+impl StatementSplitter {
+    /// Scan the next `line` and return whether it ends a statement.
+    fn ends_statement(&mut self, line: &str) -> bool {
+        let mut ends = false;
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            if self.block_comment {
+                if c == '*' && chars.peek() == Some(&'/') {
+                    chars.next();
+                    self.block_comment = false;
+                }
+            } else if let Some(quote) = self.quote {
+                // an escaped quote is doubled, which closes and reopens the string
+                if c == quote {
+                    self.quote = None;
+                }
+            } else {
+                match c {
+                    '\'' | '"' => {
+                        self.quote = Some(c);
+                        ends = false;
+                    }
+                    '-' if chars.peek() == Some(&'-') => break,
+                    '/' if chars.peek() == Some(&'*') => {
+                        chars.next();
+                        self.block_comment = true;
+                    }
+                    ';' => ends = true,
+                    c if c.is_whitespace() => {}
+                    _ => ends = false,
+                }
+            }
+        }
+        ends && self.quote.is_none() && !self.block_comment
+    }
+}
+
+/// A client session: the SQL context and the way results are presented.
+struct Session {
+    context: SQLContext,
+    // (name, source) of the frames registered with \rd
+    dataframes: Vec<(String, String)>,
+    format: OutputFormat,
+    output: Option<PathBuf>,
+    timing: bool,
+}
+
+impl Session {
+    fn new(args: &Args) -> PolarsResult<Self> {
+        let format = match (args.format, &args.output) {
+            (Some(format), _) => format,
+            (None, Some(path)) => OutputFormat::from_path(path).ok_or_else(|| {
+                polars_err!(
+                    ComputeError: "unable to infer the output format of {:?}; use --format", path
+                )
+            })?,
+            (None, None) => OutputFormat::Table,
+        };
+        Ok(Self {
+            context: SQLContext::try_new()?,
+            dataframes: Vec::new(),
+            format,
+            output: args.output.clone(),
+            timing: args.timing,
+        })
+    }
+
+    // Command: /dd | dataframes
+    fn print_dataframes(&self) {
+        println!(
+            "{} dataframes registered{}",
+            self.dataframes.len(),
+            if self.dataframes.is_empty() { "." } else { ":" }
+        );
+        for (name, file) in self.dataframes.iter() {
+            println!("{}:\t {}", name, file);
+        }
+    }
+
+    // Command: /rd | register
+    fn register_dataframe(&mut self, command: Vec<&str>) {
+        if command.len() < 3 {
+            println!("Usage: \\rd <name> <file>");
+            return;
+        }
+        let name = command[1];
+        let source = command[2];
+        let df = create_dataframe_from_filename(source);
+
+        match df {
+            Ok(frame) => {
+                self.context.register(name, frame);
+                self.dataframes.push((name.to_owned(), source.to_owned()));
+                println!("Added dataframe \"{}\" from file {}", name, source)
+            }
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+
+    // Command: /timing | timing
+    fn toggle_timing(&mut self, command: Vec<&str>) {
+        self.timing = match command.get(1) {
+            Some(&"on") => true,
+            Some(&"off") => false,
+            _ => !self.timing,
+        };
+        eprintln!("Timing is {}.", if self.timing { "on" } else { "off" });
+    }
+
+    /// Run a client command, or the input as SQL if it is not one.
+    fn run_command(&mut self, input: &str) -> PolarsResult<ControlFlow<()>> {
+        let command: Vec<&str> = input.split_whitespace().collect();
+        match command[0] {
+            "\\dd" | "dataframes" => self.print_dataframes(),
+            "\\rd" | "register" => self.register_dataframe(command),
+            "\\?" | "help" | "?" | "\\h" => print_help(),
+            "\\dt" | "tables" => self.run_sql("SHOW TABLES")?,
+            "\\d" | "\\schema" | "describe" => match command.get(1) {
+                Some(name) => self.run_sql(&format!("DESCRIBE {name}"))?,
+                None => println!("Usage: \\schema <name>"),
+            },
+            "\\x" | "\\explain" | "explain" => {
+                let query = input[command[0].len()..].trim();
+                self.run_sql(&format!("EXPLAIN {query}"))?
+            }
+            "\\timing" | "timing" => self.toggle_timing(command),
+            "\\q" | "quit" | "exit" => {
+                println!("Bye");
+                return Ok(ControlFlow::Break(()));
+            }
+            _ => {
+                if command[0].starts_with('\\') {
+                    print!("Unknown command: {}\n\n", command[0]);
+                    print_help();
+                } else {
+                    self.run_sql(input)?
+                }
+            }
+        }
+        Ok(ControlFlow::Continue(()))
+    }
+
+    /// Run all statements in `sql` and write their results.
+    fn run_sql(&mut self, sql: &str) -> PolarsResult<()> {
+        let ast = Parser::parse_sql(&GenericDialect::default(), sql)
+            .map_err(|e| polars_err!(ComputeError: "error parsing SQL: {}", e))?;
+        for stmt in &ast {
+            let start = Instant::now();
+            let mut df = execute_statement(&mut self.context, stmt)?;
+            let elapsed = start.elapsed();
+            match &self.output {
+                Some(path) => {
+                    write_frame(&mut df, self.format, BufWriter::new(File::create(path)?))?
+                }
+                None => write_frame(&mut df, self.format, io::stdout().lock())?,
+            }
+            if self.timing {
+                eprintln!(
+                    "{} rows in set ({:.3} sec)",
+                    df.height(),
+                    elapsed.as_secs_f32()
+                )
+            }
+        }
+        Ok(())
+    }
+
+    /// Run a script of SQL statements that end with `;`, and client commands
+    /// on lines of their own between the statements. Stops at the first error.
+    fn run_script(&mut self, script: &str) -> PolarsResult<()> {
+        let mut splitter = StatementSplitter::default();
+        let mut sql = String::new();
+        for line in script.lines() {
+            let trimmed = line.trim();
+            if sql.trim().is_empty() {
+                if trimmed.starts_with("--") {
+                    continue;
+                }
+                if trimmed.starts_with('\\') {
+                    if self.run_command(trimmed)?.is_break() {
+                        return Ok(());
+                    }
+                    continue;
+                }
+            }
+            sql.push_str(line);
+            sql.push('\n');
+            if splitter.ends_statement(line) {
+                self.run_sql(&sql)?;
+                sql.clear();
+            }
+        }
+        self.run_sql(&sql)
     }
-    let name = command[1];
-    let source = command[2];
-    let df = create_dataframe_from_filename(source);
-
-    match df {
-        Ok(frame) => {
-            context.register(name, frame);
-            dataframes.push((name.to_owned(), source.to_owned()));
-            println!("Added dataframe \"{}\" from file {}", name, source)
+
+    fn run_tty(&mut self) -> std::io::Result<()> {
+        let mut rl = DefaultEditor::new().unwrap();
+
+        println!("Welcome to Polars CLI. Commands end with ; or \\n");
+        println!("Type help or \\? for help.");
+
+        loop {
+            let input = match rl.readline(">> ") {
+                Ok(line) => {
+                    rl.add_history_entry(&line);
+                    line.trim().to_owned()
+                }
+                Err(ReadlineError::Interrupted) => "exit".to_string(),
+                Err(e) => {
+                    eprintln!("Error: {:?}", e);
+                    "".to_string()
+                }
+            };
+            if input.is_empty() {
+                continue;
+            }
+
+            match self.run_command(&input) {
+                Ok(ControlFlow::Break(())) => return Ok(()),
+                Ok(ControlFlow::Continue(())) => {}
+                Err(e) => eprintln!("{}", e),
+            }
+
+            println!();
         }
-        Err(e) => eprintln!("{}", e),
     }
 }
 
@@ -68,6 +427,14 @@ fn print_help() {
     println!("List of all client commands:");
     for (name, short, desc) in vec![
         ("dataframes", "dd", "Show registered frames."),
+        ("tables", "dt", "List the tables of the SQL context."),
+        ("schema", "d", "Show the schema of a table: \\schema <name>"),
+        (
+            "explain",
+            "x",
+            "Show the optimized plan of a query: \\explain <query>",
+        ),
+        ("timing", "timing", "Toggle the report of execution times."),
         ("help", "?", "Display this help."),
         (
             "register",
@@ -108,149 +475,265 @@ fn create_dataframe_from_filename(filename: &str) -> PolarsResult<LazyFrame> {
 fn create_dataframe_from_tablename(
     context: &mut SQLContext,
     relation: &TableFactor,
+    ctes: &[String],
 ) -> PolarsResult<()> {
-    if let TableFactor::Table { name, alias, .. } = relation {
-        let source = name.0.get(0).unwrap().value.as_str();
-        let name = match alias {
-            Some(alias) => alias.name.value.to_string(),
-            None => source.to_string(),
-        };
-
-        // Return early if table was already registered.
-        if context.table_map.contains_key(&name) {
-            return Ok(());
+    match relation {
+        TableFactor::Table { name, .. } => create_dataframe_from_name(context, name, ctes)?,
+        TableFactor::Derived { subquery, .. } => {
+            create_dataframes_from_query(context, subquery, ctes)?
         }
+        _ => {}
+    };
 
-        let frame = create_dataframe_from_filename(source)?;
-        context.register(&name, frame);
+    Ok(())
+}
+
+fn create_dataframe_from_name(
+    context: &mut SQLContext,
+    name: &ObjectName,
+    ctes: &[String],
+) -> PolarsResult<()> {
+    // Qualified names are resolved by catalogs, aliases by the context.
+    let [source] = name.0.as_slice() else {
+        return Ok(());
     };
+    let source = source.value.as_str();
 
+    // Return early if table was already registered or refers to a CTE.
+    if context.table_map.contains_key(source) || ctes.iter().any(|cte| cte == source) {
+        return Ok(());
+    }
+
+    let frame = create_dataframe_from_filename(source)?;
+    context.register(source, frame);
     Ok(())
 }
 
-fn create_dataframes_from_statement(context: &mut SQLContext, stmt: &Select) -> PolarsResult<()> {
+fn create_dataframes_from_statement(
+    context: &mut SQLContext,
+    stmt: &Select,
+    ctes: &[String],
+) -> PolarsResult<()> {
     let sql_tbl: &TableWithJoins = stmt
         .from
         .get(0)
         .ok_or_else(|| polars_err!(ComputeError: "no table name provided in query"))?;
 
-    create_dataframe_from_tablename(context, &sql_tbl.relation)?;
+    create_dataframe_from_tablename(context, &sql_tbl.relation, ctes)?;
 
     if !sql_tbl.joins.is_empty() {
         for tbl in &sql_tbl.joins {
-            create_dataframe_from_tablename(context, &tbl.relation)?;
+            create_dataframe_from_tablename(context, &tbl.relation, ctes)?;
         }
     }
 
     Ok(())
 }
 
-fn execute_query(context: &mut SQLContext, query: &str) -> PolarsResult<DataFrame> {
-    let ast = Parser::parse_sql(&GenericDialect::default(), query)
-        .map_err(|e| polars_err!(ComputeError: "error parsing SQL: {}", e))?;
-    polars_ensure!(ast.len() == 1, ComputeError: "one and only one statement at a time please");
-    let ast = ast.get(0).unwrap();
-    if let Statement::Query(query) = ast {
-        if let SetExpr::Select(select_stmt) = &query.body.as_ref() {
-            create_dataframes_from_statement(context, select_stmt)?;
+fn create_dataframes_from_query(
+    context: &mut SQLContext,
+    query: &Query,
+    ctes: &[String],
+) -> PolarsResult<()> {
+    let mut ctes = ctes.to_vec();
+    if let Some(with) = &query.with {
+        for cte in &with.cte_tables {
+            create_dataframes_from_query(context, &cte.query, &ctes)?;
+            ctes.push(cte.alias.name.value.clone());
         }
-        // Statement is validated in context::execute_statement
-        // so we leave it to them to return an error type for unsupported expressions
+    }
+    if let SetExpr::Select(select_stmt) = &query.body.as_ref() {
+        create_dataframes_from_statement(context, select_stmt, &ctes)?;
+    }
+    Ok(())
+}
+
+fn execute_statement(context: &mut SQLContext, stmt: &Statement) -> PolarsResult<DataFrame> {
+    // Statement is validated in context::execute_statement
+    // so we leave it to them to return an error type for unsupported expressions
+    match stmt {
+        Statement::Query(query) => create_dataframes_from_query(context, query, &[])?,
+        Statement::Explain { statement, .. } => {
+            if let Statement::Query(query) = statement.as_ref() {
+                create_dataframes_from_query(context, query, &[])?
+            }
+        }
+        Statement::ExplainTable { table_name, .. } => {
+            create_dataframe_from_name(context, table_name, &[])?
+        }
+        _ => {}
     }
 
     // Execute SQL command
-    context.execute_statement(ast)?.collect()
+    context.execute_statement(stmt)?.collect()
 }
 
 fn get_extension_from_filename(filename: &str) -> Option<&str> {
     Path::new(filename).extension().and_then(OsStr::to_str)
 }
 
-pub fn run_tty() -> std::io::Result<()> {
-    let mut stdout = io::stdout();
-    let mut context = SQLContext::try_new().unwrap();
-    let mut dataframes = Vec::new();
-    let mut rl = DefaultEditor::new().unwrap();
+pub fn run() -> io::Result<()> {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            std::process::exit(2);
+        }
+    };
+    if args.help {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+    let mut session = match Session::new(&args) {
+        Ok(session) => session,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
 
-    println!("Welcome to Polars CLI. Commands end with ; or \\n");
-    println!("Type help or \\? for help.");
+    let script = if let Some(command) = args.command {
+        command
+    } else if let Some(file) = args.file {
+        std::fs::read_to_string(file)?
+    } else if atty::is(atty::Stream::Stdin) {
+        // the interactive session always reports timings, unless toggled off
+        session.timing = true;
+        return session.run_tty();
+    } else {
+        let mut input = String::new();
+        io::stdin().lock().read_to_string(&mut input)?;
+        input
+    };
 
-    loop {
-        let input = match rl.readline(">> ") {
-            Ok(line) => {
-                rl.add_history_entry(&line);
-                line.trim().to_owned()
-            }
-            Err(ReadlineError::Interrupted) => "exit".to_string(),
-            Err(e) => {
-                eprintln!("Error: {:?}", e);
-                "".to_string()
-            }
-        };
+    if let Err(e) = session.run_script(&script) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+    Ok(())
+}
 
-        let command: Vec<&str> = input.trim().split(' ').collect();
-        if command[0].is_empty() {
-            continue;
-        }
+#[cfg(test)]
+mod test {
+    use super::*;
 
-        // Otherwise, execute command
-        match command[0] {
-            "\\dd" | "dataframes" => print_dataframes(&dataframes),
-            "\\rd" | "register" => register_dataframe(&mut context, &mut dataframes, command),
-            "\\?" | "help" | "?" | "\\h" => print_help(),
-            "\\q" | "quit" | "exit" => {
-                println!("Bye");
-                return Ok(());
-            }
-            _ => {
-                if command[0].starts_with('\\') {
-                    print!("Unknown command: {}\n\n", command[0]);
-                    print_help();
-                    continue;
-                }
+    const FOODS: &str = "../../examples/datasets/foods1.csv";
 
-                let start = Instant::now();
-                match execute_query(&mut context, input.trim()) {
-                    Ok(lf) => {
-                        println!("{}", lf);
-                        println!(
-                            "{} rows in set ({:.3} sec)",
-                            lf.shape().0,
-                            start.elapsed().as_secs_f32()
-                        )
-                    }
-                    Err(e) => eprintln!("{}", e),
-                }
-            }
-        }
+    fn parse(args: &[&str]) -> PolarsResult<Args> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() -> PolarsResult<()> {
+        let args = parse(&["-c", "SELECT 1", "--format", "csv", "--timing"])?;
+        assert_eq!(args.command.as_deref(), Some("SELECT 1"));
+        assert_eq!(args.format, Some(OutputFormat::Csv));
+        assert!(args.timing && !args.help && args.file.is_none());
+
+        let args = parse(&["script.sql", "-o", "out.md"])?;
+        assert_eq!(args.file.as_deref(), Some("script.sql"));
+        assert_eq!(args.output, Some(PathBuf::from("out.md")));
+        assert_eq!(Session::new(&args)?.format, OutputFormat::Markdown);
+        assert!(parse(&["--help"])?.help);
 
-        println!();
+        for args in [
+            &["-c"][..],
+            &["--format", "xml"],
+            &["--unknown"],
+            &["a.sql", "b.sql"],
+            &["-c", "SELECT 1", "-f", "a.sql"],
+        ] {
+            assert!(parse(args).is_err(), "{args:?}");
+        }
+        // the output format cannot be inferred
+        assert!(Session::new(&parse(&["-o", "out.txt"])?).is_err());
+        Ok(())
     }
-}
 
-pub fn run() -> io::Result<()> {
-    if atty::is(atty::Stream::Stdin) {
-        return run_tty();
+    #[test]
+    fn test_write_frame() -> PolarsResult<()> {
+        let write = |format| -> PolarsResult<String> {
+            let mut df = df! { "a" => [1, 2], "b|c" => ["x", "y|z"] }?;
+            let mut buf = vec![];
+            write_frame(&mut df, format, &mut buf)?;
+            Ok(String::from_utf8(buf).unwrap())
+        };
+        assert_eq!(write(OutputFormat::Csv)?, "a,b|c\n1,x\n2,y|z\n");
+        assert_eq!(
+            write(OutputFormat::Markdown)?,
+            "| a | b\\|c |\n| --- | --- |\n| 1 | x |\n| 2 | y\\|z |\n"
+        );
+        #[cfg(feature = "json")]
+        assert_eq!(
+            write(OutputFormat::Ndjson)?,
+            "{\"a\":1,\"b|c\":\"x\"}\n{\"a\":2,\"b|c\":\"y|z\"}\n"
+        );
+        Ok(())
     }
 
-    let mut context = SQLContext::try_new().unwrap();
-    let mut input: Vec<u8> = Vec::with_capacity(1024);
-    let mut stdin = std::io::stdin();
+    #[test]
+    fn test_run_commands() -> PolarsResult<()> {
+        let tmp = tempdir::TempDir::new("polars_sql_cli")?;
+        let output = tmp.path().join("out.csv");
+        let mut session = Session::new(&parse(&["-o", output.to_str().unwrap()])?)?;
+        let read_output = || std::fs::read_to_string(&output).unwrap();
 
-    loop {
-        input.clear();
-        stdin.lock().read_until(b';', &mut input);
+        // \rd registers a frame under a name
+        session.run_command(&format!("\\rd foods {FOODS}"))?;
+        assert_eq!(
+            session.dataframes,
+            [("foods".to_string(), FOODS.to_string())]
+        );
+        session.run_command("\\rd missing")?;
+        session.run_command("\\rd missing does_not_exist.txt")?;
+        assert_eq!(session.dataframes.len(), 1);
 
-        let query = std::str::from_utf8(&input).unwrap_or("").trim();
-        if query.is_empty() {
-            break;
-        }
+        session.run_command("\\schema foods")?;
+        assert_eq!(
+            read_output(),
+            "column_name,data_type\ncategory,str\ncalories,i64\nfats_g,f64\nsugars_g,i64\n"
+        );
+        session.run_command("\\dt")?;
+        assert_eq!(read_output(), "name\nfoods\n");
+        // input that isn't a command is run as SQL
+        session.run_command("SELECT COUNT(*) AS n FROM foods")?;
+        assert_eq!(read_output(), "n\n27\n");
+        assert!(session.run_command("\\schema missing").is_err());
 
-        match execute_query(&mut context, query) {
-            Ok(lf) => println!("{}", lf),
-            Err(e) => eprintln!("{}", e),
-        }
+        assert!(!session.timing);
+        session.run_command("\\timing")?;
+        assert!(session.timing);
+        session.run_command("\\timing on")?;
+        assert!(session.timing);
+        session.run_command("\\timing off")?;
+        assert!(!session.timing);
+
+        assert!(session.run_command("\\unknown")?.is_continue());
+        assert!(session.run_command("\\q")?.is_break());
+
+        // a script stops at \q
+        session.run_command("\\dt")?;
+        session.run_script("SELECT COUNT(*) AS n FROM foods;\n\\q\nSELECT 1 AS n FROM foods;")?;
+        assert_eq!(read_output(), "n\n27\n");
+        Ok(())
     }
 
-    Ok(())
+    #[test]
+    fn test_statement_splitter() {
+        let ends = |lines: &[&str]| {
+            let mut splitter = StatementSplitter::default();
+            lines
+                .iter()
+                .map(|line| splitter.ends_statement(line))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ends(&["SELECT 1;", "SELECT 2", ";"]), [true, false, true]);
+        assert_eq!(ends(&["SELECT 'a;", "b;' AS s;"]), [false, true]);
+        assert_eq!(ends(&["SELECT 'it''s;' AS \"x;\";"]), [true]);
+        assert_eq!(
+            ends(&["SELECT 1; -- done;", "SELECT 2 -- not done;"]),
+            [true, false]
+        );
+        assert_eq!(ends(&["SELECT 1 /* a;", "b; */ ;"]), [false, true]);
+    }
 }
@@ -1,46 +1,78 @@
-use std::cell::RefCell;
 use std::collections::BTreeSet;
+use std::sync::Arc;
 
 use polars_arrow::error::to_compute_err;
 use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
 use polars_lazy::prelude::*;
 use polars_plan::prelude::*;
 use polars_plan::utils::expressions_to_schema;
 use sqlparser::ast::{
-    Expr as SqlExpr, FunctionArg, JoinOperator, ObjectName, OrderByExpr, Query, Select, SelectItem,
-    SetExpr, Statement, TableAlias, TableFactor, TableWithJoins, Value as SQLValue,
+    BinaryOperator, Distinct, Expr as SqlExpr, FunctionArg, Ident, JoinOperator, ObjectName,
+    ObjectType, OrderByExpr, Query, Select, SelectItem, SetExpr, SetOperator, SetQuantifier,
+    Statement, TableAlias, TableFactor, TableWithJoins, Value as SQLValue, With,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::Parser;
 
-use crate::sql_expr::{parse_sql_expr, process_join_constraint};
+use crate::catalog::TableCatalog;
+use crate::sql_expr::{join_key_pair, parse_sql_expr, process_join_constraint};
 use crate::table_functions::PolarsTableFunctions;
+use crate::table_scope::{TableScope, JOIN_SUFFIX};
 
-thread_local! {pub(crate) static TABLES: RefCell<Vec<String>> = RefCell::new(vec![])}
+/// Name of the helper column holding the evaluated `HAVING` predicate.
+const HAVING_COLUMN: &str = "__POLARS_SQL_HAVING";
+
+/// A subquery that still needs to be joined onto the query that refers to it.
+#[derive(Clone)]
+struct Subquery {
+    lf: LazyFrame,
+    // key that is left joined on the first column of `lf`, a subquery without
+    // key has a single row and is cross joined instead
+    left_on: Option<Expr>,
+    // helper columns that the subquery adds to the outer query
+    columns: Vec<String>,
+}
 
 #[derive(Default, Clone)]
 pub struct SQLContext {
     pub table_map: PlHashMap<String, LazyFrame>,
+    // catalogs that resolve schema-qualified table names, keyed by their first part
+    catalogs: PlHashMap<String, Arc<dyn TableCatalog>>,
+    // common table expressions visible to the query that is being executed
+    cte_map: PlHashMap<String, LazyFrame>,
+    // subqueries that still need to be joined onto the query that refers to them
+    subqueries: Vec<Subquery>,
+    // tables visible to the query that is being planned
+    pub(crate) scope: TableScope,
 }
 
 impl SQLContext {
     pub fn try_new() -> PolarsResult<Self> {
-        TABLES.with(|cell| {
-            polars_ensure!(
-                cell.borrow().is_empty(),
-                ComputeError: "only one sql-context per thread allowed",
-            );
-            Ok(())
-        })?;
-        Ok(Self {
-            table_map: PlHashMap::new(),
-        })
+        Ok(Self::default())
     }
 
     pub fn register(&mut self, name: &str, lf: LazyFrame) {
-        TABLES.with(|cell| cell.borrow_mut().push(name.to_owned()));
         self.table_map.insert(name.to_owned(), lf);
     }
+
+    /// Remove the table `name` from the context.
+    pub fn unregister(&mut self, name: &str) {
+        self.table_map.remove(name);
+    }
+
+    /// Get the names of the registered tables, in sorted order.
+    pub fn get_tables(&self) -> Vec<String> {
+        let mut tables = self.table_map.keys().cloned().collect::<Vec<_>>();
+        tables.sort_unstable();
+        tables
+    }
+
+    /// Register a catalog that resolves table names starting with `name`,
+    /// e.g. `name.schema.table`, see [`TableCatalog`].
+    pub fn register_catalog(&mut self, name: &str, catalog: Arc<dyn TableCatalog>) {
+        self.catalogs.insert(name.to_owned(), catalog);
+    }
 }
 
 impl SQLContext {
@@ -52,105 +84,401 @@ impl SQLContext {
 
     pub fn execute_statement(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         let ast = stmt;
-        Ok(match ast {
-            Statement::Query(query) => self.execute_query(query)?,
-            stmt @ Statement::CreateTable { .. } => self.execute_create_table(stmt)?,
-            _ => polars_bail!(
+        let out = match ast {
+            Statement::Query(query) => self.execute_query(query),
+            stmt @ Statement::CreateTable { .. } => self.execute_create_table(stmt),
+            stmt @ Statement::Drop { .. } => self.execute_drop_table(stmt),
+            stmt @ Statement::Explain { .. } => self.execute_explain(stmt),
+            Statement::ExplainTable { table_name, .. } => self.execute_describe(table_name),
+            stmt @ Statement::ShowTables { .. } => self.execute_show_tables(stmt),
+            _ => Err(polars_err!(
                 ComputeError: "SQL statement type {:?} is not supported", ast,
-            ),
-        })
+            )),
+        };
+        self.subqueries.clear();
+        out
     }
 
     pub fn execute_query(&mut self, query: &Query) -> PolarsResult<LazyFrame> {
-        let mut lf = match &query.body.as_ref() {
+        // every (sub)query resolves qualified columns against its own FROM clause,
+        // and the CTEs of its WITH clause are only visible within the query
+        let outer_scope = std::mem::take(&mut self.scope);
+        let outer_ctes = query.with.as_ref().map(|_| self.cte_map.clone());
+        let out = self.execute_query_in_scope(query);
+        self.scope = outer_scope;
+        if let Some(outer_ctes) = outer_ctes {
+            self.cte_map = outer_ctes;
+        }
+        out
+    }
+
+    fn execute_query_in_scope(&mut self, query: &Query) -> PolarsResult<LazyFrame> {
+        polars_ensure!(query.fetch.is_none(), ComputeError: "FETCH clause is not supported");
+        polars_ensure!(
+            query.locks.is_empty(),
+            ComputeError: "FOR UPDATE/SHARE clauses are not supported"
+        );
+        if let Some(with) = &query.with {
+            self.register_ctes(with)?;
+        }
+        // DISTINCT is applied after ORDER BY, so that DISTINCT ON keeps the
+        // first row of every group in the requested order
+        let mut lf = match &*query.body {
             SetExpr::Select(select_stmt) => self.execute_select(select_stmt)?,
-            _ => polars_bail!(ComputeError: "INSERT, UPDATE is not supported"),
+            body => self.execute_set_expr(body)?,
         };
         if !query.order_by.is_empty() {
             lf = self.process_order_by(lf, &query.order_by)?;
         }
-        match &query.limit {
-            Some(SqlExpr::Value(SQLValue::Number(nrow, _))) => {
-                let nrow = nrow
-                    .parse()
-                    .map_err(|e| polars_err!(ComputeError: "conversion error: {}", e))?;
-                Ok(lf.limit(nrow))
+        if let SetExpr::Select(select_stmt) = &*query.body {
+            lf = self.process_distinct(lf, &select_stmt.distinct)?;
+        }
+
+        let limit = match &query.limit {
+            Some(expr) => Some(parse_row_count(expr, "LIMIT")?),
+            None => None,
+        };
+        let offset = match &query.offset {
+            Some(offset) => Some(parse_row_count(&offset.value, "OFFSET")?),
+            None => None,
+        };
+        Ok(match (offset, limit) {
+            (None, None) => lf,
+            (None, Some(n)) => lf.limit(n),
+            (Some(m), n) => lf.slice(m as i64, n.unwrap_or(IdxSize::MAX)),
+        })
+    }
+
+    fn execute_set_expr(&mut self, expr: &SetExpr) -> PolarsResult<LazyFrame> {
+        match expr {
+            SetExpr::Select(select_stmt) => {
+                let lf = self.execute_select(select_stmt)?;
+                self.process_distinct(lf, &select_stmt.distinct)
             }
-            None => Ok(lf),
-            _ => polars_bail!(
-                ComputeError: "non-number arguments to LIMIT clause are not supported",
-            ),
+            SetExpr::Query(query) => self.execute_query(query),
+            SetExpr::SetOperation {
+                op,
+                set_quantifier,
+                left,
+                right,
+            } => self.execute_set_operation(op, set_quantifier, left, right),
+            _ => polars_bail!(ComputeError: "INSERT, UPDATE is not supported"),
         }
     }
 
-    /// execute the 'FROM' part of the query
-    fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
-        let (tbl_name, mut lf) = self.get_table(&tbl_expr.relation)?;
-        if !tbl_expr.joins.is_empty() {
-            for tbl in &tbl_expr.joins {
-                let (join_tbl_name, join_tbl) = self.get_table(&tbl.relation)?;
-                match &tbl.join_operator {
-                    JoinOperator::Inner(constraint) => {
-                        let (left_on, right_on) =
-                            process_join_constraint(constraint, &tbl_name, &join_tbl_name)?;
-                        lf = lf.inner_join(join_tbl, left_on, right_on)
-                    }
-                    JoinOperator::LeftOuter(constraint) => {
-                        let (left_on, right_on) =
-                            process_join_constraint(constraint, &tbl_name, &join_tbl_name)?;
-                        lf = lf.left_join(join_tbl, left_on, right_on)
-                    }
-                    JoinOperator::FullOuter(constraint) => {
-                        let (left_on, right_on) =
-                            process_join_constraint(constraint, &tbl_name, &join_tbl_name)?;
-                        lf = lf.outer_join(join_tbl, left_on, right_on)
-                    }
-                    JoinOperator::CrossJoin => lf = lf.cross_join(join_tbl),
-                    join_type => {
-                        polars_bail!(
-                            ComputeError:
-                            "join type '{:?}' not yet supported by polars-sql", join_type
-                        );
-                    }
+    /// execute a `UNION`, `INTERSECT` or `EXCEPT` of two queries
+    fn execute_set_operation(
+        &mut self,
+        op: &SetOperator,
+        quantifier: &SetQuantifier,
+        left: &SetExpr,
+        right: &SetExpr,
+    ) -> PolarsResult<LazyFrame> {
+        let left = self.execute_set_expr(left)?;
+        let right = self.execute_set_expr(right)?;
+        let (left, right) = coerce_set_operands(left, right)?;
+
+        let all = match quantifier {
+            SetQuantifier::All => true,
+            SetQuantifier::Distinct | SetQuantifier::None => false,
+            SetQuantifier::ByName | SetQuantifier::AllByName => {
+                polars_bail!(ComputeError: "{} BY NAME is not supported", op)
+            }
+        };
+        match op {
+            SetOperator::Union => {
+                let lf = concat([left, right], false, true)?;
+                if all {
+                    Ok(lf)
+                } else {
+                    Ok(lf.unique_stable(None, UniqueKeepStrategy::First))
+                }
+            }
+            SetOperator::Intersect | SetOperator::Except => {
+                polars_ensure!(
+                    !all,
+                    ComputeError: "{} ALL is not supported", op
+                );
+                let on = left
+                    .schema()?
+                    .iter_names()
+                    .map(|name| col(name))
+                    .collect::<Vec<_>>();
+                let how = if matches!(op, SetOperator::Intersect) {
+                    JoinType::Semi
+                } else {
+                    JoinType::Anti
+                };
+                Ok(left
+                    .join(right, &on, &on, how)
+                    .unique_stable(None, UniqueKeepStrategy::First))
+            }
+        }
+    }
+
+    /// register the common table expressions of a `WITH` clause
+    fn register_ctes(&mut self, with: &With) -> PolarsResult<()> {
+        polars_ensure!(
+            !with.recursive,
+            ComputeError: "recursive CTEs are not supported"
+        );
+        for cte in &with.cte_tables {
+            let lf = self.execute_query(&cte.query)?;
+            let lf = rename_aliased_columns(lf, &cte.alias.columns)?;
+            self.cte_map.insert(cte.alias.name.value.clone(), lf);
+        }
+        Ok(())
+    }
+
+    /// Register the result of a scalar subquery.
+    ///
+    /// The subquery is reduced to a single row with a unique column name,
+    /// which is returned so that the outer query can refer to it.
+    pub(crate) fn register_subquery(&mut self, lf: LazyFrame) -> PolarsResult<String> {
+        let schema = lf.schema()?;
+        polars_ensure!(
+            schema.len() == 1,
+            ComputeError: "scalar subquery must return a single column; got {}", schema.len()
+        );
+        let column = schema.iter_names().next().unwrap();
+        let name = format!("__POLARS_SQL_SUBQUERY_{}", self.subqueries.len());
+        let lf = lf.select([col(column).first().alias(&name)]);
+        self.subqueries.push(Subquery {
+            lf,
+            left_on: None,
+            columns: vec![name.clone()],
+        });
+        Ok(name)
+    }
+
+    /// Register the subquery of an `expr IN (SELECT ...)` predicate.
+    ///
+    /// The distinct values of the subquery are left joined onto the outer query.
+    /// Returns whether a match was found, and whether the predicate is unknown
+    /// if no match was found because of a NULL in `expr` or in the subquery.
+    pub(crate) fn register_in_subquery(
+        &mut self,
+        expr: Expr,
+        lf: LazyFrame,
+    ) -> PolarsResult<(Expr, Expr)> {
+        let schema = lf.schema()?;
+        polars_ensure!(
+            schema.len() == 1,
+            ComputeError: "IN subquery must return a single column; got {}", schema.len()
+        );
+        let column = col(schema.iter_names().next().unwrap());
+        let lf = lf.cache();
+
+        let key = format!("__POLARS_SQL_SUBQUERY_{}", self.subqueries.len());
+        let found = format!("{key}_FOUND");
+        let values = lf
+            .clone()
+            .select([column.clone().alias(&key)])
+            .drop_nulls(None)
+            .unique(None, UniqueKeepStrategy::Any)
+            .with_column(lit(true).alias(&found));
+        self.subqueries.push(Subquery {
+            lf: values,
+            left_on: Some(expr.clone()),
+            columns: vec![key, found.clone()],
+        });
+
+        let has_null =
+            self.register_subquery(lf.clone().select([column.clone().null_count().gt(lit(0))]))?;
+        let is_empty = self.register_subquery(lf.select([column.count().eq(lit(0))]))?;
+        let unknown = col(&has_null).or(expr.is_null().and(col(&is_empty).not()));
+        Ok((col(&found).is_not_null(), unknown))
+    }
+
+    /// Join the subqueries that were registered after `offset` onto `lf`.
+    ///
+    /// Returns the names of the added columns.
+    fn join_subqueries(&mut self, mut lf: LazyFrame, offset: usize) -> (LazyFrame, Vec<String>) {
+        let subqueries = self.subqueries.split_off(offset);
+        let mut names = Vec::with_capacity(subqueries.len());
+        for subquery in subqueries {
+            lf = match subquery.left_on {
+                Some(left_on) => {
+                    let key = &subquery.columns[0];
+                    lf.with_column(left_on.alias(key)).join(
+                        subquery.lf,
+                        [col(key)],
+                        [col(key)],
+                        JoinType::Left,
+                    )
+                }
+                None => lf.cross_join(subquery.lf),
+            };
+            names.extend(subquery.columns);
+        }
+        (lf, names)
+    }
+
+    /// execute the 'WHERE' part of the query
+    ///
+    /// Top level `IN (SELECT ...)` predicates are lowered to semi joins,
+    /// all other predicates are combined in a single filter.
+    fn process_where(
+        &mut self,
+        mut lf: LazyFrame,
+        conjunctions: &[&SqlExpr],
+    ) -> PolarsResult<LazyFrame> {
+        let offset = self.subqueries.len();
+        let mut predicates = vec![];
+
+        for expr in conjunctions {
+            match expr {
+                // `NOT IN` can't be an anti join, as it never holds if the
+                // subquery contains a NULL
+                SqlExpr::InSubquery {
+                    expr,
+                    subquery,
+                    negated: false,
+                } => {
+                    let left_on = parse_sql_expr(expr, self)?;
+                    let other = self.execute_query(subquery)?;
+                    let schema = other.schema()?;
+                    polars_ensure!(
+                        schema.len() == 1,
+                        ComputeError: "IN subquery must return a single column; got {}", schema.len()
+                    );
+                    // NULL never matches
+                    let other = other.drop_nulls(None);
+                    let right_on = col(schema.iter_names().next().unwrap());
+                    lf = lf.join(other, [left_on], [right_on], JoinType::Semi);
                 }
+                expr => predicates.push(parse_sql_expr(expr, self)?),
             }
+        }
+
+        let (lf, subqueries) = self.join_subqueries(lf, offset);
+        let lf = match predicates.into_iter().reduce(|acc, e| acc.and(e)) {
+            Some(predicate) => lf.filter(predicate),
+            None => lf,
         };
+        if subqueries.is_empty() {
+            Ok(lf)
+        } else {
+            Ok(lf.drop_columns(subqueries))
+        }
+    }
 
-        Ok(lf)
+    /// execute the 'FROM' part of the query, returns the joined frame and the
+    /// scope of the relations that were joined
+    fn execute_from_statement(
+        &mut self,
+        tbl_expr: &TableWithJoins,
+    ) -> PolarsResult<(LazyFrame, TableScope)> {
+        let (tbl_name, mut lf) = self.get_table(&tbl_expr.relation)?;
+        let schema = lf.schema()?;
+        let mut scope = TableScope::new(&tbl_name, &schema);
+        for tbl in &tbl_expr.joins {
+            let (join_tbl_name, join_tbl) = self.get_table(&tbl.relation)?;
+            let schema = join_tbl.schema()?;
+            let join_scope = TableScope::new(&join_tbl_name, &schema);
+            let (how, constraint) = match &tbl.join_operator {
+                JoinOperator::Inner(constraint) => (JoinType::Inner, Some(constraint)),
+                JoinOperator::LeftOuter(constraint) => (JoinType::Left, Some(constraint)),
+                JoinOperator::FullOuter(constraint) => (JoinType::Outer, Some(constraint)),
+                JoinOperator::CrossJoin => (JoinType::Cross, None),
+                join_type => {
+                    polars_bail!(
+                        ComputeError:
+                        "join type '{:?}' not yet supported by polars-sql", join_type
+                    );
+                }
+            };
+            let keys = match constraint {
+                Some(constraint) => process_join_constraint(constraint, &scope, &join_scope)?,
+                None => vec![],
+            };
+            lf = join_relation(lf, &mut scope, join_tbl, join_scope, &keys, how)?;
+        }
+        Ok((lf, scope))
     }
 
     /// execute the 'SELECT' part of the query
     fn execute_select(&mut self, select_stmt: &Select) -> PolarsResult<LazyFrame> {
-        // Determine involved dataframe
-        // Implicit join require some more work in query parsers, Explicit join are preferred for now.
-        let sql_tbl: &TableWithJoins = select_stmt
+        // Fail on clauses that would otherwise be silently ignored
+        let unsupported = [
+            ("TOP", select_stmt.top.is_some()),
+            ("SELECT INTO", select_stmt.into.is_some()),
+            ("LATERAL VIEW", !select_stmt.lateral_views.is_empty()),
+            ("CLUSTER BY", !select_stmt.cluster_by.is_empty()),
+            ("DISTRIBUTE BY", !select_stmt.distribute_by.is_empty()),
+            ("SORT BY", !select_stmt.sort_by.is_empty()),
+            ("WINDOW", !select_stmt.named_window.is_empty()),
+            ("QUALIFY", select_stmt.qualify.is_some()),
+        ];
+        if let Some((clause, _)) = unsupported.iter().find(|(_, present)| *present) {
+            polars_bail!(ComputeError: "{} clause is not supported", clause);
+        }
+        let mut conjunctions = vec![];
+        if let Some(expr) = &select_stmt.selection {
+            split_conjunctions(expr, &mut conjunctions);
+        }
+
+        // Determine involved dataframes
+        let (sql_tbl, implicit_joins) = select_stmt
             .from
-            .get(0)
+            .split_first()
             .ok_or_else(|| polars_err!(ComputeError: "no table name provided in query"))?;
-
-        let lf = self.execute_from_statement(sql_tbl)?;
+        let (mut lf, mut scope) = self.execute_from_statement(sql_tbl)?;
+        for sql_tbl in implicit_joins {
+            // comma-separated relations are inner joined on the equalities in
+            // WHERE that compare both sides, or cross joined if there are none
+            let (join_tbl, join_scope) = self.execute_from_statement(sql_tbl)?;
+            let mut keys = vec![];
+            let mut remaining = Vec::with_capacity(conjunctions.len());
+            for expr in conjunctions {
+                let key = match expr {
+                    SqlExpr::BinaryOp {
+                        left,
+                        op: BinaryOperator::Eq,
+                        right,
+                    } => join_key_pair(left, right, &scope, &join_scope)?,
+                    _ => None,
+                };
+                match key {
+                    Some(key) => keys.push(key),
+                    None => remaining.push(expr),
+                }
+            }
+            conjunctions = remaining;
+            let how = if keys.is_empty() {
+                JoinType::Cross
+            } else {
+                JoinType::Inner
+            };
+            lf = join_relation(lf, &mut scope, join_tbl, join_scope, &keys, how)?;
+        }
+        self.scope = scope;
         let mut contains_wildcard = false;
 
         // Filter Expression
-        let lf = match select_stmt.selection.as_ref() {
-            Some(expr) => {
-                let filter_expression = parse_sql_expr(expr)?;
-                lf.filter(filter_expression)
-            }
-            None => lf,
+        let lf = if conjunctions.is_empty() {
+            lf
+        } else {
+            self.process_where(lf, &conjunctions)?
         };
+        let offset = self.subqueries.len();
         // Column Projections
-        let projections: Vec<_> = select_stmt
+        let mut projections: Vec<_> = select_stmt
             .projection
             .iter()
             .map(|select_item| {
                 Ok(match select_item {
-                    SelectItem::UnnamedExpr(expr) => parse_sql_expr(expr)?,
+                    SelectItem::UnnamedExpr(expr) => parse_sql_expr(expr, self)?,
                     SelectItem::ExprWithAlias { expr, alias } => {
-                        let expr = parse_sql_expr(expr)?;
+                        let expr = parse_sql_expr(expr, self)?;
                         expr.alias(&alias.value)
                     }
-                    SelectItem::QualifiedWildcard { .. } | SelectItem::Wildcard { .. } => {
+                    SelectItem::QualifiedWildcard(name, _) => {
+                        contains_wildcard = true;
+                        let tbl_name = &name.0.last().unwrap().value;
+                        cols(self.scope.columns(tbl_name)?)
+                    }
+                    SelectItem::Wildcard { .. } => {
                         contains_wildcard = true;
                         col("*")
                     }
@@ -179,17 +507,75 @@ impl SQLContext {
                     ComputeError:
                     "groupby error: a positive number or an expression expected",
                 )),
-                _ => parse_sql_expr(e),
+                _ => parse_sql_expr(e, self),
             })
             .collect::<PolarsResult<_>>()?;
 
-        if groupby_keys.is_empty() {
-            Ok(lf.select(projections))
+        let having = match &select_stmt.having {
+            Some(expr) => {
+                let having = parse_sql_expr(expr, self)?;
+                let schema = lf.schema()?;
+                let having = resolve_projection_aliases(having, &projections, &schema);
+                Some(having.alias(HAVING_COLUMN))
+            }
+            None => None,
+        };
+
+        // Scalar subqueries in the projection
+        let (lf, subqueries) = self.join_subqueries(lf, offset);
+        if !subqueries.is_empty() {
+            for e in projections.iter_mut() {
+                if matches!(e, Expr::Wildcard) {
+                    *e = col("*").exclude(&subqueries)
+                }
+            }
+        }
+
+        if !groupby_keys.is_empty() {
+            self.process_groupby(lf, contains_wildcard, &groupby_keys, &projections, having)
+        } else if let Some(having) = having {
+            // without GROUP BY the whole table is a single group
+            projections.push(having);
+            Ok(lf
+                .select(projections)
+                .filter(col(HAVING_COLUMN))
+                .drop_columns([HAVING_COLUMN]))
         } else {
-            self.process_groupby(lf, contains_wildcard, &groupby_keys, &projections)
+            Ok(lf.select(projections))
         }
     }
 
+    fn process_distinct(
+        &mut self,
+        lf: LazyFrame,
+        distinct: &Option<Distinct>,
+    ) -> PolarsResult<LazyFrame> {
+        Ok(match distinct {
+            None => lf,
+            Some(Distinct::Distinct) => lf.unique_stable(None, UniqueKeepStrategy::First),
+            Some(Distinct::On(exprs)) => {
+                let schema = lf.schema()?;
+                let subset = exprs
+                    .iter()
+                    .map(|e| match parse_sql_expr(e, self)? {
+                        Expr::Column(name) => {
+                            polars_ensure!(
+                                schema.contains(&name),
+                                ComputeError:
+                                "DISTINCT ON column {} must appear in the SELECT list", name
+                            );
+                            Ok(name.to_string())
+                        }
+                        _ => polars_bail!(
+                            ComputeError: "DISTINCT ON only supports column names, got {}", e
+                        ),
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                lf.unique_stable(Some(subset), UniqueKeepStrategy::First)
+            }
+        })
+    }
+
     fn execute_create_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         if let Statement::CreateTable {
             if_not_exists,
@@ -221,6 +607,146 @@ impl SQLContext {
         }
     }
 
+    fn execute_drop_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Drop {
+            object_type,
+            if_exists,
+            names,
+            ..
+        } = stmt
+        {
+            polars_ensure!(
+                *object_type == ObjectType::Table,
+                ComputeError: "DROP {} is not supported", object_type
+            );
+            let tbl_names = names
+                .iter()
+                .map(|name| {
+                    polars_ensure!(
+                        name.0.len() == 1,
+                        ComputeError: "cannot drop table {} of a catalog", name
+                    );
+                    let tbl_name = name.0[0].value.as_str();
+                    polars_ensure!(
+                        *if_exists || self.table_map.contains_key(tbl_name),
+                        ComputeError: "relation {} was not found", tbl_name
+                    );
+                    Ok(tbl_name)
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            for tbl_name in tbl_names {
+                self.unregister(tbl_name);
+            }
+            let out = df! {
+                "Response" => ["Drop Table"]
+            }
+            .unwrap()
+            .lazy();
+            Ok(out)
+        } else {
+            unreachable!()
+        }
+    }
+
+    /// Execute `EXPLAIN <query>`, returning the optimized logical plan with one line per row.
+    fn execute_explain(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Explain {
+            analyze, statement, ..
+        } = stmt
+        {
+            polars_ensure!(!analyze, ComputeError: "EXPLAIN ANALYZE is not supported");
+            let lf = match statement.as_ref() {
+                Statement::Query(query) => self.execute_query(query)?,
+                stmt => {
+                    polars_bail!(ComputeError: "EXPLAIN is only supported for queries, got {}", stmt)
+                }
+            };
+            let plan = lf.describe_optimized_plan()?;
+            let lines = plan
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .collect::<Vec<_>>();
+            let out = df! {
+                "Logical Plan" => lines,
+            }?;
+            Ok(out.lazy())
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn execute_describe(&mut self, table_name: &ObjectName) -> PolarsResult<LazyFrame> {
+        let (_, lf) = self.get_named_table(table_name)?;
+        let schema = lf.schema()?;
+        let (names, dtypes): (Vec<_>, Vec<_>) = schema
+            .iter()
+            .map(|(name, dtype)| (name.to_string(), dtype.to_string()))
+            .unzip();
+        let out = df! {
+            "column_name" => names,
+            "data_type" => dtypes,
+        }?;
+        Ok(out.lazy())
+    }
+
+    fn execute_show_tables(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::ShowTables {
+            db_name, filter, ..
+        } = stmt
+        {
+            polars_ensure!(
+                db_name.is_none(),
+                ComputeError: "SHOW TABLES of a catalog is not supported"
+            );
+            polars_ensure!(filter.is_none(), ComputeError: "SHOW TABLES filters are not supported");
+            let out = df! {
+                "name" => self.get_tables(),
+            }?;
+            Ok(out.lazy())
+        } else {
+            unreachable!()
+        }
+    }
+
+    /// Get a table from the catalog registered under `catalog`.
+    fn get_catalog_table(&self, catalog: &str, path: &[Ident]) -> PolarsResult<LazyFrame> {
+        let name = || {
+            let path = path.iter().map(|i| i.value.as_str()).collect::<Vec<_>>();
+            format!("{}.{}", catalog, path.join("."))
+        };
+        let catalog = self
+            .catalogs
+            .get(catalog)
+            .ok_or_else(|| polars_err!(ComputeError: "relation {} was not found", name()))?;
+        let path = path.iter().map(|i| i.value.as_str()).collect::<Vec<_>>();
+        catalog
+            .get_table(&path)?
+            .ok_or_else(|| polars_err!(ComputeError: "relation {} was not found", name()))
+    }
+
+    /// Resolve a table name to the name it has in scope and its frame.
+    fn get_named_table<'a>(&self, name: &'a ObjectName) -> PolarsResult<(&'a str, LazyFrame)> {
+        // qualified names are resolved by the catalog named by their first part
+        match name.0.as_slice() {
+            [tbl_name] => {
+                let tbl_name = tbl_name.value.as_str();
+                let lf = if let Some(lf) = self.cte_map.get(tbl_name) {
+                    lf.clone()
+                } else if let Some(lf) = self.table_map.get(tbl_name) {
+                    lf.clone()
+                } else {
+                    polars_bail!(ComputeError: "relation {} was not found", tbl_name);
+                };
+                Ok((tbl_name, lf))
+            }
+            [catalog, path @ ..] => {
+                let lf = self.get_catalog_table(&catalog.value, path)?;
+                Ok((path.last().unwrap().value.as_str(), lf))
+            }
+            [] => unreachable!(),
+        }
+    }
+
     fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
@@ -229,17 +755,28 @@ impl SQLContext {
                 if let Some(args) = args {
                     return self.execute_tbl_function(name, alias, args);
                 }
-                let tbl_name = name.0.get(0).unwrap().value.as_str();
-
-                if self.table_map.contains_key(tbl_name) {
-                    let lf = self.table_map.get(tbl_name).cloned().ok_or_else(|| {
-                        polars_err!(
-                            ComputeError: "table '{}' was not registered in the SQLContext", name,
-                        )
-                    })?;
-                    Ok((tbl_name.to_string(), lf))
-                } else {
-                    polars_bail!(ComputeError: "relation {} was not found", tbl_name);
+                let (tbl_name, lf) = self.get_named_table(name)?;
+                match alias {
+                    Some(alias) => {
+                        let lf = rename_aliased_columns(lf, &alias.columns)?;
+                        Ok((alias.name.value.clone(), lf))
+                    }
+                    None => Ok((tbl_name.to_string(), lf)),
+                }
+            }
+            TableFactor::Derived {
+                lateral,
+                subquery,
+                alias,
+            } => {
+                polars_ensure!(!lateral, ComputeError: "LATERAL derived tables are not supported");
+                let lf = self.execute_query(subquery)?;
+                match alias {
+                    Some(alias) => {
+                        let lf = rename_aliased_columns(lf, &alias.columns)?;
+                        Ok((alias.name.value.clone(), lf))
+                    }
+                    None => Ok((String::new(), lf)),
                 }
             }
             // Support bare table, optional with alias for now
@@ -270,7 +807,7 @@ impl SQLContext {
         let mut descending = Vec::with_capacity(ob.len());
 
         for ob in ob {
-            by.push(parse_sql_expr(&ob.expr)?);
+            by.push(parse_sql_expr(&ob.expr, self)?);
             if let Some(false) = ob.asc {
                 descending.push(true)
             } else {
@@ -291,6 +828,7 @@ impl SQLContext {
         contains_wildcard: bool,
         groupby_keys: &[Expr],
         projections: &[Expr],
+        having: Option<Expr>,
     ) -> PolarsResult<LazyFrame> {
         // check groupby and projection due to difference between SQL and polars
         // Return error on wild card, shouldn't process this
@@ -323,7 +861,13 @@ impl SQLContext {
             }
         }
 
-        let aggregated = lf.groupby(groupby_keys).agg(&aggregation_projection);
+        // HAVING is evaluated as an extra aggregation that is filtered on
+        let has_having = having.is_some();
+        aggregation_projection.extend(having);
+        let mut aggregated = lf.groupby(groupby_keys).agg(&aggregation_projection);
+        if has_having {
+            aggregated = aggregated.filter(col(HAVING_COLUMN));
+        }
         let projection_schema =
             expressions_to_schema(projections, &schema_before, Context::Default)?;
         // a final projection to get the proper order
@@ -343,11 +887,148 @@ impl SQLContext {
     }
 }
 
-impl Drop for SQLContext {
-    fn drop(&mut self) {
-        // drop old tables
-        TABLES.with(|cell| {
-            cell.borrow_mut().clear();
-        });
+/// Replace the columns in `expr` that refer to an alias of the projection by the
+/// aliased expression, e.g. `total` in `SELECT SUM(b) AS total ... HAVING total > 1`.
+/// Columns of the input take precedence over aliases.
+fn resolve_projection_aliases(mut expr: Expr, projections: &[Expr], schema: &Schema) -> Expr {
+    let aliases = projections
+        .iter()
+        .filter_map(|e| match e {
+            Expr::Alias(e, name) => Some((name.as_ref(), e.as_ref())),
+            _ => None,
+        })
+        .collect::<PlHashMap<_, _>>();
+    expr.mutate().apply(|e| {
+        if let Expr::Column(name) = e {
+            if schema.get(name).is_none() {
+                if let Some(aliased) = aliases.get(name.as_ref()) {
+                    *e = (*aliased).clone();
+                }
+            }
+        }
+        true
+    });
+    expr
+}
+
+/// Join `right` onto `left` on the given `(left, right)` key columns and add
+/// the relations of `right_scope` to `scope`.
+fn join_relation(
+    left: LazyFrame,
+    scope: &mut TableScope,
+    right: LazyFrame,
+    mut right_scope: TableScope,
+    keys: &[(String, String)],
+    how: JoinType,
+) -> PolarsResult<LazyFrame> {
+    let left_schema = left.schema()?;
+    if let JoinType::Cross = how {
+        scope.join(right_scope, &left_schema, keys);
+        return Ok(left.cross_join(right));
+    }
+    polars_ensure!(!keys.is_empty(), ComputeError: "join requires an ON or USING constraint");
+    let left_on = keys.iter().map(|(l, _)| col(l)).collect::<Vec<_>>();
+    let right_on = keys.iter().map(|(_, r)| col(r)).collect::<Vec<_>>();
+    let (right, joined_keys) = if let JoinType::Inner = how {
+        (right, keys)
+    } else {
+        // the join drops the right-hand keys, which are only equal to the left-hand keys
+        // for matched rows, so they are copied to columns that are null for the other rows
+        let right_schema = right.schema()?;
+        let copies = keys
+            .iter()
+            .map(|(_, r)| {
+                let mut name = format!("{r}{JOIN_SUFFIX}");
+                while right_schema.contains(&name) {
+                    name.push_str(JOIN_SUFFIX);
+                }
+                right_scope.rename(r, &name);
+                col(r).alias(&name)
+            })
+            .collect::<Vec<_>>();
+        (right.with_columns(copies), &[][..])
+    };
+    scope.join(right_scope, &left_schema, joined_keys);
+    Ok(left.join(right, left_on, right_on, how))
+}
+
+/// Parse the row count of a `LIMIT` or `OFFSET` clause.
+fn parse_row_count(expr: &SqlExpr, clause: &str) -> PolarsResult<IdxSize> {
+    match expr {
+        SqlExpr::Value(SQLValue::Number(n, _)) => n
+            .parse()
+            .map_err(|e| polars_err!(ComputeError: "conversion error: {}", e)),
+        _ => polars_bail!(
+            ComputeError: "non-number arguments to {} clause are not supported", clause
+        ),
+    }
+}
+
+/// Rename the columns of a CTE or derived table,
+/// e.g. `WITH tbl (a, b) AS (...)`.
+fn rename_aliased_columns(lf: LazyFrame, columns: &[Ident]) -> PolarsResult<LazyFrame> {
+    if columns.is_empty() {
+        return Ok(lf);
+    }
+    let schema = lf.schema()?;
+    polars_ensure!(
+        schema.len() == columns.len(),
+        ComputeError: "{} column aliases given for a relation with {} columns",
+        columns.len(), schema.len()
+    );
+    let existing = schema.iter_names().cloned().collect::<Vec<_>>();
+    Ok(lf.rename(existing, columns.iter().map(|c| c.value.as_str())))
+}
+
+/// Make the columns of both sides of a set operation line up by position:
+/// the right side takes the column names of the left side and both sides are
+/// cast to their common supertypes.
+fn coerce_set_operands(left: LazyFrame, right: LazyFrame) -> PolarsResult<(LazyFrame, LazyFrame)> {
+    let left_schema = left.schema()?;
+    let right_schema = right.schema()?;
+    polars_ensure!(
+        left_schema.len() == right_schema.len(),
+        ComputeError: "set operation requires both sides to have the same number of columns; got {} and {}",
+        left_schema.len(), right_schema.len()
+    );
+
+    let mut left_projection = Vec::with_capacity(left_schema.len());
+    let mut right_projection = Vec::with_capacity(right_schema.len());
+    for ((left_name, left_dtype), (right_name, right_dtype)) in
+        left_schema.iter().zip(right_schema.iter())
+    {
+        let dtype = try_get_supertype(left_dtype, right_dtype).map_err(|_| {
+            polars_err!(
+                ComputeError: "column '{}' of type {} can't be combined with column '{}' of type {}",
+                left_name, left_dtype, right_name, right_dtype
+            )
+        })?;
+        let mut left_expr = col(left_name);
+        if left_dtype != &dtype {
+            left_expr = left_expr.cast(dtype.clone())
+        }
+        let mut right_expr = col(right_name);
+        if right_dtype != &dtype {
+            right_expr = right_expr.cast(dtype)
+        }
+        left_projection.push(left_expr);
+        right_projection.push(right_expr.alias(left_name));
+    }
+    Ok((left.select(left_projection), right.select(right_projection)))
+}
+
+/// Split a predicate on its top level `AND`s.
+pub(crate) fn split_conjunctions<'a>(expr: &'a SqlExpr, out: &mut Vec<&'a SqlExpr>) {
+    match expr {
+        SqlExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            split_conjunctions(left, out);
+            split_conjunctions(right, out);
+        }
+        SqlExpr::Nested(expr) => split_conjunctions(expr, out),
+        expr => out.push(expr),
     }
 }
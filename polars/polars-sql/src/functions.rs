@@ -1,13 +1,32 @@
-use polars_core::prelude::{polars_bail, polars_err, PolarsError, PolarsResult};
-use polars_lazy::dsl::{lit, Expr};
+use polars_core::export::chrono::Local;
+use polars_core::prelude::{
+    polars_bail, polars_ensure, polars_err, DataType, DatetimeChunked, IdxSize, IntoSeries,
+    PolarsError, PolarsResult, RankMethod, RankOptions, SortOptions, TimeUnit, IDX_DTYPE,
+};
+use polars_lazy::dsl::{arg_sort_by, coalesce, concat_str, lit, when, Expr, GetOutput};
+use polars_plan::logical_plan::{LiteralValue, NULL};
 use sqlparser::ast::{
-    Expr as SqlExpr, Function as SQLFunction, FunctionArg, FunctionArgExpr, Value as SqlValue,
-    WindowSpec,
+    DateTimeField, Expr as SqlExpr, Function as SQLFunction, FunctionArg, FunctionArgExpr,
+    Value as SqlValue, WindowFrameBound, WindowFrameUnits, WindowSpec, WindowType,
 };
 
 use crate::sql_expr::parse_sql_expr;
+use crate::SQLContext;
+
+/// The rows of its partition that a window aggregate is computed over for every row.
+#[derive(Clone, Copy)]
+enum WindowFrame {
+    /// The whole partition.
+    Partition,
+    /// The rows of the ordered partition up to and including the current row,
+    /// and its peers (rows with the same ORDER BY values) if `peers` is set.
+    Running { peers: bool },
+}
 
-pub(crate) struct SqlFunctionVisitor<'a>(pub(crate) &'a SQLFunction);
+pub(crate) struct SqlFunctionVisitor<'a> {
+    pub(crate) func: &'a SQLFunction,
+    pub(crate) ctx: &'a mut SQLContext,
+}
 
 /// SQL functions that are supported by Polars
 pub(crate) enum PolarsSqlFunctions {
@@ -74,6 +93,11 @@ pub(crate) enum PolarsSqlFunctions {
     /// SELECT POW(column_1, 2) from df;
     /// ```
     Pow,
+    /// SQL 'round' function
+    /// ```sql
+    /// SELECT ROUND(column_1, 2) from df;
+    /// ```
+    Round,
     // ----
     // String functions
     // ----
@@ -109,6 +133,68 @@ pub(crate) enum PolarsSqlFunctions {
     /// SELECT column_2 from df WHERE ENDS_WITH(column_1, 'a');
     /// ```
     EndsWith,
+    /// SQL 'length' function (in characters)
+    /// ```sql
+    /// SELECT LENGTH(column_1) from df;
+    /// ```
+    Length,
+    /// SQL 'octet_length' function (in bytes)
+    /// ```sql
+    /// SELECT OCTET_LENGTH(column_1) from df;
+    /// ```
+    OctetLength,
+    /// SQL 'substr' function, positions start at 1
+    /// ```sql
+    /// SELECT SUBSTR(column_1, 2, 3) from df;
+    /// ```
+    Substring,
+    /// SQL 'replace' function
+    /// ```sql
+    /// SELECT REPLACE(column_1, 'old', 'new') from df;
+    /// ```
+    Replace,
+    /// SQL 'concat' function
+    /// ```sql
+    /// SELECT CONCAT(column_1, '-', column_2) from df;
+    /// ```
+    Concat,
+    // ----
+    // Conditional functions
+    // ----
+    /// SQL 'coalesce' function
+    /// ```sql
+    /// SELECT COALESCE(column_1, column_2, 0) from df;
+    /// ```
+    Coalesce,
+    /// SQL 'nullif' function
+    /// ```sql
+    /// SELECT NULLIF(column_1, 0) from df;
+    /// ```
+    NullIf,
+    // ----
+    // Date functions
+    // ----
+    /// SQL 'date_trunc' function
+    /// ```sql
+    /// SELECT DATE_TRUNC('month', column_1) from df;
+    /// ```
+    DateTrunc,
+    /// SQL 'strftime' function
+    /// ```sql
+    /// SELECT STRFTIME(column_1, '%Y-%m-%d') from df;
+    /// ```
+    Strftime,
+    /// SQL 'now' function, the local time at which the query is planned
+    /// ```sql
+    /// SELECT NOW() from df;
+    /// SELECT CURRENT_TIMESTAMP from df;
+    /// ```
+    Now,
+    /// SQL 'current_date' function
+    /// ```sql
+    /// SELECT CURRENT_DATE from df;
+    /// ```
+    CurrentDate,
     // ----
     // Aggregate functions
     // ----
@@ -161,6 +247,51 @@ pub(crate) enum PolarsSqlFunctions {
     /// ```
     Last,
     // ----
+    // Window functions
+    // ----
+    /// SQL 'row_number' function
+    /// Number of the current row within its partition, counting from 1.
+    /// ```sql
+    /// SELECT ROW_NUMBER() OVER (PARTITION BY column_1 ORDER BY column_2) from df;
+    /// ```
+    RowNumber,
+    /// SQL 'rank' function
+    /// Rank of the current row within its partition, with gaps.
+    /// ```sql
+    /// SELECT RANK() OVER (PARTITION BY column_1 ORDER BY column_2) from df;
+    /// ```
+    Rank,
+    /// SQL 'dense_rank' function
+    /// Rank of the current row within its partition, without gaps.
+    /// ```sql
+    /// SELECT DENSE_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) from df;
+    /// ```
+    DenseRank,
+    /// SQL 'lag' function
+    /// Value of the row `offset` rows before the current row (default 1).
+    /// ```sql
+    /// SELECT LAG(column_1, 1, 0) OVER (ORDER BY column_2) from df;
+    /// ```
+    Lag,
+    /// SQL 'lead' function
+    /// Value of the row `offset` rows after the current row (default 1).
+    /// ```sql
+    /// SELECT LEAD(column_1, 1, 0) OVER (ORDER BY column_2) from df;
+    /// ```
+    Lead,
+    /// SQL 'first_value' function
+    /// First value of the (ordered) partition.
+    /// ```sql
+    /// SELECT FIRST_VALUE(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) from df;
+    /// ```
+    FirstValue,
+    /// SQL 'last_value' function
+    /// Last value of the (ordered) partition.
+    /// ```sql
+    /// SELECT LAST_VALUE(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) from df;
+    /// ```
+    LastValue,
+    // ----
     // Array functions
     // ----
     /// SQL 'array_length' function
@@ -244,6 +375,7 @@ impl TryFrom<&'_ SQLFunction> for PolarsSqlFunctions {
             "log10" => Self::Log10,
             "log" => Self::Log,
             "pow" => Self::Pow,
+            "round" => Self::Round,
             // ----
             // String functions
             // ----
@@ -253,6 +385,23 @@ impl TryFrom<&'_ SQLFunction> for PolarsSqlFunctions {
             "rtrim" => Self::RTrim,
             "starts_with" => Self::StartsWith,
             "ends_with" => Self::EndsWith,
+            "length" | "char_length" | "character_length" => Self::Length,
+            "octet_length" => Self::OctetLength,
+            "substr" => Self::Substring,
+            "replace" => Self::Replace,
+            "concat" => Self::Concat,
+            // ----
+            // Conditional functions
+            // ----
+            "coalesce" => Self::Coalesce,
+            "nullif" => Self::NullIf,
+            // ----
+            // Date functions
+            // ----
+            "date_trunc" => Self::DateTrunc,
+            "strftime" => Self::Strftime,
+            "now" | "current_timestamp" => Self::Now,
+            "current_date" => Self::CurrentDate,
             // ----
             // Aggregate functions
             // ----
@@ -266,6 +415,16 @@ impl TryFrom<&'_ SQLFunction> for PolarsSqlFunctions {
             "first" => Self::First,
             "last" => Self::Last,
             // ----
+            // Window functions
+            // ----
+            "row_number" => Self::RowNumber,
+            "rank" => Self::Rank,
+            "dense_rank" => Self::DenseRank,
+            "lag" => Self::Lag,
+            "lead" => Self::Lead,
+            "first_value" => Self::FirstValue,
+            "last_value" => Self::LastValue,
+            // ----
             // Array functions
             // ----
             "array_length" => Self::ArrayLength,
@@ -283,9 +442,9 @@ impl TryFrom<&'_ SQLFunction> for PolarsSqlFunctions {
     }
 }
 
-impl SqlFunctionVisitor<'_> {
-    pub(crate) fn visit_function(&self) -> PolarsResult<Expr> {
-        let function = self.0;
+impl<'a> SqlFunctionVisitor<'a> {
+    pub(crate) fn visit_function(&mut self) -> PolarsResult<Expr> {
+        let function = self.func;
 
         let function_name: PolarsSqlFunctions = function.try_into()?;
         use PolarsSqlFunctions::*;
@@ -305,6 +464,17 @@ impl SqlFunctionVisitor<'_> {
             Log10 => self.visit_unary(|e| e.log(10.0)),
             Log => self.visit_binary(Expr::log),
             Pow => self.visit_binary::<Expr>(Expr::pow),
+            Round => match function.args.len() {
+                1 => self.visit_unary(|e| e.round(0)),
+                2 => self.try_visit_binary(|e, decimals: i64| {
+                    polars_ensure!(
+                        decimals >= 0,
+                        InvalidOperation: "ROUND does not support negative decimals: {}", decimals
+                    );
+                    Ok(e.round(decimals as u32))
+                }),
+                _ => not_supported_error("round", &extract_args(function)),
+            },
             // ----
             // String functions
             // ----
@@ -313,33 +483,73 @@ impl SqlFunctionVisitor<'_> {
             LTrim => match function.args.len() {
                 1 => self.visit_unary(|e| e.str().lstrip(None)),
                 2 => self.visit_binary(|e, s| e.str().lstrip(Some(s))),
-                _ => panic!(
-                    "Invalid number of arguments for LTrim: {}",
-                    function.args.len()
-                ),
+                _ => not_supported_error("ltrim", &extract_args(function)),
             },
             RTrim => match function.args.len() {
                 1 => self.visit_unary(|e| e.str().rstrip(None)),
                 2 => self.visit_binary(|e, s| e.str().rstrip(Some(s))),
-                _ => panic!(
-                    "Invalid number of arguments for RTrim: {}",
-                    function.args.len()
-                ),
+                _ => not_supported_error("rtrim", &extract_args(function)),
             },
             StartsWith => self.visit_binary(|e, s| e.str().starts_with(s)),
             EndsWith => self.visit_binary(|e, s| e.str().ends_with(s)),
+            Length => self.visit_unary(|e| e.str().n_chars()),
+            OctetLength => self.visit_unary(|e| e.str().lengths()),
+            Substring => match function.args.len() {
+                2 => self.visit_binary(|e, start: i64| substring(e, start, None)),
+                3 => self
+                    .visit_ternary(|e, start: i64, length: i64| substring(e, start, Some(length))),
+                _ => not_supported_error("substr", &extract_args(function)),
+            },
+            Replace => {
+                self.visit_ternary(|e, old: Expr, new: Expr| e.str().replace_all(old, new, true))
+            }
+            // unlike `||`, CONCAT skips NULL arguments
+            Concat => self.visit_variadic(|exprs| {
+                let exprs = exprs
+                    .iter()
+                    .map(|e| e.clone().cast(DataType::Utf8).fill_null(lit("")))
+                    .collect::<Vec<_>>();
+                concat_str(exprs, "")
+            }),
+            // ----
+            // Conditional functions
+            // ----
+            Coalesce => self.visit_variadic(coalesce),
+            NullIf => self.visit_binary(|l, r: Expr| {
+                when(l.clone().eq(r))
+                    .then(Expr::Literal(LiteralValue::Null))
+                    .otherwise(l)
+            }),
+            // ----
+            // Date functions
+            // ----
+            DateTrunc => self.visit_date_trunc(),
+            Strftime => self.visit_binary(|e, fmt: String| e.dt().strftime(&fmt)),
+            Now => self.visit_nullary(now),
+            CurrentDate => self.visit_nullary(|| now().cast(DataType::Date)),
             // ----
             // Aggregate functions
             // ----
             Count => self.visit_count(),
-            Sum => self.visit_unary(Expr::sum),
-            Min => self.visit_unary(Expr::min),
-            Max => self.visit_unary(Expr::max),
-            Avg => self.visit_unary(Expr::mean),
-            StdDev => self.visit_unary(|e| e.std(1)),
-            Variance => self.visit_unary(|e| e.var(1)),
+            Sum => self.visit_aggregate(Expr::sum, Some(|e| e.cumsum(false).forward_fill(None))),
+            Min => self.visit_aggregate(Expr::min, Some(|e| e.cummin(false).forward_fill(None))),
+            Max => self.visit_aggregate(Expr::max, Some(|e| e.cummax(false).forward_fill(None))),
+            Avg => self.visit_aggregate(Expr::mean, Some(running_mean)),
+            StdDev => self.visit_aggregate(|e| e.std(1), None),
+            Variance => self.visit_aggregate(|e| e.var(1), None),
+            // the frame always starts at the first row of the partition
             First => self.visit_unary(Expr::first),
-            Last => self.visit_unary(Expr::last),
+            Last => self.visit_aggregate(Expr::last, Some(|e| e)),
+            // ----
+            // Window functions
+            // ----
+            RowNumber => self.visit_row_number(),
+            Rank => self.visit_rank(RankMethod::Min),
+            DenseRank => self.visit_rank(RankMethod::Dense),
+            Lag => self.visit_shift(1),
+            Lead => self.visit_shift(-1),
+            FirstValue => self.visit_unary(Expr::first),
+            LastValue => self.visit_aggregate(Expr::last, Some(|e| e)),
             // ----
             // Array functions
             // ----
@@ -356,83 +566,477 @@ impl SqlFunctionVisitor<'_> {
         }
     }
 
-    fn visit_unary(&self, f: impl Fn(Expr) -> Expr) -> PolarsResult<Expr> {
-        let function = self.0;
+    fn visit_unary(&mut self, f: impl Fn(Expr) -> Expr) -> PolarsResult<Expr> {
+        let function = self.func;
         let args = extract_args(function);
-        if let FunctionArgExpr::Expr(sql_expr) = args[0] {
+        if let [FunctionArgExpr::Expr(sql_expr)] = args.as_slice() {
             // parse the inner sql expr -- e.g. SUM(a) -> a
-            let expr = parse_sql_expr(sql_expr)?;
+            let expr = parse_sql_expr(sql_expr, self.ctx)?;
+            // sort the inner expr by the window's ORDER BY if present
+            let expr = self.apply_window_order(expr)?;
             // apply the function on the inner expr -- e.g. SUM(a) -> SUM
             let expr = f(expr);
-            // apply the window spec if present
-            apply_window_spec(expr, &function.over)
+            // apply the window's PARTITION BY if present
+            self.apply_window_partition(expr)
         } else {
             not_supported_error(function.name.0[0].value.as_str(), &args)
         }
     }
 
-    fn visit_binary<Arg: FromSqlExpr>(&self, f: impl Fn(Expr, Arg) -> Expr) -> PolarsResult<Expr> {
-        let function = self.0;
+    /// Visit an aggregate function. Over a window with a running frame the aggregate
+    /// is replaced by `running`, which computes it for every row of the ordered
+    /// partition; aggregates without such a counterpart are rejected there.
+    fn visit_aggregate(
+        &mut self,
+        f: impl Fn(Expr) -> Expr,
+        running: Option<fn(Expr) -> Expr>,
+    ) -> PolarsResult<Expr> {
+        let function = self.func;
         let args = extract_args(function);
-        if let FunctionArgExpr::Expr(sql_expr) = args[0] {
-            let expr = apply_window_spec(parse_sql_expr(sql_expr)?, &function.over)?;
-            if let FunctionArgExpr::Expr(sql_expr) = args[1] {
-                let expr2 = Arg::from_sql_expr(sql_expr)?;
-                Ok(f(expr, expr2))
-            } else {
-                not_supported_error(function.name.0[0].value.as_str(), &args)
-            }
+        if let [FunctionArgExpr::Expr(sql_expr)] = args.as_slice() {
+            let expr = parse_sql_expr(sql_expr, self.ctx)?;
+            self.apply_window_aggregate(expr, f, running)
         } else {
             not_supported_error(function.name.0[0].value.as_str(), &args)
         }
     }
 
-    fn visit_count(&self) -> PolarsResult<Expr> {
-        let args = extract_args(self.0);
-        Ok(match (args.len(), self.0.distinct) {
+    fn visit_binary<Arg: FromSqlExpr>(
+        &mut self,
+        f: impl Fn(Expr, Arg) -> Expr,
+    ) -> PolarsResult<Expr> {
+        self.try_visit_binary(|e, arg| Ok(f(e, arg)))
+    }
+
+    fn try_visit_binary<Arg: FromSqlExpr>(
+        &mut self,
+        f: impl Fn(Expr, Arg) -> PolarsResult<Expr>,
+    ) -> PolarsResult<Expr> {
+        let function = self.func;
+        let args = extract_args(function);
+        match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr1), FunctionArgExpr::Expr(sql_expr2)] => {
+                let expr1 = parse_sql_expr(sql_expr1, self.ctx)?;
+                let expr2 = Arg::from_sql_expr(sql_expr2, self.ctx)?;
+                self.apply_window_partition(f(expr1, expr2)?)
+            }
+            _ => not_supported_error(function.name.0[0].value.as_str(), &args),
+        }
+    }
+
+    fn visit_nullary(&mut self, f: impl Fn() -> Expr) -> PolarsResult<Expr> {
+        let function = self.func;
+        let args = extract_args(function);
+        if !args.is_empty() {
+            return not_supported_error(function.name.0[0].value.as_str(), &args);
+        }
+        self.apply_window_partition(f())
+    }
+
+    fn visit_ternary<Arg1: FromSqlExpr, Arg2: FromSqlExpr>(
+        &mut self,
+        f: impl Fn(Expr, Arg1, Arg2) -> Expr,
+    ) -> PolarsResult<Expr> {
+        let function = self.func;
+        let args = extract_args(function);
+        match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr1), FunctionArgExpr::Expr(sql_expr2), FunctionArgExpr::Expr(sql_expr3)] =>
+            {
+                let expr1 = parse_sql_expr(sql_expr1, self.ctx)?;
+                let expr2 = Arg1::from_sql_expr(sql_expr2, self.ctx)?;
+                let expr3 = Arg2::from_sql_expr(sql_expr3, self.ctx)?;
+                self.apply_window_partition(f(expr1, expr2, expr3))
+            }
+            _ => not_supported_error(function.name.0[0].value.as_str(), &args),
+        }
+    }
+
+    fn visit_variadic(&mut self, f: impl Fn(&[Expr]) -> Expr) -> PolarsResult<Expr> {
+        let function = self.func;
+        let args = extract_args(function);
+        let mut exprs = Vec::with_capacity(args.len());
+        for arg in &args {
+            match arg {
+                FunctionArgExpr::Expr(sql_expr) => exprs.push(parse_sql_expr(sql_expr, self.ctx)?),
+                _ => return not_supported_error(function.name.0[0].value.as_str(), &args),
+            }
+        }
+        if exprs.is_empty() {
+            return not_supported_error(function.name.0[0].value.as_str(), &args);
+        }
+        self.apply_window_partition(f(&exprs))
+    }
+
+    /// DATE_TRUNC('unit', expr)
+    fn visit_date_trunc(&mut self) -> PolarsResult<Expr> {
+        let function = self.func;
+        let args = extract_args(function);
+        match args.as_slice() {
+            [FunctionArgExpr::Expr(unit), FunctionArgExpr::Expr(sql_expr)] => {
+                let unit = String::from_sql_expr(unit, self.ctx)?;
+                let every = match unit.to_lowercase().as_str() {
+                    "microsecond" | "microseconds" => "1us",
+                    "millisecond" | "milliseconds" => "1ms",
+                    "second" => "1s",
+                    "minute" => "1m",
+                    "hour" => "1h",
+                    "day" => "1d",
+                    "week" => "1w",
+                    "month" => "1mo",
+                    "quarter" => "3mo",
+                    "year" => "1y",
+                    other => polars_bail!(
+                        InvalidOperation: "DATE_TRUNC unit '{}' is not supported", other
+                    ),
+                };
+                let expr = parse_sql_expr(sql_expr, self.ctx)?;
+                self.apply_window_partition(expr.dt().truncate(every, "0ns"))
+            }
+            _ => not_supported_error(function.name.0[0].value.as_str(), &args),
+        }
+    }
+
+    fn visit_count(&mut self) -> PolarsResult<Expr> {
+        let function = self.func;
+        let args = extract_args(function);
+        match (args.len(), function.distinct) {
             // count()
-            (0, false) => lit(1i32).count(),
+            (0, false) => self.visit_count_rows(),
             // count(distinct)
-            (0, true) => return not_supported_error("count", &args),
+            (0, true) => not_supported_error("count", &args),
             (1, false) => match args[0] {
                 // count(col)
                 FunctionArgExpr::Expr(sql_expr) => {
-                    let expr = apply_window_spec(parse_sql_expr(sql_expr)?, &self.0.over)?;
-                    expr.count()
+                    let expr = parse_sql_expr(sql_expr, self.ctx)?;
+                    self.apply_window_aggregate(
+                        expr,
+                        Expr::count,
+                        Some(|e| e.is_not_null().cast(IDX_DTYPE).cumsum(false)),
+                    )
                 }
                 // count(*)
-                FunctionArgExpr::Wildcard => lit(1i32).count(),
+                FunctionArgExpr::Wildcard => self.visit_count_rows(),
                 // count(tbl.*) is not supported
-                _ => return not_supported_error("count", &args),
+                _ => not_supported_error("count", &args),
             },
             (1, true) => {
                 // count(distinct col)
                 if let FunctionArgExpr::Expr(sql_expr) = args[0] {
-                    let expr = apply_window_spec(parse_sql_expr(sql_expr)?, &self.0.over)?;
-                    expr.n_unique()
+                    let expr = parse_sql_expr(sql_expr, self.ctx)?;
+                    self.apply_window_aggregate(expr, Expr::n_unique, None)
                 } else {
                     // count(distinct *) or count(distinct tbl.*) is not supported
-                    return not_supported_error("count", &args);
+                    not_supported_error("count", &args)
                 }
             }
-            _ => return not_supported_error("count", &args),
+            _ => not_supported_error("count", &args),
+        }
+    }
+
+    /// count(*)
+    fn visit_count_rows(&mut self) -> PolarsResult<Expr> {
+        let (order_by, _) = self.window_order_by()?;
+        match order_by.into_iter().next() {
+            // count the rows of a running frame by the positions of the first ORDER BY key
+            Some(key) => self.apply_window_aggregate(
+                key,
+                |_| lit(1i32).count(),
+                Some(|e| e.cumcount(false).cast(IDX_DTYPE) + lit(1 as IdxSize)),
+            ),
+            None => self.apply_window_partition(lit(1i32).count()),
+        }
+    }
+
+    fn visit_row_number(&mut self) -> PolarsResult<Expr> {
+        let (order_by, descending) = self.window_order_by()?;
+        let partition_by = self.window_partition_by()?;
+        let expr = if !order_by.is_empty() {
+            // position of every row in the ordered partition
+            arg_sort_by(order_by, &descending).arg_sort(SortOptions::default())
+        } else if let Some(e) = partition_by.first() {
+            e.clone().cumcount(false)
+        } else {
+            polars_bail!(
+                InvalidOperation:
+                "ROW_NUMBER requires an OVER clause with PARTITION BY or ORDER BY"
+            )
+        };
+        self.apply_window_partition(expr + lit(1 as IdxSize))
+    }
+
+    fn visit_rank(&mut self, method: RankMethod) -> PolarsResult<Expr> {
+        let function_name = self.func.name.0[0].value.to_uppercase();
+        let (mut order_by, descending) = self.window_order_by()?;
+        polars_ensure!(
+            order_by.len() == 1,
+            InvalidOperation:
+            "{} requires an OVER clause with an ORDER BY on a single expression",
+            function_name
+        );
+        let options = RankOptions {
+            method,
+            descending: descending[0],
+        };
+        let expr = order_by.pop().unwrap().rank(options);
+        self.apply_window_partition(expr)
+    }
+
+    /// LAG/LEAD(expr [, offset [, default]]); `direction` is 1 for LAG and -1 for LEAD.
+    fn visit_shift(&mut self, direction: i64) -> PolarsResult<Expr> {
+        let function = self.func;
+        let args = extract_args(function);
+        let sql_exprs = args
+            .iter()
+            .map(|arg| match arg {
+                FunctionArgExpr::Expr(sql_expr) => Ok(sql_expr),
+                _ => polars_bail!(
+                    InvalidOperation:
+                    "function `{}` with args {:?} is not supported in polars-sql",
+                    function.name.0[0].value, args
+                ),
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let (expr, offset, default) = match sql_exprs.as_slice() {
+            [e] => (parse_sql_expr(e, self.ctx)?, 1, None),
+            [e, n] => (
+                parse_sql_expr(e, self.ctx)?,
+                i64::from_sql_expr(n, self.ctx)?,
+                None,
+            ),
+            [e, n, d] => (
+                parse_sql_expr(e, self.ctx)?,
+                i64::from_sql_expr(n, self.ctx)?,
+                Some(parse_sql_expr(d, self.ctx)?),
+            ),
+            _ => return not_supported_error(function.name.0[0].value.as_str(), &args),
+        };
+        let periods = direction * offset;
+        self.visit_ordered_window(expr, |e| match default {
+            Some(d) => e.shift_and_fill(periods, d),
+            None => e.shift(periods),
+        })
+    }
+
+    /// Apply a function producing one value per row within the window. The rows of each
+    /// partition are sorted by the ORDER BY keys before `f` is applied and the result is
+    /// mapped back to the original row order afterwards.
+    fn visit_ordered_window(
+        &mut self,
+        expr: Expr,
+        f: impl FnOnce(Expr) -> Expr,
+    ) -> PolarsResult<Expr> {
+        let (order_by, descending) = self.window_order_by()?;
+        let expr = if order_by.is_empty() {
+            f(expr)
+        } else {
+            let inverse =
+                arg_sort_by(order_by.clone(), &descending).arg_sort(SortOptions::default());
+            f(expr.sort_by(order_by, descending)).take(inverse)
+        };
+        self.apply_window_partition(expr)
+    }
+
+    fn window_spec(&self) -> PolarsResult<Option<&'a WindowSpec>> {
+        let window_spec = match &self.func.over {
+            Some(WindowType::WindowSpec(window_spec)) => window_spec,
+            Some(WindowType::NamedWindow(name)) => {
+                polars_bail!(InvalidOperation: "named window `{}` is not supported", name)
+            }
+            None => return Ok(None),
+        };
+        if let Some(frame) = &window_spec.window_frame {
+            // only frames from the start of the partition up to the current row or
+            // the end of the partition are supported
+            let supported = matches!(frame.start_bound, WindowFrameBound::Preceding(None))
+                && matches!(
+                    frame.end_bound,
+                    None | Some(WindowFrameBound::CurrentRow)
+                        | Some(WindowFrameBound::Following(None))
+                );
+            polars_ensure!(
+                supported,
+                InvalidOperation: "window frame {:?} is not supported in polars-sql", frame
+            );
+        }
+        Ok(Some(window_spec))
+    }
+
+    fn window_frame(&self) -> PolarsResult<WindowFrame> {
+        let Some(window_spec) = self.window_spec()? else {
+            return Ok(WindowFrame::Partition)
+        };
+        if window_spec.order_by.is_empty() {
+            return Ok(WindowFrame::Partition);
+        }
+        Ok(match &window_spec.window_frame {
+            // the default frame of an ordered window is
+            // RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW
+            None => WindowFrame::Running { peers: true },
+            Some(frame) => match frame.end_bound {
+                Some(WindowFrameBound::Following(None)) => WindowFrame::Partition,
+                _ => WindowFrame::Running {
+                    peers: !matches!(frame.units, WindowFrameUnits::Rows),
+                },
+            },
         })
     }
-}
 
-fn apply_window_spec(expr: Expr, window_spec: &Option<WindowSpec>) -> PolarsResult<Expr> {
-    Ok(match &window_spec {
-        Some(window_spec) => {
-            // Process for simple window specification, partition by first
-            let partition_by = window_spec
+    fn window_partition_by(&mut self) -> PolarsResult<Vec<Expr>> {
+        match self.window_spec()? {
+            Some(window_spec) => window_spec
                 .partition_by
                 .iter()
-                .map(parse_sql_expr)
-                .collect::<PolarsResult<Vec<_>>>()?;
+                .map(|e| parse_sql_expr(e, self.ctx))
+                .collect(),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn window_order_by(&mut self) -> PolarsResult<(Vec<Expr>, Vec<bool>)> {
+        let mut by = vec![];
+        let mut descending = vec![];
+        if let Some(window_spec) = self.window_spec()? {
+            for ob in &window_spec.order_by {
+                by.push(parse_sql_expr(&ob.expr, self.ctx)?);
+                descending.push(matches!(ob.asc, Some(false)));
+            }
+        }
+        Ok((by, descending))
+    }
+
+    /// Sort `expr` by the ORDER BY of the window, if any.
+    fn apply_window_order(&mut self, expr: Expr) -> PolarsResult<Expr> {
+        let (order_by, descending) = self.window_order_by()?;
+        Ok(if order_by.is_empty() {
+            expr
+        } else {
+            expr.sort_by(order_by, descending)
+        })
+    }
+
+    /// Aggregate `expr` over the frame of the window, see [`Self::visit_aggregate`].
+    fn apply_window_aggregate(
+        &mut self,
+        expr: Expr,
+        f: impl FnOnce(Expr) -> Expr,
+        running: Option<fn(Expr) -> Expr>,
+    ) -> PolarsResult<Expr> {
+        match self.window_frame()? {
+            WindowFrame::Partition => {
+                let expr = self.apply_window_order(expr)?;
+                self.apply_window_partition(f(expr))
+            }
+            WindowFrame::Running { peers } => {
+                let Some(running) = running else {
+                    polars_bail!(
+                        InvalidOperation:
+                        "{} over an ordered window requires a frame spanning the whole partition",
+                        self.func.name.0[0].value.to_uppercase()
+                    )
+                };
+                let (order_by, descending) = self.window_order_by()?;
+                let sort = |e: Expr| e.sort_by(order_by.clone(), descending.clone());
+                let mut expr = running(sort(expr));
+                if peers {
+                    // every row takes the value of its last peer, which ends a run
+                    // of equal ORDER BY values in the sorted partition
+                    let run_end = order_by
+                        .iter()
+                        .map(|key| {
+                            let key = sort(key.clone());
+                            key.clone().neq(key.shift(-1)).fill_null(lit(true))
+                        })
+                        .reduce(|acc, e| acc.or(e))
+                        .unwrap();
+                    let last_peer = when(run_end)
+                        .then(expr.clone().cumcount(false))
+                        .otherwise(lit(NULL))
+                        .backward_fill(None);
+                    expr = expr.take(last_peer);
+                }
+                let inverse = arg_sort_by(order_by, &descending).arg_sort(SortOptions::default());
+                self.apply_window_partition(expr.take(inverse))
+            }
+        }
+    }
+
+    /// Evaluate `expr` over the PARTITION BY of the window, if any.
+    fn apply_window_partition(&mut self, expr: Expr) -> PolarsResult<Expr> {
+        let partition_by = self.window_partition_by()?;
+        Ok(if partition_by.is_empty() {
+            expr
+        } else {
             expr.over(partition_by)
-            // Order by and Row range may not be supported at the moment
+        })
+    }
+}
+
+/// The current local time, taken when the query is executed rather than planned,
+/// so that a LazyFrame that is collected repeatedly doesn't return a stale time.
+fn now() -> Expr {
+    let dtype = DataType::Datetime(TimeUnit::Nanoseconds, None);
+    lit(0i32).map(
+        |s| {
+            let now = Local::now().naive_local();
+            let ca = DatetimeChunked::from_naive_datetime(s.name(), [now], TimeUnit::Nanoseconds);
+            Ok(Some(ca.into_series()))
+        },
+        GetOutput::from_type(dtype),
+    )
+}
+
+/// Running `AVG`, ignoring NULLs like its aggregate.
+fn running_mean(expr: Expr) -> Expr {
+    let sum = expr
+        .clone()
+        .cast(DataType::Float64)
+        .cumsum(false)
+        .forward_fill(None);
+    let count = expr.is_not_null().cast(DataType::Float64).cumsum(false);
+    sum / count
+}
+
+/// SQL `SUBSTRING`/`SUBSTR`: `start` is 1-based and positions before the
+/// first character still count against `length`.
+pub(crate) fn substring(expr: Expr, start: i64, length: Option<i64>) -> Expr {
+    let offset = start - 1;
+    let (offset, length) = if offset < 0 {
+        (0, length.map(|l| l + offset))
+    } else {
+        (offset, length)
+    };
+    expr.str().slice(offset, length.map(|l| l.max(0) as u64))
+}
+
+/// SQL `EXTRACT(field FROM expr)`
+pub(crate) fn extract_datetime_field(expr: Expr, field: &DateTimeField) -> PolarsResult<Expr> {
+    Ok(match field {
+        DateTimeField::Year => expr.dt().year(),
+        DateTimeField::Isoyear => expr.dt().iso_year(),
+        DateTimeField::Quarter => expr.dt().quarter(),
+        DateTimeField::Month => expr.dt().month(),
+        DateTimeField::Week => expr.dt().week(),
+        DateTimeField::Day => expr.dt().day(),
+        // day of the week, Sunday is 0
+        DateTimeField::Dow => expr.dt().weekday() % lit(7u32),
+        // ISO day of the week, Monday is 1 and Sunday is 7
+        DateTimeField::Isodow => expr.dt().weekday(),
+        DateTimeField::Doy => expr.dt().ordinal_day(),
+        DateTimeField::Date => expr.dt().date(),
+        DateTimeField::Hour => expr.dt().hour(),
+        DateTimeField::Minute => expr.dt().minute(),
+        DateTimeField::Second => expr.dt().second(),
+        DateTimeField::Millisecond | DateTimeField::Milliseconds => expr.dt().millisecond(),
+        DateTimeField::Microsecond | DateTimeField::Microseconds => expr.dt().microsecond(),
+        DateTimeField::Nanosecond | DateTimeField::Nanoseconds => expr.dt().nanosecond(),
+        // seconds since the unix epoch
+        DateTimeField::Epoch => {
+            expr.dt()
+                .timestamp(TimeUnit::Microseconds)
+                .cast(DataType::Float64)
+                / lit(1e6)
         }
-        None => expr,
+        other => polars_bail!(InvalidOperation: "EXTRACT({}) is not supported", other),
     })
 }
 
@@ -456,13 +1060,30 @@ fn extract_args(sql_function: &SQLFunction) -> Vec<&FunctionArgExpr> {
 }
 
 pub(crate) trait FromSqlExpr {
-    fn from_sql_expr(expr: &SqlExpr) -> PolarsResult<Self>
+    fn from_sql_expr(expr: &SqlExpr, ctx: &mut SQLContext) -> PolarsResult<Self>
     where
         Self: Sized;
 }
 
 impl FromSqlExpr for f64 {
-    fn from_sql_expr(expr: &SqlExpr) -> PolarsResult<Self>
+    fn from_sql_expr(expr: &SqlExpr, _ctx: &mut SQLContext) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        match expr {
+            SqlExpr::Value(v) => match v {
+                SqlValue::Number(s, _) => s
+                    .parse()
+                    .map_err(|_| polars_err!(ComputeError: "can't parse literal {:?}", s)),
+                _ => polars_bail!(ComputeError: "can't parse literal {:?}", v),
+            },
+            _ => polars_bail!(ComputeError: "can't parse literal {:?}", expr),
+        }
+    }
+}
+
+impl FromSqlExpr for i64 {
+    fn from_sql_expr(expr: &SqlExpr, _ctx: &mut SQLContext) -> PolarsResult<Self>
     where
         Self: Sized,
     {
@@ -479,7 +1100,7 @@ impl FromSqlExpr for f64 {
 }
 
 impl FromSqlExpr for String {
-    fn from_sql_expr(expr: &SqlExpr) -> PolarsResult<Self>
+    fn from_sql_expr(expr: &SqlExpr, _ctx: &mut SQLContext) -> PolarsResult<Self>
     where
         Self: Sized,
     {
@@ -494,10 +1115,10 @@ impl FromSqlExpr for String {
 }
 
 impl FromSqlExpr for Expr {
-    fn from_sql_expr(expr: &SqlExpr) -> PolarsResult<Self>
+    fn from_sql_expr(expr: &SqlExpr, ctx: &mut SQLContext) -> PolarsResult<Self>
     where
         Self: Sized,
     {
-        parse_sql_expr(expr)
+        parse_sql_expr(expr, ctx)
     }
 }
@@ -1,7 +1,10 @@
+mod catalog;
 mod context;
 mod functions;
 mod sql_expr;
 mod table_functions;
+mod table_scope;
+pub use catalog::TableCatalog;
 pub use context::SQLContext;
 
 #[cfg(test)]
@@ -9,6 +12,9 @@ mod test {
     use polars_core::prelude::*;
     use polars_lazy::dsl::lit;
     use polars_lazy::prelude::*;
+    use sqlparser::ast::Statement;
+    use sqlparser::dialect::GenericDialect;
+    use sqlparser::parser::Parser;
 
     use super::*;
 
@@ -544,6 +550,738 @@ mod test {
         assert!(df_sql.frame_equal(&df_pl));
     }
 
+    #[test]
+    fn test_cte() -> PolarsResult<()> {
+        let df = create_sample_df()?;
+        let mut context = SQLContext::try_new()?;
+        context.register("df", df.clone().lazy());
+        let df_sql = context
+            .execute(
+                r#"
+            WITH t1 AS (SELECT a, b FROM df WHERE a > 10),
+                 t2 (x, y) AS (SELECT a, b FROM t1 WHERE a < 20)
+            SELECT x, y FROM t2
+        "#,
+            )?
+            .collect()?;
+        let df_pl = df
+            .lazy()
+            .filter(col("a").gt(lit(10)).and(col("a").lt(lit(20))))
+            .select(&[col("a").alias("x"), col("b").alias("y")])
+            .collect()?;
+        assert!(df_sql.frame_equal(&df_pl));
+        // CTEs don't outlive their statement
+        assert!(context.execute("SELECT * FROM t1").is_err());
+
+        // nor the query that defines them
+        let ast = Parser::parse_sql(
+            &GenericDialect::default(),
+            "WITH t1 AS (SELECT a FROM df) SELECT * FROM t1",
+        )
+        .unwrap();
+        let Statement::Query(query) = &ast[0] else {
+            unreachable!()
+        };
+        context.execute_query(query)?.collect()?;
+        assert!(context.execute("SELECT * FROM t1").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_derived_table() -> PolarsResult<()> {
+        let df = create_sample_df()?;
+        let mut context = SQLContext::try_new()?;
+        context.register("df", df.clone().lazy());
+        let df_sql = context
+            .execute("SELECT c FROM (SELECT a + b AS c FROM df) AS sub WHERE c < 100")?
+            .collect()?;
+        let df_pl = df
+            .lazy()
+            .select(&[(col("a") + col("b")).alias("c")])
+            .filter(col("c").lt(lit(100)))
+            .collect()?;
+        assert!(df_sql.frame_equal(&df_pl));
+        Ok(())
+    }
+
+    #[test]
+    fn test_in_subquery() -> PolarsResult<()> {
+        let df = create_sample_df()?;
+        let other = df! {
+            "x" => [1i64, 3, 5],
+        }?;
+        let mut context = SQLContext::try_new()?;
+        context.register("df", df.clone().lazy());
+        context.register("other", other.lazy());
+
+        let df_sql = context
+            .execute("SELECT * FROM df WHERE a IN (SELECT x FROM other) AND b > 150")?
+            .collect()?;
+        let df_pl = df
+            .clone()
+            .lazy()
+            .filter(
+                col("a")
+                    .is_in(lit(Series::new("", [1i64, 3, 5])))
+                    .and(col("b").gt(lit(150))),
+            )
+            .collect()?;
+        assert!(df_sql.frame_equal(&df_pl));
+
+        let df_sql = context
+            .execute("SELECT * FROM df WHERE a NOT IN (SELECT x FROM other)")?
+            .collect()?;
+        let df_pl = df
+            .lazy()
+            .filter(col("a").is_in(lit(Series::new("", [1i64, 3, 5]))).not())
+            .collect()?;
+        assert!(df_sql.frame_equal(&df_pl));
+        Ok(())
+    }
+
+    #[test]
+    fn test_in_null_semantics() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [Some(1i64), Some(2), None],
+        }?;
+        let other = df! {
+            "x" => [Some(1i64), None],
+        }?;
+        let mut context = SQLContext::try_new()?;
+        context.register("df", df.lazy());
+        context.register("other", other.lazy());
+
+        let df_sql = context
+            .execute(
+                r#"
+            SELECT
+                a IN (1, NULL) AS in_null,
+                a NOT IN (1, NULL) AS not_in_null,
+                a NOT IN (1, 3) AS not_in,
+                a IN (SELECT x FROM other) AS in_sub,
+                a NOT IN (SELECT x FROM other) AS not_in_sub,
+                a NOT IN (SELECT x FROM other WHERE x > 5) AS not_in_empty
+            FROM df
+            "#,
+            )?
+            .collect()?;
+        let expected = df! {
+            "in_null" => [Some(true), None, None],
+            "not_in_null" => [Some(false), None, None],
+            "not_in" => [Some(false), Some(true), None],
+            "in_sub" => [Some(true), None, None],
+            "not_in_sub" => [Some(false), None, None],
+            "not_in_empty" => [true, true, true],
+        }?;
+        assert!(df_sql.frame_equal_missing(&expected));
+
+        let df_sql = context
+            .execute("SELECT a FROM df WHERE a IN (SELECT x FROM other) OR a = 2")?
+            .collect()?;
+        assert!(df_sql.frame_equal(&df! { "a" => [1i64, 2] }?));
+
+        let df_sql = context
+            .execute("SELECT a FROM df WHERE a NOT IN (SELECT x FROM other WHERE x IS NOT NULL)")?
+            .collect()?;
+        assert!(df_sql.frame_equal(&df! { "a" => [2i64] }?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_scalar_subquery() -> PolarsResult<()> {
+        let df = create_sample_df()?;
+        let mut context = SQLContext::try_new()?;
+        context.register("df", df.clone().lazy());
+        let df_sql = context
+            .execute(
+                r#"
+            SELECT *, (SELECT MAX(a) FROM df) AS max_a
+            FROM df
+            WHERE b > (SELECT AVG(b) FROM df WHERE a > (SELECT MIN(a) FROM df))
+        "#,
+            )?
+            .collect()?;
+        let df_pl = df
+            .lazy()
+            .filter(col("b").gt(lit(5049.5)))
+            .with_column(lit(99i64).alias("max_a"))
+            .collect()?;
+        assert!(df_sql.frame_equal(&df_pl));
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_operations() -> PolarsResult<()> {
+        let df1 = df! {
+            "a" => [1i64, 2, 2, 3],
+            "b" => ["x", "y", "y", "z"],
+        }?;
+        let df2 = df! {
+            "c" => [2i32, 3, 4],
+            "d" => ["y", "z", "w"],
+        }?;
+        let mut context = SQLContext::try_new()?;
+        context.register("df1", df1.lazy());
+        context.register("df2", df2.lazy());
+
+        let df_sql = context
+            .execute("SELECT a, b FROM df1 UNION ALL SELECT c, d FROM df2")?
+            .collect()?;
+        let expected = df! {
+            "a" => [1i64, 2, 2, 3, 2, 3, 4],
+            "b" => ["x", "y", "y", "z", "y", "z", "w"],
+        }?;
+        assert!(df_sql.frame_equal(&expected));
+
+        let df_sql = context
+            .execute("SELECT a, b FROM df1 UNION SELECT c, d FROM df2")?
+            .collect()?;
+        let expected = df! {
+            "a" => [1i64, 2, 3, 4],
+            "b" => ["x", "y", "z", "w"],
+        }?;
+        assert!(df_sql.frame_equal(&expected));
+
+        let df_sql = context
+            .execute("SELECT a, b FROM df1 INTERSECT SELECT c, d FROM df2")?
+            .collect()?;
+        let expected = df! {
+            "a" => [2i64, 3],
+            "b" => ["y", "z"],
+        }?;
+        assert!(df_sql.frame_equal(&expected));
+
+        let df_sql = context
+            .execute("SELECT a, b FROM df1 EXCEPT SELECT c, d FROM df2")?
+            .collect()?;
+        let expected = df! {
+            "a" => [1i64],
+            "b" => ["x"],
+        }?;
+        assert!(df_sql.frame_equal(&expected));
+
+        assert!(context
+            .execute("SELECT a, b FROM df1 UNION SELECT c FROM df2")
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_functions() -> PolarsResult<()> {
+        let df = df! {
+            "g" => ["a", "b", "a", "b", "a"],
+            "t" => [3i64, 1, 1, 2, 2],
+            "k" => [1i64, 1, 2, 1, 2],
+            "v" => [10i64, 20, 30, 40, 50],
+        }?;
+        let mut context = SQLContext::try_new()?;
+        context.register("df", df.lazy());
+        let df_sql = context
+            .execute(
+                r#"
+            SELECT
+                ROW_NUMBER() OVER (PARTITION BY g ORDER BY t) AS rn,
+                ROW_NUMBER() OVER (PARTITION BY g) AS rn_unordered,
+                RANK() OVER (PARTITION BY g ORDER BY k DESC) AS rnk,
+                DENSE_RANK() OVER (PARTITION BY g ORDER BY k DESC) AS dense_rnk,
+                LAG(v) OVER (PARTITION BY g ORDER BY t) AS lag,
+                LEAD(v, 1, 0) OVER (PARTITION BY g ORDER BY t) AS lead,
+                FIRST_VALUE(v) OVER (PARTITION BY g ORDER BY t) AS first,
+                LAST_VALUE(v) OVER (PARTITION BY g ORDER BY t) AS last,
+                SUM(v) OVER (PARTITION BY g) AS sum,
+                COUNT(v) OVER (PARTITION BY g) AS count,
+                SUM(v) OVER (PARTITION BY g ORDER BY t) AS running_sum,
+                AVG(v) OVER (PARTITION BY g ORDER BY t) AS running_avg,
+                MAX(v) OVER (ORDER BY k) AS running_max_peers,
+                COUNT(*) OVER (ORDER BY k) AS running_count_peers,
+                SUM(v) OVER (
+                    ORDER BY k ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW
+                ) AS running_sum_rows,
+                LAST_VALUE(v) OVER (
+                    PARTITION BY g ORDER BY t
+                    ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+                ) AS last_of_partition
+            FROM df
+            "#,
+            )?
+            .collect()?;
+        let expected = df! {
+            "rn" => [3i64, 1, 1, 2, 2],
+            "rn_unordered" => [1i64, 1, 2, 2, 3],
+            "rnk" => [3i64, 1, 1, 1, 1],
+            "dense_rnk" => [2i64, 1, 1, 1, 1],
+            "lag" => [Some(50i64), None, None, Some(20), Some(30)],
+            "lead" => [0i64, 40, 50, 0, 10],
+            "first" => [30i64, 20, 30, 20, 30],
+            "last" => [10i64, 20, 30, 40, 50],
+            "sum" => [90i64, 60, 90, 60, 90],
+            "count" => [3i64, 2, 3, 2, 3],
+            "running_sum" => [90i64, 20, 30, 60, 80],
+            "running_avg" => [30i64, 20, 30, 30, 40],
+            "running_max_peers" => [40i64, 40, 50, 40, 50],
+            "running_count_peers" => [3i64, 3, 5, 3, 5],
+            "running_sum_rows" => [10i64, 30, 100, 70, 150],
+            "last_of_partition" => [10i64, 40, 10, 40, 10],
+        }?;
+        let df_sql = df_sql
+            .lazy()
+            .select([col("*").cast(DataType::Int64)])
+            .collect()?;
+        assert!(df_sql.frame_equal_missing(&expected));
+
+        assert!(context
+            .execute("SELECT RANK() OVER (PARTITION BY g) FROM df")
+            .is_err());
+        assert!(context
+            .execute(
+                "SELECT SUM(v) OVER (ORDER BY t ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df"
+            )
+            .is_err());
+        assert!(context
+            .execute("SELECT STDDEV(v) OVER (PARTITION BY g ORDER BY t) FROM df")
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_having() -> PolarsResult<()> {
+        let df = df! {
+            "a" => ["x", "y", "x", "z", "y", "x"],
+            "b" => [1i64, 2, 3, 4, 5, 6],
+        }?;
+        let mut context = SQLContext::try_new()?;
+        context.register("df", df.lazy());
+        let df_sql = context
+            .execute("SELECT a, SUM(b) AS total FROM df GROUP BY a HAVING COUNT(b) > 1 ORDER BY a")?
+            .collect()?;
+        let expected = df! {
+            "a" => ["x", "y"],
+            "total" => [10i64, 7],
+        }?;
+        assert!(df_sql.frame_equal(&expected));
+
+        let df_sql = context
+            .execute("SELECT SUM(b) AS total FROM df HAVING SUM(b) > 100")?
+            .collect()?;
+        assert_eq!(df_sql.shape(), (0, 1));
+
+        // HAVING can refer to the aliases of the projection
+        let df_sql = context
+            .execute("SELECT a, SUM(b) AS total FROM df GROUP BY a HAVING total > 5 ORDER BY a")?
+            .collect()?;
+        assert!(df_sql.frame_equal(&expected));
+        let df_sql = context
+            .execute("SELECT SUM(b) AS total FROM df HAVING total > 20")?
+            .collect()?;
+        assert!(df_sql.frame_equal(&df! { "total" => [21i64] }?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_distinct() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [1i64, 1, 2, 2, 3],
+            "b" => ["x", "x", "y", "z", "z"],
+            "c" => [5i64, 4, 3, 2, 1],
+        }?;
+        let mut context = SQLContext::try_new()?;
+        context.register("df", df.lazy());
+
+        let df_sql = context.execute("SELECT DISTINCT a, b FROM df")?.collect()?;
+        let expected = df! {
+            "a" => [1i64, 2, 2, 3],
+            "b" => ["x", "y", "z", "z"],
+        }?;
+        assert!(df_sql.frame_equal(&expected));
+
+        // DISTINCT ON keeps the first row of every group in ORDER BY order
+        let df_sql = context
+            .execute("SELECT DISTINCT ON (a) a, c FROM df ORDER BY a, c")?
+            .collect()?;
+        let expected = df! {
+            "a" => [1i64, 2, 3],
+            "c" => [4i64, 2, 1],
+        }?;
+        assert!(df_sql.frame_equal(&expected));
+
+        assert!(context
+            .execute("SELECT DISTINCT ON (a + 1) a FROM df")
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_limit_offset() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [1i64, 2, 3, 4, 5],
+        }?;
+        let mut context = SQLContext::try_new()?;
+        context.register("df", df.lazy());
+
+        let df_sql = context
+            .execute("SELECT a FROM df LIMIT 2 OFFSET 1")?
+            .collect()?;
+        assert!(df_sql.frame_equal(&df! { "a" => [2i64, 3] }?));
+
+        let df_sql = context.execute("SELECT a FROM df OFFSET 3")?.collect()?;
+        assert!(df_sql.frame_equal(&df! { "a" => [4i64, 5] }?));
+
+        assert!(context
+            .execute("SELECT a FROM df FETCH FIRST 2 ROWS ONLY")
+            .is_err());
+        assert!(context.execute("SELECT TOP 2 a FROM df").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_case_in_like() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [1i64, 2, 3, 4],
+            "b" => ["apple", "Banana", "cherry", "a_b%c"],
+        }?;
+        let mut context = SQLContext::try_new()?;
+        context.register("df", df.lazy());
+        let df_sql = context
+            .execute(
+                r#"
+            SELECT
+                CASE WHEN a < 2 THEN 'low' WHEN a < 4 THEN 'mid' ELSE 'high' END AS size,
+                CASE a WHEN 1 THEN 'one' WHEN 2 THEN 'two' END AS name,
+                a IN (1, 3) AS in_list,
+                b NOT IN ('apple', 'cherry') AS not_in_list,
+                b LIKE '%an%' AS like,
+                b ILIKE 'b%' AS ilike,
+                b LIKE 'a!_b!%_' ESCAPE '!' AS escaped
+            FROM df
+            "#,
+            )?
+            .collect()?;
+        let expected = df! {
+            "size" => ["low", "mid", "mid", "high"],
+            "name" => [Some("one"), Some("two"), None, None],
+            "in_list" => [true, false, true, false],
+            "not_in_list" => [false, true, false, true],
+            "like" => [false, true, false, false],
+            "ilike" => [false, true, false, false],
+            "escaped" => [false, false, false, true],
+        }?;
+        assert!(df_sql.frame_equal_missing(&expected));
+
+        // the wildcards match newlines
+        context.register("lines", df! { "s" => ["a\nb"] }?.lazy());
+        let df_sql = context
+            .execute("SELECT s LIKE 'a%b' AS any, s LIKE 'a_b' AS single FROM lines")?
+            .collect()?;
+        assert!(df_sql.frame_equal(&df! { "any" => [true], "single" => [true] }?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_scalar_function_library() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [Some(1.234f64), None, Some(3.456)],
+            "b" => [Some("hello"), Some("world"), None],
+            "c" => [0i64, 2, 3],
+        }?;
+        let mut context = SQLContext::try_new()?;
+        context.register("df", df.lazy());
+        let df_sql = context
+            .execute(
+                r#"
+            SELECT
+                SUBSTR(b, 2, 3) AS substr,
+                SUBSTRING(b FROM 3) AS substring,
+                LENGTH(b) AS length,
+                REPLACE(b, 'l', 'L') AS replace,
+                CONCAT(b, '-', c) AS concat,
+                b || '-' || c AS concat_op,
+                COALESCE(a, c) AS coalesce,
+                NULLIF(c, 0) AS nullif,
+                ROUND(a, 1) AS round
+            FROM df
+            "#,
+            )?
+            .collect()?;
+        let expected = df! {
+            "substr" => [Some("ell"), Some("orl"), None],
+            "substring" => [Some("llo"), Some("rld"), None],
+            "length" => [Some(5u32), Some(5), None],
+            "replace" => [Some("heLLo"), Some("worLd"), None],
+            "concat" => ["hello-0", "world-2", "-3"],
+            "concat_op" => [Some("hello-0"), Some("world-2"), None],
+            "coalesce" => [1.234f64, 2.0, 3.456],
+            "nullif" => [None, Some(2i64), Some(3)],
+            "round" => [Some(1.2f64), None, Some(3.5)],
+        }?;
+        assert!(df_sql.frame_equal_missing(&expected));
+
+        // invalid arguments are errors, not panics
+        for query in [
+            "SELECT ROUND(a, -1) FROM df",
+            "SELECT NULLIF(a) FROM df",
+            "SELECT STRFTIME(a) FROM df",
+            "SELECT ABS() FROM df",
+            "SELECT LTRIM(b, 'x', 'y') FROM df",
+        ] {
+            let res = context.execute(query);
+            assert!(
+                matches!(res, Err(PolarsError::InvalidOperation(_))),
+                "{query}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_date_functions() -> PolarsResult<()> {
+        use polars_core::export::chrono::NaiveDate;
+
+        let df = df! {
+            "d" => [
+                NaiveDate::from_ymd_opt(2023, 5, 17).unwrap(),
+                NaiveDate::from_ymd_opt(2021, 12, 31).unwrap(),
+            ],
+        }?;
+        let mut context = SQLContext::try_new()?;
+        context.register("df", df.lazy());
+        let df_sql = context
+            .execute(
+                r#"
+            SELECT
+                EXTRACT(YEAR FROM d) AS year,
+                EXTRACT(MONTH FROM d) AS month,
+                EXTRACT(DOW FROM d) AS dow,
+                STRFTIME(d, '%d/%m/%Y') AS formatted,
+                DATE_TRUNC('month', d) AS month_start,
+                CURRENT_DATE >= d AS in_past
+            FROM df
+            "#,
+            )?
+            .collect()?;
+        let expected = df! {
+            "year" => [2023i32, 2021],
+            "month" => [5u32, 12],
+            "dow" => [3u32, 5],
+            "formatted" => ["17/05/2023", "31/12/2021"],
+            "month_start" => [
+                NaiveDate::from_ymd_opt(2023, 5, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2021, 12, 1).unwrap(),
+            ],
+            "in_past" => [true, true],
+        }?;
+        assert!(df_sql.frame_equal(&expected));
+
+        assert!(context
+            .execute("SELECT DATE_TRUNC('fortnight', d) FROM df")
+            .is_err());
+
+        // NOW() is evaluated every time the query runs
+        let lf = context.execute("SELECT NOW() AS now FROM df")?;
+        let first = lf.clone().collect()?.column("now")?.max::<i64>();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        let second = lf.collect()?.column("now")?.max::<i64>();
+        assert!(second > first);
+        Ok(())
+    }
+
+    #[test]
+    fn test_qualified_columns_and_joins() -> PolarsResult<()> {
+        let df1 = df! {
+            "id" => [1i64, 2, 3],
+            "x" => ["a", "b", "c"],
+        }?;
+        let df2 = df! {
+            "id" => [1i64, 2, 4],
+            "x" => ["p", "q", "r"],
+            "y" => [10i64, 20, 40],
+        }?;
+        let df3 = df! {
+            "y" => [10i64, 20],
+            "z" => ["ten", "twenty"],
+        }?;
+        let mut context = SQLContext::try_new()?;
+        context.register("df1", df1.lazy());
+        context.register("df2", df2.lazy());
+        context.register("df3", df3.lazy());
+        // several contexts can live on the same thread
+        let _other = SQLContext::try_new()?;
+
+        let expected = df! {
+            "id" => [1i64, 2],
+            "x" => ["a", "b"],
+            "bx" => ["p", "q"],
+            "y" => [10i64, 20],
+        }?;
+        for sql in [
+            "SELECT a.id, a.x, b.x AS bx, b.y FROM df1 AS a JOIN df2 AS b ON a.id = b.id ORDER BY a.id",
+            "SELECT a.id, a.x, b.x AS bx, b.y FROM df1 a JOIN df2 b ON b.id = a.id ORDER BY a.id",
+            "SELECT a.id, a.x, b.x AS bx, b.y FROM df1 a, df2 b WHERE a.id = b.id ORDER BY a.id",
+            "SELECT df1.id, df1.x, df2.x AS bx, df2.y FROM df1 JOIN df2 USING (id) ORDER BY df1.id",
+        ] {
+            let df_sql = context.execute(sql)?.collect()?;
+            assert!(df_sql.frame_equal(&expected), "{sql}");
+        }
+
+        // the join predicate is taken from WHERE, the remaining predicates filter
+        let df_sql = context
+            .execute("SELECT a.x, b.x AS bx FROM df1 a, df2 b WHERE a.id = b.id AND b.y > 10")?
+            .collect()?;
+        assert!(df_sql.frame_equal(&df! { "x" => ["b"], "bx" => ["q"] }?));
+
+        // keys can refer to any of the relations joined so far
+        let df_sql = context
+            .execute(
+                r#"
+            SELECT a.x, c.z
+            FROM df1 a
+            JOIN df2 b ON a.id = b.id
+            JOIN df3 c ON b.y = c.y
+            ORDER BY a.x
+            "#,
+            )?
+            .collect()?;
+        assert!(df_sql.frame_equal(&df! { "x" => ["a", "b"], "z" => ["ten", "twenty"] }?));
+
+        let df_sql = context
+            .execute("SELECT b.* FROM df1 a JOIN df2 b ON a.id = b.id ORDER BY b.y")?
+            .collect()?;
+        assert_eq!(df_sql.get_column_names(), &["id", "x_right", "y"]);
+
+        // the right-hand keys of an outer join are null for the rows without a match
+        let df_sql = context
+            .execute(
+                "SELECT a.id, b.id AS bid, b.y FROM df1 a LEFT JOIN df2 b ON a.id = b.id WHERE b.id IS NULL",
+            )?
+            .collect()?;
+        let expected = df! {
+            "id" => [3i64],
+            "bid" => [None::<i64>],
+            "y" => [None::<i64>],
+        }?;
+        assert!(df_sql.frame_equal_missing(&expected));
+        let df_sql = context
+            .execute(
+                "SELECT b.id AS bid FROM df1 a FULL OUTER JOIN df2 b ON a.id = b.id ORDER BY bid",
+            )?
+            .collect()?;
+        let expected = df! { "bid" => [None, Some(1i64), Some(2), Some(4)] }?;
+        assert!(df_sql.frame_equal_missing(&expected));
+
+        assert!(context.execute("SELECT c.id FROM df1 a").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_table_registry() -> PolarsResult<()> {
+        let df = df! { "a" => [1i64, 2, 3] }?;
+        let mut context = SQLContext::try_new()?;
+        let mut other = SQLContext::try_new()?;
+        context.register("tbl_b", df.clone().lazy());
+        context.register("tbl_a", df.clone().lazy());
+        other.register("tbl_c", df.lazy());
+        assert_eq!(context.get_tables(), &["tbl_a", "tbl_b"]);
+        assert_eq!(other.get_tables(), &["tbl_c"]);
+
+        context.unregister("tbl_b");
+        assert_eq!(context.get_tables(), &["tbl_a"]);
+        assert!(context.execute("SELECT * FROM tbl_b").is_err());
+
+        assert!(context.execute("DROP TABLE tbl_x").is_err());
+        context.execute("DROP TABLE IF EXISTS tbl_x")?.collect()?;
+        context.execute("DROP TABLE tbl_a")?.collect()?;
+        assert!(context.get_tables().is_empty());
+        assert_eq!(other.get_tables(), &["tbl_c"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_introspection() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [1i64, 2, 3],
+            "b" => ["x", "y", "z"],
+        }?;
+        let mut context = SQLContext::try_new()?;
+        context.register("tbl_b", df.clone().lazy());
+        context.register("tbl_a", df.lazy());
+
+        let tables = context.execute("SHOW TABLES")?.collect()?;
+        let expected = df! { "name" => ["tbl_a", "tbl_b"] }?;
+        assert!(tables.frame_equal(&expected));
+
+        let described = context.execute("DESCRIBE tbl_a")?.collect()?;
+        let expected = df! {
+            "column_name" => ["a", "b"],
+            "data_type" => ["i64", "str"],
+        }?;
+        assert!(described.frame_equal(&expected));
+        assert!(context.execute("DESCRIBE tbl_x").is_err());
+
+        let plan = context
+            .execute("EXPLAIN SELECT a FROM tbl_a WHERE a > 1")?
+            .collect()?;
+        assert_eq!(plan.get_column_names(), &["Logical Plan"]);
+        assert!(plan.height() > 1);
+        let plan = plan.column("Logical Plan")?.utf8()?;
+        assert!(plan
+            .into_iter()
+            .flatten()
+            .any(|line| line.contains("SELECTION")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_table_catalog() -> PolarsResult<()> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        struct TestCatalog {
+            lookups: AtomicUsize,
+        }
+
+        impl TableCatalog for TestCatalog {
+            fn get_table(&self, path: &[&str]) -> PolarsResult<Option<LazyFrame>> {
+                self.lookups.fetch_add(1, Ordering::Relaxed);
+                Ok(match path {
+                    ["sales", "orders"] => Some(
+                        df! {
+                            "id" => [1i64, 2],
+                            "amount" => [10i64, 20],
+                        }?
+                        .lazy(),
+                    ),
+                    _ => None,
+                })
+            }
+        }
+
+        let catalog = Arc::new(TestCatalog {
+            lookups: AtomicUsize::new(0),
+        });
+        let mut context = SQLContext::try_new()?;
+        context.register_catalog("warehouse", catalog.clone());
+        assert_eq!(catalog.lookups.load(Ordering::Relaxed), 0);
+
+        let df_sql = context
+            .execute(
+                "SELECT orders.id, sales.orders.amount FROM warehouse.sales.orders WHERE amount > 10",
+            )?
+            .collect()?;
+        let expected = df! {
+            "id" => [2i64],
+            "amount" => [20i64],
+        }?;
+        assert!(df_sql.frame_equal(&expected));
+        assert_eq!(catalog.lookups.load(Ordering::Relaxed), 1);
+
+        assert!(context
+            .execute("SELECT * FROM warehouse.sales.customers")
+            .is_err());
+        assert!(context
+            .execute("SELECT * FROM unknown.sales.orders")
+            .is_err());
+        Ok(())
+    }
+
     fn assert_sql_to_polars(df: &DataFrame, sql: &str, f: impl FnOnce(LazyFrame) -> LazyFrame) {
         let mut context = SQLContext::try_new().unwrap();
         context.register("df", df.clone().lazy());
@@ -650,6 +1388,52 @@ mod test {
         assert!(df_sql.frame_equal(&expected));
     }
 
+    #[test]
+    #[cfg(feature = "csv")]
+    fn read_csv_tbl_func_options() -> PolarsResult<()> {
+        let mut context = SQLContext::try_new()?;
+        let sql = r#"
+            SELECT *
+            FROM read_csv(
+                '../../examples/datasets/foods1.csv',
+                has_header => false,
+                skip_rows => 1,
+                n_rows => 5,
+                infer_schema_length => 0
+            )"#;
+        let df_sql = context.execute(sql)?.collect()?;
+        let expected = LazyCsvReader::new("../../examples/datasets/foods1.csv")
+            .has_header(false)
+            .with_skip_rows(1)
+            .with_n_rows(Some(5))
+            .with_infer_schema_length(Some(0))
+            .finish()?
+            .collect()?;
+        assert!(df_sql.frame_equal(&expected));
+        assert_eq!(df_sql.height(), 5);
+        assert_eq!(df_sql.dtypes(), vec![DataType::Utf8; 4]);
+
+        let df_sql = context
+            .execute(
+                "SELECT * FROM read_csv('../../examples/datasets/foods*.csv', delimiter => ',')",
+            )?
+            .collect()?;
+        let expected = LazyCsvReader::new("../../examples/datasets/foods*.csv")
+            .finish()?
+            .collect()?;
+        assert!(df_sql.frame_equal_missing(&expected));
+
+        for sql in [
+            "SELECT * FROM read_csv('../../examples/datasets/foods1.csv', foo => 1)",
+            "SELECT * FROM read_csv('../../examples/datasets/foods1.csv', n_rows => 'x')",
+            "SELECT * FROM read_csv('../../examples/datasets/foods1.csv', n_rows => 1, n_rows => 2)",
+            "SELECT * FROM read_csv('../../examples/datasets/foods1.csv', 1)",
+        ] {
+            assert!(context.execute(sql).is_err(), "{sql}");
+        }
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "parquet")]
     fn read_parquet_tbl() {
@@ -695,6 +1479,78 @@ mod test {
         assert_eq!(df_2.width(), 4);
     }
 
+    #[test]
+    #[cfg(feature = "json")]
+    fn read_ndjson_tbl() -> PolarsResult<()> {
+        use polars_io::prelude::{JsonFormat, JsonWriter};
+        use polars_io::SerWriter;
+
+        let tmp = tempdir::TempDir::new("polars_sql_read_ndjson")?;
+        let path = tmp.path().join("data.ndjson");
+        let mut df = df! {
+            "a" => [1i64, 2, 3],
+            "b" => ["x", "y", "z"],
+        }?;
+        JsonWriter::new(std::fs::File::create(&path)?)
+            .with_json_format(JsonFormat::JsonLines)
+            .finish(&mut df)?;
+
+        let mut context = SQLContext::try_new()?;
+        let sql = format!("SELECT * FROM read_ndjson('{}')", path.display());
+        let out = context.execute(&sql)?.collect()?;
+        assert!(out.frame_equal(&df));
+
+        let sql = format!(
+            "SELECT * FROM read_json_lines('{}', n_rows => 2, row_count_name => 'idx')",
+            path.display()
+        );
+        let out = context.execute(&sql)?.collect()?;
+        assert_eq!(out.get_column_names(), &["idx", "a", "b"]);
+        assert_eq!(out.height(), 2);
+
+        let sql = format!(
+            "SELECT * FROM read_ndjson('{}', memmap => true)",
+            path.display()
+        );
+        assert!(context.execute(&sql).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "avro")]
+    fn read_avro_tbl() -> PolarsResult<()> {
+        use polars_io::avro::AvroWriter;
+        use polars_io::SerWriter;
+
+        let tmp = tempdir::TempDir::new("polars_sql_read_avro")?;
+        let path = tmp.path().join("data.avro");
+        let mut df = df! {
+            "a" => [1i64, 2, 3],
+            "b" => ["x", "y", "z"],
+        }?;
+        AvroWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+
+        let mut context = SQLContext::try_new()?;
+        let sql = format!("SELECT * FROM read_avro('{}')", path.display());
+        let out = context.execute(&sql)?.collect()?;
+        assert!(out.frame_equal(&df));
+
+        let sql = format!(
+            "SELECT * FROM read_avro('{}', n_rows => 2, row_count_name => 'idx', row_count_offset => 10)",
+            path.display()
+        );
+        let out = context.execute(&sql)?.collect()?;
+        assert_eq!(out.get_column_names(), &["idx", "a", "b"]);
+        assert_eq!(Vec::from(out.column("idx")?.idx()?), &[Some(10), Some(11)]);
+
+        let sql = format!(
+            "SELECT * FROM read_avro('{}', cache => true)",
+            path.display()
+        );
+        assert!(context.execute(&sql).is_err());
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "csv")]
     fn iss_7436() {
@@ -3,12 +3,55 @@ use polars_lazy::dsl::Expr;
 use polars_lazy::prelude::*;
 use sqlparser::ast::{
     ArrayAgg, BinaryOperator as SQLBinaryOperator, BinaryOperator, DataType as SQLDataType,
-    Expr as SqlExpr, Function as SQLFunction, JoinConstraint, OrderByExpr, TrimWhereField,
-    UnaryOperator, Value as SqlValue,
+    DateTimeField, Expr as SqlExpr, Function as SQLFunction, JoinConstraint, OrderByExpr, Query,
+    TrimWhereField, UnaryOperator, Value as SqlValue,
 };
 
-use crate::context::TABLES;
-use crate::functions::SqlFunctionVisitor;
+use crate::context::split_conjunctions;
+use crate::functions::{extract_datetime_field, substring, FromSqlExpr, SqlFunctionVisitor};
+use crate::table_scope::TableScope;
+use crate::SQLContext;
+
+/// Evaluate an `IN` predicate with SQL's three-valued logic: it holds if a match
+/// was `found`, and is NULL instead of false if the outcome is `unknown` because
+/// of a NULL operand or a NULL in the list.
+fn in_predicate(found: Expr, unknown: Expr) -> Expr {
+    when(found)
+        .then(lit(true))
+        .when(unknown)
+        .then(lit(NULL))
+        .otherwise(lit(false))
+}
+
+/// Translate a SQL `LIKE` pattern into an anchored regex, in which the wildcards
+/// also match newlines.
+fn like_to_regex(pattern: &str, escape_char: Option<char>) -> PolarsResult<String> {
+    let mut regex = String::with_capacity(pattern.len() + 6);
+    regex.push_str("(?s)^");
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if Some(c) == escape_char => {
+                let escaped = chars.next().ok_or_else(
+                    || polars_err!(ComputeError: "LIKE pattern {:?} ends with an escape character", pattern),
+                )?;
+                push_regex_literal(&mut regex, escaped);
+            }
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            c => push_regex_literal(&mut regex, c),
+        }
+    }
+    regex.push('$');
+    Ok(regex)
+}
+
+fn push_regex_literal(regex: &mut String, c: char) {
+    if "\\.+*?()|[]{}^$#&-~".contains(c) {
+        regex.push('\\');
+    }
+    regex.push(c);
+}
 
 pub(crate) fn map_sql_polars_datatype(data_type: &SQLDataType) -> PolarsResult<DataType> {
     Ok(match data_type {
@@ -42,10 +85,12 @@ pub(crate) fn map_sql_polars_datatype(data_type: &SQLDataType) -> PolarsResult<D
 }
 
 /// Recursively walks a SQL Expr to create a polars Expr
-pub(crate) struct SqlExprVisitor {}
+pub(crate) struct SqlExprVisitor<'a> {
+    ctx: &'a mut SQLContext,
+}
 
-impl SqlExprVisitor {
-    fn visit_expr(&self, expr: &SqlExpr) -> PolarsResult<Expr> {
+impl SqlExprVisitor<'_> {
+    fn visit_expr(&mut self, expr: &SqlExpr) -> PolarsResult<Expr> {
         match expr {
             SqlExpr::CompoundIdentifier(idents) => self.visit_compound_identifier(idents),
             SqlExpr::Identifier(ident) => self.visit_identifier(ident),
@@ -77,6 +122,41 @@ impl SqlExprVisitor {
             SqlExpr::AllOp(_) => Ok(self.visit_expr(expr)?.all()),
             SqlExpr::Nested(expr) => self.visit_expr(expr),
             SqlExpr::UnaryOp { op, expr } => self.visit_unary_op(op, expr),
+            SqlExpr::Subquery(subquery) => self.visit_subquery(subquery),
+            SqlExpr::Case {
+                operand,
+                conditions,
+                results,
+                else_result,
+            } => self.visit_case(operand, conditions, results, else_result),
+            SqlExpr::InList {
+                expr,
+                list,
+                negated,
+            } => self.visit_in_list(expr, list, *negated),
+            SqlExpr::Like {
+                negated,
+                expr,
+                pattern,
+                escape_char,
+            } => self.visit_like(expr, pattern, *escape_char, *negated, false),
+            SqlExpr::ILike {
+                negated,
+                expr,
+                pattern,
+                escape_char,
+            } => self.visit_like(expr, pattern, *escape_char, *negated, true),
+            SqlExpr::Substring {
+                expr,
+                substring_from,
+                substring_for,
+            } => self.visit_substring(expr, substring_from, substring_for),
+            SqlExpr::Extract { field, expr } => self.visit_extract(field, expr),
+            SqlExpr::InSubquery {
+                expr,
+                subquery,
+                negated,
+            } => self.visit_in_subquery(expr, subquery, *negated),
             other => polars_bail!(ComputeError: "SQL expression {:?} is not yet supported", other),
         }
     }
@@ -84,24 +164,19 @@ impl SqlExprVisitor {
     /// Visit a compound identifier
     ///
     /// e.g. df.column or "df"."column"
-    fn visit_compound_identifier(&self, idents: &[sqlparser::ast::Ident]) -> PolarsResult<Expr> {
-        polars_ensure!(
-            idents.len() == 2,
-            ComputeError: "compound identifier {:?} is not yet supported", idents,
-        );
-        let tbl_name = &idents[0].value;
-        let refers_main_table = TABLES.with(|cell| {
-            let tables = cell.borrow();
-            tables.len() == 1 && tables.contains(tbl_name)
-        });
-        polars_ensure!(
-            refers_main_table, ComputeError:
-            "compound identifier {:?} is not yet supported if multiple tables are registered",
-            idents
-        );
-        Ok(col(&idents[1].value))
+    fn visit_compound_identifier(
+        &mut self,
+        idents: &[sqlparser::ast::Ident],
+    ) -> PolarsResult<Expr> {
+        // `schema.table.column` refers to the table by its last name part
+        let [.., tbl_name, column] = idents else {
+            polars_bail!(ComputeError: "compound identifier {:?} is not yet supported", idents);
+        };
+        let name = self.ctx.scope.resolve(&tbl_name.value, &column.value)?;
+        Ok(col(name))
     }
-    fn visit_unary_op(&self, op: &UnaryOperator, expr: &SqlExpr) -> PolarsResult<Expr> {
+
+    fn visit_unary_op(&mut self, op: &UnaryOperator, expr: &SqlExpr) -> PolarsResult<Expr> {
         let expr = self.visit_expr(expr)?;
         Ok(match op {
             UnaryOperator::Plus => lit(0) + expr,
@@ -114,7 +189,7 @@ impl SqlExprVisitor {
     /// Visit a single identifier
     ///
     /// e.g. column
-    fn visit_identifier(&self, ident: &sqlparser::ast::Ident) -> PolarsResult<Expr> {
+    fn visit_identifier(&mut self, ident: &sqlparser::ast::Ident) -> PolarsResult<Expr> {
         Ok(col(&ident.value))
     }
 
@@ -122,7 +197,7 @@ impl SqlExprVisitor {
     ///
     /// e.g. column + 1 or column1 / column2
     fn visit_binary_op(
-        &self,
+        &mut self,
         left: &SqlExpr,
         op: &BinaryOperator,
         right: &SqlExpr,
@@ -156,15 +231,30 @@ impl SqlExprVisitor {
     /// e.g. SUM(column) or COUNT(*)
     ///
     /// See [SqlFunctionVisitor] for more details
-    fn visit_function(&self, function: &SQLFunction) -> PolarsResult<Expr> {
-        let visitor = SqlFunctionVisitor(function);
+    fn visit_function(&mut self, function: &SQLFunction) -> PolarsResult<Expr> {
+        let mut visitor = SqlFunctionVisitor {
+            func: function,
+            ctx: self.ctx,
+        };
         visitor.visit_function()
     }
 
+    /// Visit a scalar SQL subquery
+    ///
+    /// e.g. `SELECT * FROM df WHERE a > (SELECT AVG(a) FROM df)`
+    ///
+    /// The subquery is executed into a single row frame that is cross joined
+    /// onto the outer query, see [SQLContext::register_subquery].
+    fn visit_subquery(&mut self, subquery: &Query) -> PolarsResult<Expr> {
+        let lf = self.ctx.execute_query(subquery)?;
+        let name = self.ctx.register_subquery(lf)?;
+        Ok(col(&name))
+    }
+
     /// Visit a SQL CAST
     ///
     /// e.g. `CAST(column AS INT)` or `column::INT`
-    fn visit_cast(&self, expr: &SqlExpr, data_type: &SQLDataType) -> PolarsResult<Expr> {
+    fn visit_cast(&mut self, expr: &SqlExpr, data_type: &SQLDataType) -> PolarsResult<Expr> {
         let polars_type = map_sql_polars_datatype(data_type)?;
         let expr = self.visit_expr(expr)?;
 
@@ -176,7 +266,7 @@ impl SqlExprVisitor {
     /// e.g. 1, 'foo', 1.0, NULL
     ///
     /// See [SqlValue] and [LiteralValue] for more details
-    fn visit_literal(&self, value: &SqlValue) -> PolarsResult<Expr> {
+    fn visit_literal(&mut self, value: &SqlValue) -> PolarsResult<Expr> {
         Ok(match value {
             SqlValue::Number(s, _) => {
                 // Check for existence of decimal separator dot
@@ -199,7 +289,7 @@ impl SqlExprVisitor {
     /// Visit a SQL `BETWEEN` expression
     /// See [sqlparser::ast::Expr::Between] for more details
     fn visit_between(
-        &self,
+        &mut self,
         expr: &SqlExpr,
         negated: bool,
         low: &SqlExpr,
@@ -215,10 +305,178 @@ impl SqlExprVisitor {
             Ok(expr.clone().gt(low).and(expr.lt(high)))
         }
     }
+    /// Visit a SQL `CASE` expression
+    ///
+    /// e.g. `CASE WHEN a > 1 THEN 'x' ELSE 'y' END` or `CASE a WHEN 1 THEN 'x' END`
+    fn visit_case(
+        &mut self,
+        operand: &Option<Box<SqlExpr>>,
+        conditions: &[SqlExpr],
+        results: &[SqlExpr],
+        else_result: &Option<Box<SqlExpr>>,
+    ) -> PolarsResult<Expr> {
+        let operand = operand.as_ref().map(|e| self.visit_expr(e)).transpose()?;
+        let mut branches = Vec::with_capacity(conditions.len());
+        for (condition, result) in conditions.iter().zip(results) {
+            let condition = self.visit_expr(condition)?;
+            // a "simple" CASE compares the operand against every condition
+            let condition = match &operand {
+                Some(operand) => operand.clone().eq(condition),
+                None => condition,
+            };
+            branches.push((condition, self.visit_expr(result)?));
+        }
+        let otherwise = match else_result {
+            Some(e) => self.visit_expr(e)?,
+            None => Expr::Literal(LiteralValue::Null),
+        };
+
+        let mut branches = branches.into_iter();
+        let (condition, result) = branches
+            .next()
+            .ok_or_else(|| polars_err!(ComputeError: "CASE requires at least one WHEN clause"))?;
+        let chain = when(condition).then(result);
+        let expr = match branches.next() {
+            None => chain.otherwise(otherwise),
+            Some((condition, result)) => branches
+                .fold(chain.when(condition).then(result), |chain, (c, r)| {
+                    chain.when(c).then(r)
+                })
+                .otherwise(otherwise),
+        };
+        Ok(expr)
+    }
+
+    /// Visit a SQL `IN` list
+    ///
+    /// e.g. `a IN (1, 2, 3)` or `a NOT IN ('x', 'y')`
+    fn visit_in_list(
+        &mut self,
+        expr: &SqlExpr,
+        list: &[SqlExpr],
+        negated: bool,
+    ) -> PolarsResult<Expr> {
+        let expr = self.visit_expr(expr)?;
+        let list = list
+            .iter()
+            .map(|e| self.visit_expr(e))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let values = list
+            .iter()
+            .map(|e| match e {
+                Expr::Literal(value) => value.to_anyvalue(),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>();
+
+        polars_ensure!(!list.is_empty(), ComputeError: "IN list must not be empty");
+
+        let (found, unknown) = match values {
+            // all values are literals, so they can be collected into a Series
+            Some(values) => {
+                let has_null = values.iter().any(|av| matches!(av, AnyValue::Null));
+                let values = values
+                    .into_iter()
+                    .filter(|av| !matches!(av, AnyValue::Null))
+                    .collect::<Vec<_>>();
+                let found = if values.is_empty() {
+                    lit(false)
+                } else {
+                    let values = Series::from_any_values("", &values, false)?;
+                    expr.clone().is_in(lit(values))
+                };
+                let unknown = if has_null { lit(true) } else { expr.is_null() };
+                (found, unknown)
+            }
+            None => {
+                let found = list
+                    .iter()
+                    .map(|e| expr.clone().eq(e.clone()).fill_null(lit(false)))
+                    .reduce(|acc, e| acc.or(e))
+                    .unwrap();
+                let unknown = list
+                    .into_iter()
+                    .fold(expr.is_null(), |acc, e| acc.or(e.is_null()));
+                (found, unknown)
+            }
+        };
+        let is_in = in_predicate(found, unknown);
+        Ok(if negated { is_in.not() } else { is_in })
+    }
+
+    /// Visit a SQL `IN` subquery
+    ///
+    /// e.g. `a IN (SELECT b FROM df)`, see [SQLContext::register_in_subquery].
+    fn visit_in_subquery(
+        &mut self,
+        expr: &SqlExpr,
+        subquery: &Query,
+        negated: bool,
+    ) -> PolarsResult<Expr> {
+        let expr = self.visit_expr(expr)?;
+        let lf = self.ctx.execute_query(subquery)?;
+        let (found, unknown) = self.ctx.register_in_subquery(expr, lf)?;
+        let is_in = in_predicate(found, unknown);
+        Ok(if negated { is_in.not() } else { is_in })
+    }
+
+    /// Visit a SQL `LIKE` or `ILIKE` expression
+    ///
+    /// The pattern is translated to an anchored regex, e.g. `'a%b_'` becomes `^a.*b.$`.
+    fn visit_like(
+        &mut self,
+        expr: &SqlExpr,
+        pattern: &SqlExpr,
+        escape_char: Option<char>,
+        negated: bool,
+        case_insensitive: bool,
+    ) -> PolarsResult<Expr> {
+        let expr = self.visit_expr(expr)?;
+        let pattern = match self.visit_expr(pattern)? {
+            Expr::Literal(LiteralValue::Utf8(pattern)) => pattern,
+            _ => polars_bail!(ComputeError: "LIKE pattern must be a string literal"),
+        };
+        let mut regex = like_to_regex(&pattern, escape_char)?;
+        if case_insensitive {
+            regex.insert_str(0, "(?i)");
+        }
+        let matches = expr.str().contains(lit(regex), true);
+        Ok(if negated { matches.not() } else { matches })
+    }
+
+    /// Visit a SQL `SUBSTRING` expression
+    ///
+    /// e.g. `SUBSTRING(a FROM 2 FOR 3)`
+    fn visit_substring(
+        &mut self,
+        expr: &SqlExpr,
+        substring_from: &Option<Box<SqlExpr>>,
+        substring_for: &Option<Box<SqlExpr>>,
+    ) -> PolarsResult<Expr> {
+        let expr = self.visit_expr(expr)?;
+        let start = match substring_from {
+            Some(e) => i64::from_sql_expr(e, self.ctx)?,
+            None => 1,
+        };
+        let length = match substring_for {
+            Some(e) => Some(i64::from_sql_expr(e, self.ctx)?),
+            None => None,
+        };
+        Ok(substring(expr, start, length))
+    }
+
+    /// Visit a SQL `EXTRACT` expression
+    ///
+    /// e.g. `EXTRACT(YEAR FROM column)`
+    fn visit_extract(&mut self, field: &DateTimeField, expr: &SqlExpr) -> PolarsResult<Expr> {
+        let expr = self.visit_expr(expr)?;
+        extract_datetime_field(expr, field)
+    }
+
     /// Visit a SQL 'TRIM' function
     /// See [sqlparser::ast::Expr::Trim] for more details
     fn visit_trim(
-        &self,
+        &mut self,
         expr: &SqlExpr,
         trim_where: &Option<TrimWhereField>,
         trim_what: &Option<Box<SqlExpr>>,
@@ -242,12 +500,17 @@ impl SqlExprVisitor {
     }
 
     /// Visit a SQL `ARRAY_AGG` expression
-    fn visit_arr_agg(&self, expr: &ArrayAgg) -> PolarsResult<Expr> {
+    fn visit_arr_agg(&mut self, expr: &ArrayAgg) -> PolarsResult<Expr> {
         let mut base = self.visit_expr(&expr.expr)?;
 
         if let Some(order_by) = expr.order_by.as_ref() {
-            let (order_by, descending) = self.visit_order_by(order_by)?;
-            base = base.sort_by(vec![order_by], vec![descending]);
+            let (order_by, descending): (Vec<_>, Vec<_>) = order_by
+                .iter()
+                .map(|ob| self.visit_order_by(ob))
+                .collect::<PolarsResult<Vec<_>>>()?
+                .into_iter()
+                .unzip();
+            base = base.sort_by(order_by, descending);
         }
 
         if let Some(limit) = &expr.limit {
@@ -272,7 +535,7 @@ impl SqlExprVisitor {
         Ok(base.list())
     }
 
-    fn visit_order_by(&self, order_by: &OrderByExpr) -> PolarsResult<(Expr, bool)> {
+    fn visit_order_by(&mut self, order_by: &OrderByExpr) -> PolarsResult<(Expr, bool)> {
         let expr = self.visit_expr(&order_by.expr)?;
         let descending = order_by.asc.unwrap_or(false);
         Ok((expr, descending))
@@ -283,40 +546,83 @@ impl SqlExprVisitor {
     }
 }
 
-pub(crate) fn parse_sql_expr(expr: &SqlExpr) -> PolarsResult<Expr> {
-    let visitor = SqlExprVisitor {};
+pub(crate) fn parse_sql_expr(expr: &SqlExpr, ctx: &mut SQLContext) -> PolarsResult<Expr> {
+    let mut visitor = SqlExprVisitor { ctx };
     visitor.visit_expr(expr)
 }
 
+/// Get the `(left, right)` join key column names of an `ON` or `USING` constraint.
 pub(super) fn process_join_constraint(
     constraint: &JoinConstraint,
-    left_name: &str,
-    right_name: &str,
-) -> PolarsResult<(Expr, Expr)> {
-    if let JoinConstraint::On(SqlExpr::BinaryOp { left, op, right }) = constraint {
-        match (left.as_ref(), right.as_ref()) {
-            (SqlExpr::CompoundIdentifier(left), SqlExpr::CompoundIdentifier(right)) => {
-                if left.len() == 2 && right.len() == 2 {
-                    let tbl_a = &left[0].value;
-                    let col_a = &left[1].value;
-
-                    let tbl_b = &right[0].value;
-                    let col_b = &right[1].value;
-
-                    if let BinaryOperator::Eq = op {
-                        if left_name == tbl_a && right_name == tbl_b {
-                            return Ok((col(col_a), col(col_b)));
-                        } else if left_name == tbl_b && right_name == tbl_a {
-                            return Ok((col(col_b), col(col_a)));
+    left: &TableScope,
+    right: &TableScope,
+) -> PolarsResult<Vec<(String, String)>> {
+    match constraint {
+        JoinConstraint::On(expr) => {
+            let mut conjunctions = vec![];
+            split_conjunctions(expr, &mut conjunctions);
+            conjunctions
+                .into_iter()
+                .map(|expr| match expr {
+                    SqlExpr::BinaryOp {
+                        left: l,
+                        op: BinaryOperator::Eq,
+                        right: r,
+                    } => match (l.as_ref(), r.as_ref()) {
+                        (SqlExpr::Identifier(l), SqlExpr::Identifier(r)) => {
+                            Ok((l.value.clone(), r.value.clone()))
                         }
-                    }
-                }
-            }
-            (SqlExpr::Identifier(left), SqlExpr::Identifier(right)) => {
-                return Ok((col(&left.value), col(&right.value)))
-            }
-            _ => {}
+                        _ => join_key_pair(l, r, left, right)?.ok_or_else(|| {
+                            polars_err!(
+                                ComputeError:
+                                "join condition {} does not compare columns of both sides", expr
+                            )
+                        }),
+                    },
+                    _ => polars_bail!(
+                        ComputeError: "SQL join condition {} is not yet supported", expr
+                    ),
+                })
+                .collect()
+        }
+        JoinConstraint::Using(idents) => Ok(idents
+            .iter()
+            .map(|i| (i.value.clone(), i.value.clone()))
+            .collect()),
+        _ => {
+            polars_bail!(ComputeError: "SQL join constraint {:?} is not yet supported", constraint)
         }
     }
-    polars_bail!(ComputeError: "SQL join constraint {:?} is not yet supported", constraint);
+}
+
+/// If `l = r` compares a column of `left` with a column of `right`, return the
+/// `(left, right)` join key column names.
+pub(super) fn join_key_pair(
+    l: &SqlExpr,
+    r: &SqlExpr,
+    left: &TableScope,
+    right: &TableScope,
+) -> PolarsResult<Option<(String, String)>> {
+    let (SqlExpr::CompoundIdentifier(l), SqlExpr::CompoundIdentifier(r)) = (l, r) else {
+        return Ok(None);
+    };
+    let ([.., l_tbl, l_col], [.., r_tbl, r_col]) = (l.as_slice(), r.as_slice()) else {
+        return Ok(None);
+    };
+    let (l_tbl, l_col, r_tbl, r_col) = (&l_tbl.value, &l_col.value, &r_tbl.value, &r_col.value);
+    Ok(
+        if left.contains_table(l_tbl) && right.contains_table(r_tbl) {
+            Some((
+                left.resolve(l_tbl, l_col)?.to_string(),
+                right.resolve(r_tbl, r_col)?.to_string(),
+            ))
+        } else if left.contains_table(r_tbl) && right.contains_table(l_tbl) {
+            Some((
+                left.resolve(r_tbl, r_col)?.to_string(),
+                right.resolve(l_tbl, l_col)?.to_string(),
+            ))
+        } else {
+            None
+        },
+    )
 }
@@ -1,32 +1,51 @@
 use std::str::FromStr;
 
 use polars_core::prelude::{polars_bail, PolarsError, PolarsResult};
-#[cfg(feature = "csv")]
-use polars_lazy::prelude::LazyCsvReader;
 use polars_lazy::prelude::LazyFrame;
-use sqlparser::ast::{FunctionArg, FunctionArgExpr};
+use sqlparser::ast::FunctionArg;
 
 /// Table functions that are supported by Polars
+///
+/// All of them take the path of the file(s) to read as first argument, which
+/// may be a glob pattern, followed by optional named arguments that configure the reader.
 #[allow(clippy::enum_variant_names)]
 pub(crate) enum PolarsTableFunctions {
     /// SQL 'read_csv' function
     /// ```sql
     /// SELECT * FROM read_csv('path/to/file.csv')
+    /// SELECT * FROM read_csv('path/to/*.csv', delimiter => ';', has_header => false)
     /// ```
     #[cfg(feature = "csv")]
     ReadCsv,
     /// SQL 'read_parquet' function
     /// ```sql
     /// SELECT * FROM read_parquet('path/to/file.parquet')
+    /// SELECT * FROM read_parquet('path/to/*.parquet', n_rows => 100)
+    /// SELECT * FROM read_parquet('path/to/**/*.parquet', hive_partitioning => true)
     /// ```
     #[cfg(feature = "parquet")]
     ReadParquet,
     /// SQL 'read_ipc' function
     /// ```sql
     /// SELECT * FROM read_ipc('path/to/file.ipc')
+    /// SELECT * FROM read_ipc('path/to/file.ipc', memmap => false)
     /// ```
     #[cfg(feature = "ipc")]
     ReadIpc,
+    /// SQL 'read_ndjson' function
+    /// ```sql
+    /// SELECT * FROM read_ndjson('path/to/file.ndjson')
+    /// SELECT * FROM read_ndjson('path/to/file.ndjson', infer_schema_length => 1000)
+    /// ```
+    #[cfg(feature = "json")]
+    ReadNdjson,
+    /// SQL 'read_avro' function
+    /// ```sql
+    /// SELECT * FROM read_avro('path/to/file.avro')
+    /// SELECT * FROM read_avro('path/to/*.avro', n_rows => 100)
+    /// ```
+    #[cfg(feature = "avro")]
+    ReadAvro,
 }
 
 impl FromStr for PolarsTableFunctions {
@@ -41,6 +60,10 @@ impl FromStr for PolarsTableFunctions {
             "read_parquet" => PolarsTableFunctions::ReadParquet,
             #[cfg(feature = "ipc")]
             "read_ipc" => PolarsTableFunctions::ReadIpc,
+            #[cfg(feature = "json")]
+            "read_ndjson" | "read_json_lines" => PolarsTableFunctions::ReadNdjson,
+            #[cfg(feature = "avro")]
+            "read_avro" => PolarsTableFunctions::ReadAvro,
             _ => polars_bail!(ComputeError: "'{}' is not a supported table function", s),
         })
     }
@@ -56,43 +79,370 @@ impl PolarsTableFunctions {
             PolarsTableFunctions::ReadParquet => self.read_parquet(args),
             #[cfg(feature = "ipc")]
             PolarsTableFunctions::ReadIpc => self.read_ipc(args),
+            #[cfg(feature = "json")]
+            PolarsTableFunctions::ReadNdjson => self.read_ndjson(args),
+            #[cfg(feature = "avro")]
+            PolarsTableFunctions::ReadAvro => self.read_avro(args),
             _ => unreachable!(),
         }
     }
+}
 
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json",
+    feature = "avro"
+))]
+mod readers {
+    use polars_core::prelude::{polars_ensure, polars_err, IdxSize};
     #[cfg(feature = "csv")]
-    fn read_csv(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        use polars_lazy::frame::LazyFileListReader;
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyCsvReader::new(&path).finish()?;
-        Ok((path, lf))
-    }
-
+    use polars_io::csv::{CsvEncoding, NullValues};
     #[cfg(feature = "parquet")]
-    fn read_parquet(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_parquet(&path, Default::default())?;
-        Ok((path, lf))
+    use polars_io::parquet::ParallelStrategy;
+    use polars_io::RowCount;
+    #[cfg(feature = "csv")]
+    use polars_lazy::prelude::LazyCsvReader;
+    #[cfg(feature = "json")]
+    use polars_lazy::prelude::LazyJsonLineReader;
+    #[cfg(feature = "avro")]
+    use polars_lazy::prelude::ScanArgsAvro;
+    #[cfg(feature = "ipc")]
+    use polars_lazy::prelude::ScanArgsIpc;
+    #[cfg(feature = "parquet")]
+    use polars_lazy::prelude::ScanArgsParquet;
+    use sqlparser::ast::{Expr as SqlExpr, FunctionArgExpr, Value as SqlValue};
+
+    use super::*;
+
+    impl PolarsTableFunctions {
+        #[cfg(feature = "csv")]
+        pub(super) fn read_csv(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
+            use polars_lazy::frame::LazyFileListReader;
+
+            let mut args = TableFunctionArgs::try_new(args)?;
+            let mut reader = LazyCsvReader::new(&args.path);
+            if let Some(has_header) = args.bool("has_header")? {
+                reader = reader.has_header(has_header);
+            }
+            if let Some(delimiter) = args.byte("delimiter")? {
+                reader = reader.with_delimiter(delimiter);
+            }
+            if let Some(quote_char) = args.opt_byte("quote_char")? {
+                reader = reader.with_quote_char(quote_char);
+            }
+            if let Some(comment_char) = args.opt_byte("comment_char")? {
+                reader = reader.with_comment_char(comment_char);
+            }
+            if let Some(eol_char) = args.byte("eol_char")? {
+                reader = reader.with_end_of_line_char(eol_char);
+            }
+            if let Some(skip_rows) = args.usize("skip_rows")? {
+                reader = reader.with_skip_rows(skip_rows);
+            }
+            if let Some(offset) = args.usize("skip_rows_after_header")? {
+                reader = reader.with_skip_rows_after_header(offset);
+            }
+            if let Some(n_rows) = args.usize("n_rows")? {
+                reader = reader.with_n_rows(Some(n_rows));
+            }
+            if let Some(infer_schema_length) = args.opt_usize("infer_schema_length")? {
+                reader = reader.with_infer_schema_length(infer_schema_length);
+            }
+            if let Some(ignore_errors) = args.bool("ignore_errors")? {
+                reader = reader.with_ignore_errors(ignore_errors);
+            }
+            if let Some(null_value) = args.string("null_values")? {
+                reader = reader.with_null_values(Some(NullValues::AllColumnsSingle(null_value)));
+            }
+            if let Some(missing_is_null) = args.bool("missing_is_null")? {
+                reader = reader.with_missing_is_null(missing_is_null);
+            }
+            if let Some(try_parse_dates) = args.bool("try_parse_dates")? {
+                reader = reader.with_try_parse_dates(try_parse_dates);
+            }
+            if let Some(encoding) = args.string("encoding")? {
+                let encoding = match encoding.as_str() {
+                    "utf8" => CsvEncoding::Utf8,
+                    "utf8-lossy" => CsvEncoding::LossyUtf8,
+                    _ => polars_bail!(
+                        ComputeError: "encoding must be one of 'utf8' or 'utf8-lossy', got '{}'", encoding
+                    ),
+                };
+                reader = reader.with_encoding(encoding);
+            }
+            if let Some(low_memory) = args.bool("low_memory")? {
+                reader = reader.low_memory(low_memory);
+            }
+            if let Some(cache) = args.bool("cache")? {
+                reader = reader.with_cache(cache);
+            }
+            if let Some(rechunk) = args.bool("rechunk")? {
+                reader = reader.with_rechunk(rechunk);
+            }
+            reader = reader.with_row_count(args.row_count()?);
+            let path = args.finish()?;
+            let lf = reader.finish()?;
+            Ok((path, lf))
+        }
+
+        #[cfg(feature = "parquet")]
+        pub(super) fn read_parquet(
+            &self,
+            args: &[FunctionArg],
+        ) -> PolarsResult<(String, LazyFrame)> {
+            let mut args = TableFunctionArgs::try_new(args)?;
+            let mut scan_args = ScanArgsParquet::default();
+            if let Some(n_rows) = args.usize("n_rows")? {
+                scan_args.n_rows = Some(n_rows);
+            }
+            if let Some(cache) = args.bool("cache")? {
+                scan_args.cache = cache;
+            }
+            if let Some(parallel) = args.string("parallel")? {
+                scan_args.parallel = match parallel.as_str() {
+                    "auto" => ParallelStrategy::Auto,
+                    "columns" => ParallelStrategy::Columns,
+                    "row_groups" => ParallelStrategy::RowGroups,
+                    "none" => ParallelStrategy::None,
+                    _ => polars_bail!(
+                        ComputeError:
+                        "parallel must be one of 'auto', 'columns', 'row_groups' or 'none', got '{}'",
+                        parallel,
+                    ),
+                };
+            }
+            if let Some(rechunk) = args.bool("rechunk")? {
+                scan_args.rechunk = rechunk;
+            }
+            if let Some(low_memory) = args.bool("low_memory")? {
+                scan_args.low_memory = low_memory;
+            }
+            if let Some(use_statistics) = args.bool("use_statistics")? {
+                scan_args.use_statistics = use_statistics;
+            }
+            if let Some(hive_partitioning) = args.bool("hive_partitioning")? {
+                scan_args.hive_partitioning = hive_partitioning;
+            }
+            scan_args.row_count = args.row_count()?;
+            let path = args.finish()?;
+            let lf = LazyFrame::scan_parquet(&path, scan_args)?;
+            Ok((path, lf))
+        }
+
+        #[cfg(feature = "ipc")]
+        pub(super) fn read_ipc(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
+            let mut args = TableFunctionArgs::try_new(args)?;
+            let mut scan_args = ScanArgsIpc::default();
+            if let Some(n_rows) = args.usize("n_rows")? {
+                scan_args.n_rows = Some(n_rows);
+            }
+            if let Some(cache) = args.bool("cache")? {
+                scan_args.cache = cache;
+            }
+            if let Some(rechunk) = args.bool("rechunk")? {
+                scan_args.rechunk = rechunk;
+            }
+            if let Some(memmap) = args.bool("memmap")? {
+                scan_args.memmap = memmap;
+            }
+            if let Some(hive_partitioning) = args.bool("hive_partitioning")? {
+                scan_args.hive_partitioning = hive_partitioning;
+            }
+            scan_args.row_count = args.row_count()?;
+            let path = args.finish()?;
+            let lf = LazyFrame::scan_ipc(&path, scan_args)?;
+            Ok((path, lf))
+        }
+
+        #[cfg(feature = "json")]
+        pub(super) fn read_ndjson(
+            &self,
+            args: &[FunctionArg],
+        ) -> PolarsResult<(String, LazyFrame)> {
+            use polars_lazy::frame::LazyFileListReader;
+
+            let mut args = TableFunctionArgs::try_new(args)?;
+            let mut reader = LazyJsonLineReader::new(args.path.clone());
+            if let Some(n_rows) = args.usize("n_rows")? {
+                reader = reader.with_n_rows(Some(n_rows));
+            }
+            if let Some(infer_schema_length) = args.opt_usize("infer_schema_length")? {
+                reader = reader.with_infer_schema_length(infer_schema_length);
+            }
+            if let Some(batch_size) = args.usize("batch_size")? {
+                reader = reader.with_batch_size(Some(batch_size));
+            }
+            if let Some(low_memory) = args.bool("low_memory")? {
+                reader = reader.low_memory(low_memory);
+            }
+            if let Some(rechunk) = args.bool("rechunk")? {
+                reader = reader.with_rechunk(rechunk);
+            }
+            reader = reader.with_row_count(args.row_count()?);
+            let path = args.finish()?;
+            let lf = reader.finish()?;
+            Ok((path, lf))
+        }
+
+        #[cfg(feature = "avro")]
+        pub(super) fn read_avro(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
+            let mut args = TableFunctionArgs::try_new(args)?;
+            let mut scan_args = ScanArgsAvro::default();
+            if let Some(n_rows) = args.usize("n_rows")? {
+                scan_args.n_rows = Some(n_rows);
+            }
+            if let Some(rechunk) = args.bool("rechunk")? {
+                scan_args.rechunk = rechunk;
+            }
+            scan_args.row_count = args.row_count()?;
+            let path = args.finish()?;
+            let lf = LazyFrame::scan_avro(&path, scan_args)?;
+            Ok((path, lf))
+        }
     }
 
-    #[cfg(feature = "ipc")]
-    fn read_ipc(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_ipc(&path, Default::default())?;
-        Ok((path, lf))
+    /// The arguments of a table function: the path of the file(s) to read followed
+    /// by named options, e.g. `read_csv('file.csv', has_header => false)`.
+    ///
+    /// The readers take the options they know about; any option that is left is
+    /// reported as unsupported by [`TableFunctionArgs::finish`].
+    struct TableFunctionArgs<'a> {
+        path: String,
+        options: Vec<(&'a str, &'a SqlExpr)>,
     }
 
-    #[allow(dead_code)]
-    fn get_file_path_from_arg(&self, arg: &FunctionArg) -> PolarsResult<String> {
-        use sqlparser::ast::{Expr as SqlExpr, Value as SqlValue};
-        match arg {
-            FunctionArg::Unnamed(FunctionArgExpr::Expr(SqlExpr::Value(
-                SqlValue::SingleQuotedString(s),
-            ))) => Ok(s.to_string()),
-            _ => polars_bail!(
-                ComputeError:
-                "only a single quoted string is accepted as the first parameter; received: {}", arg,
-            ),
+    impl<'a> TableFunctionArgs<'a> {
+        fn try_new(args: &'a [FunctionArg]) -> PolarsResult<Self> {
+            let (path, named) = match args {
+                [FunctionArg::Unnamed(FunctionArgExpr::Expr(SqlExpr::Value(
+                    SqlValue::SingleQuotedString(s),
+                ))), named @ ..] => (s.clone(), named),
+                _ => polars_bail!(
+                    ComputeError:
+                    "only a single quoted string is accepted as the first parameter; received: {}",
+                    args.get(0).map_or_else(|| "nothing".to_string(), |arg| arg.to_string()),
+                ),
+            };
+            let mut options: Vec<(&str, &SqlExpr)> = Vec::with_capacity(named.len());
+            for arg in named {
+                match arg {
+                    FunctionArg::Named {
+                        name,
+                        arg: FunctionArgExpr::Expr(expr),
+                    } => {
+                        let name = name.value.as_str();
+                        polars_ensure!(
+                            options.iter().all(|(n, _)| *n != name),
+                            ComputeError: "option '{}' was given more than once", name
+                        );
+                        options.push((name, expr));
+                    }
+                    _ => polars_bail!(
+                        ComputeError:
+                        "only named arguments such as `name => value` may follow the path; received: {}",
+                        arg,
+                    ),
+                }
+            }
+            Ok(Self { path, options })
+        }
+
+        /// Take the option `name` and convert its value with `f`.
+        fn take<T>(
+            &mut self,
+            name: &str,
+            expected: &str,
+            f: impl Fn(&SqlValue) -> Option<T>,
+        ) -> PolarsResult<Option<T>> {
+            let Some(idx) = self.options.iter().position(|(n, _)| *n == name) else {
+                return Ok(None);
+            };
+            let (_, expr) = self.options.remove(idx);
+            let value = match expr {
+                SqlExpr::Value(value) => f(value),
+                _ => None,
+            };
+            value.map(Some).ok_or_else(|| {
+                polars_err!(ComputeError: "option '{}' expects {}; received: {}", name, expected, expr)
+            })
+        }
+
+        fn bool(&mut self, name: &str) -> PolarsResult<Option<bool>> {
+            self.take(name, "a boolean", |v| match v {
+                SqlValue::Boolean(b) => Some(*b),
+                _ => None,
+            })
+        }
+
+        fn usize(&mut self, name: &str) -> PolarsResult<Option<usize>> {
+            self.take(name, "a non-negative integer", |v| match v {
+                SqlValue::Number(s, _) => s.parse().ok(),
+                _ => None,
+            })
+        }
+
+        /// A `usize` option that may be disabled with `NULL`.
+        #[cfg(any(feature = "csv", feature = "json"))]
+        fn opt_usize(&mut self, name: &str) -> PolarsResult<Option<Option<usize>>> {
+            self.take(name, "a non-negative integer or NULL", |v| match v {
+                SqlValue::Number(s, _) => s.parse().ok().map(Some),
+                SqlValue::Null => Some(None),
+                _ => None,
+            })
+        }
+
+        fn string(&mut self, name: &str) -> PolarsResult<Option<String>> {
+            self.take(name, "a single quoted string", |v| match v {
+                SqlValue::SingleQuotedString(s) => Some(s.clone()),
+                _ => None,
+            })
+        }
+
+        #[cfg(feature = "csv")]
+        fn byte(&mut self, name: &str) -> PolarsResult<Option<u8>> {
+            self.take(name, "a single byte character", |v| match v {
+                SqlValue::SingleQuotedString(s) if s.len() == 1 => Some(s.as_bytes()[0]),
+                _ => None,
+            })
+        }
+
+        /// A single byte option that may be disabled with `NULL`.
+        #[cfg(feature = "csv")]
+        fn opt_byte(&mut self, name: &str) -> PolarsResult<Option<Option<u8>>> {
+            self.take(name, "a single byte character or NULL", |v| match v {
+                SqlValue::SingleQuotedString(s) if s.len() == 1 => Some(Some(s.as_bytes()[0])),
+                SqlValue::Null => Some(None),
+                _ => None,
+            })
+        }
+
+        /// The `row_count_name` and `row_count_offset` options.
+        fn row_count(&mut self) -> PolarsResult<Option<RowCount>> {
+            let name = self.string("row_count_name")?;
+            let offset = self.usize("row_count_offset")?;
+            match name {
+                Some(name) => Ok(Some(RowCount {
+                    name,
+                    offset: offset.unwrap_or(0) as IdxSize,
+                })),
+                None => {
+                    polars_ensure!(
+                        offset.is_none(),
+                        ComputeError: "option 'row_count_offset' requires 'row_count_name'"
+                    );
+                    Ok(None)
+                }
+            }
+        }
+
+        /// Check that all options were used and return the path.
+        fn finish(self) -> PolarsResult<String> {
+            if let Some((name, _)) = self.options.first() {
+                polars_bail!(ComputeError: "'{}' is not a supported option of this table function", name);
+            }
+            Ok(self.path)
         }
     }
 }
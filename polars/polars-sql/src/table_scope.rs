@@ -0,0 +1,103 @@
+use polars_core::prelude::*;
+
+/// Suffix that `join` appends to right-hand columns that clash with a left-hand column.
+pub(crate) const JOIN_SUFFIX: &str = "_right";
+
+/// The relations that are visible to a `SELECT`.
+///
+/// Maps every table name (or alias) in the `FROM` clause to its columns and
+/// the names those columns have in the frame that is produced by the joins,
+/// so qualified references like `tbl.column` can be resolved.
+#[derive(Default, Clone)]
+pub(crate) struct TableScope {
+    // (table name or alias, [(column name, column name in the joined frame)])
+    tables: Vec<(String, Vec<(String, String)>)>,
+}
+
+impl TableScope {
+    /// Scope of a single relation with the given schema.
+    pub(crate) fn new(name: &str, schema: &Schema) -> Self {
+        let columns = schema
+            .iter_names()
+            .map(|c| (c.to_string(), c.to_string()))
+            .collect();
+        Self {
+            tables: vec![(name.to_string(), columns)],
+        }
+    }
+
+    pub(crate) fn contains_table(&self, table: &str) -> bool {
+        self.table(table).is_some()
+    }
+
+    /// Resolve `table.column` to the name of the column in the joined frame.
+    pub(crate) fn resolve(&self, table: &str, column: &str) -> PolarsResult<&str> {
+        let columns = self.table(table).ok_or_else(
+            || polars_err!(ComputeError: "no table or alias named '{}' in scope", table),
+        )?;
+        columns
+            .iter()
+            .find(|(c, _)| c == column)
+            .map(|(_, name)| name.as_str())
+            .ok_or_else(|| {
+                polars_err!(ColumnNotFound: "column '{}' not found in table '{}'", column, table)
+            })
+    }
+
+    /// The names of all columns of `table` in the joined frame, e.g. for `tbl.*`.
+    pub(crate) fn columns(&self, table: &str) -> PolarsResult<Vec<String>> {
+        let columns = self.table(table).ok_or_else(
+            || polars_err!(ComputeError: "no table or alias named '{}' in scope", table),
+        )?;
+        Ok(columns.iter().map(|(_, name)| name.clone()).collect())
+    }
+
+    /// Refer to the column `from` of the frame by `to`, e.g. after it is copied under that name.
+    pub(crate) fn rename(&mut self, from: &str, to: &str) {
+        for (_, columns) in &mut self.tables {
+            for (_, in_frame) in columns.iter_mut().filter(|(_, f)| f == from) {
+                *in_frame = to.to_string();
+            }
+        }
+    }
+
+    /// Add the relations of `right` after it has been joined onto the frame
+    /// described by this scope.
+    ///
+    /// `keys` holds the `(left, right)` column names of an inner join; the right-hand
+    /// keys are dropped by the join, so they resolve to the left-hand keys. Other
+    /// right-hand columns that clash with a left-hand column get the join suffix.
+    pub(crate) fn join(
+        &mut self,
+        right: TableScope,
+        left_schema: &Schema,
+        keys: &[(String, String)],
+    ) {
+        let rename = |column: &str| -> String {
+            if let Some((left_key, _)) = keys.iter().find(|(_, r)| r == column) {
+                left_key.clone()
+            } else if left_schema.contains(column) {
+                format!("{column}{JOIN_SUFFIX}")
+            } else {
+                column.to_string()
+            }
+        };
+        for (name, columns) in right.tables {
+            let columns = columns
+                .into_iter()
+                .map(|(c, in_frame)| {
+                    let in_frame = rename(&in_frame);
+                    (c, in_frame)
+                })
+                .collect();
+            self.tables.push((name, columns));
+        }
+    }
+
+    fn table(&self, table: &str) -> Option<&[(String, String)]> {
+        self.tables
+            .iter()
+            .find(|(name, _)| name == table)
+            .map(|(_, columns)| columns.as_slice())
+    }
+}
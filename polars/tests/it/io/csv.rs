@@ -1,6 +1,7 @@
 use std::io::Cursor;
 
 use polars::io::RowCount;
+use tempdir::TempDir;
 
 use super::*;
 
@@ -27,6 +28,99 @@ fn write_csv() {
     assert_eq!("0,22.1\n1,19.9\n2,7.0\n3,2.0\n4,3.0\n", csv);
 }
 
+#[test]
+fn write_csv_quote_style() -> PolarsResult<()> {
+    let mut df = df![
+        "int" => [Some(1), None],
+        "str" => ["a", "b,c"],
+        "bool" => [true, false],
+    ]?;
+    let mut write = |quote_style| -> PolarsResult<String> {
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .with_quote_style(quote_style)
+            .finish(&mut df)?;
+        Ok(String::from_utf8(buf).unwrap())
+    };
+
+    assert_eq!(
+        write(QuoteStyle::Necessary)?,
+        "int,str,bool\n1,a,true\n,\"b,c\",false\n"
+    );
+    assert_eq!(
+        write(QuoteStyle::Always)?,
+        "\"int\",\"str\",\"bool\"\n\"1\",\"a\",\"true\"\n,\"b,c\",\"false\"\n"
+    );
+    assert_eq!(
+        write(QuoteStyle::NonNumeric)?,
+        "\"int\",\"str\",\"bool\"\n1,\"a\",\"true\"\n,\"b,c\",\"false\"\n"
+    );
+    assert_eq!(
+        write(QuoteStyle::Never)?,
+        "int,str,bool\n1,a,true\n,b,c,false\n"
+    );
+    Ok(())
+}
+
+#[test]
+fn write_csv_line_terminator_bom_and_modes() -> PolarsResult<()> {
+    let mut df = df!["a" => [1i64, 2], "b" => ["x", "y"]]?;
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .with_line_terminator("\r\n".into())
+        .include_bom(true)
+        .finish(&mut df)?;
+    assert_eq!(buf, b"\xEF\xBB\xBFa,b\r\n1,x\r\n2,y\r\n");
+    // the reader skips the byte order mark
+    let out = CsvReader::new(Cursor::new(buf)).finish()?;
+    assert!(out.frame_equal(&df));
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .include_bom(true)
+        .header_only(true)
+        .finish(&mut df)?;
+    for _ in 0..2 {
+        CsvWriter::new(&mut buf)
+            .include_bom(true)
+            .append(true)
+            .finish(&mut df)?;
+    }
+    assert_eq!(buf, b"\xEF\xBB\xBFa,b\n1,x\n2,y\n1,x\n2,y\n");
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "decompress")]
+fn write_csv_compressed() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..1000).collect::<Vec<i64>>(),
+        "b" => (0..1000).map(|i| format!("value {i}")).collect::<Vec<_>>(),
+    ]?;
+
+    for compression in [CsvCompression::Gzip(None), CsvCompression::Zstd(Some(3))] {
+        let mut buf: Vec<u8> = Vec::new();
+        let mut writer = CsvWriter::new(&mut buf)
+            .with_compression(compression)
+            .batched(&df.schema())?;
+        writer.write_batch(&df.slice(0, 500))?;
+        writer.write_batch(&df.slice(500, 500))?;
+        writer.finish()?;
+        assert!(!buf.starts_with(b"a,b"));
+
+        let out = CsvReader::new(Cursor::new(buf)).finish()?;
+        assert!(out.frame_equal(&df));
+    }
+
+    let mut buf: Vec<u8> = Vec::new();
+    let result = CsvWriter::new(&mut buf)
+        .with_compression(CsvCompression::Gzip(Some(10)))
+        .finish(&mut df.clone());
+    assert!(result.is_err());
+    Ok(())
+}
+
 #[test]
 fn test_read_csv_file() {
     let file = std::fs::File::open(FOODS_CSV).unwrap();
@@ -1093,3 +1187,102 @@ fn test_leading_whitespace_with_quote() -> PolarsResult<()> {
     assert_eq!(col_2.get(0)?, AnyValue::Float64(4.1));
     Ok(())
 }
+
+#[test]
+fn test_multi_char_delimiter() -> PolarsResult<()> {
+    let csv = "a||b||c\n1||\"x||y\"||3\n2||z|w||\n";
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_multi_char_delimiter("||")
+        .finish()?;
+    let expected = df![
+        "a" => [1i64, 2],
+        "b" => ["x||y", "z|w"],
+        "c" => [Some(3i64), None],
+    ]?;
+    assert!(df.frame_equal_missing(&expected));
+
+    let csv = "a\t|\tb\n1\t|\t2\n";
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_multi_char_delimiter("\t|\t")
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["a", "b"]);
+    assert_eq!(df.column("b")?.get(0)?, AnyValue::Int64(2));
+    Ok(())
+}
+
+#[test]
+fn test_multiple_null_values_per_column() -> PolarsResult<()> {
+    let csv = "a,b\nNA,x\n-,y\n1,NA\n";
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_null_values(Some(NullValues::Named(vec![
+            ("a".into(), "NA".into()),
+            ("a".into(), "-".into()),
+        ])))
+        .finish()?;
+    let expected = df![
+        "a" => [None, None, Some(1i64)],
+        "b" => ["x", "y", "NA"],
+    ]?;
+    assert!(df.frame_equal_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_truncated_rows() -> PolarsResult<()> {
+    let mut csv = String::from("a,b,c\n");
+    for i in 0..10_000 {
+        if i % 2 == 0 {
+            csv.push_str(&format!("{i},x\n"));
+        } else {
+            csv.push_str(&format!("{i},x,y\n"));
+        }
+    }
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file).with_truncated_rows(true).finish()?;
+    assert_eq!(df.shape(), (10_000, 3));
+    assert_eq!(df.column("c")?.null_count(), 5_000);
+    assert_eq!(df.column("a")?.i64()?.get(9_999), Some(9_999));
+    Ok(())
+}
+
+#[test]
+fn test_empty_multi_char_delimiter() {
+    let file = Cursor::new("a,b\n1,2\n");
+    let res = CsvReader::new(file).with_multi_char_delimiter("").finish();
+    assert!(matches!(res, Err(PolarsError::InvalidOperation(_))));
+}
+
+#[test]
+fn test_batched_quoted_new_lines() -> PolarsResult<()> {
+    let mut csv = String::from("a,b,c\n");
+    for i in 0..1000 {
+        csv.push_str(&format!("{i},\"x\ny,\"\"z\"\"\n\",{i}\n"));
+    }
+    let tempdir = TempDir::new("batched_quoted_new_lines")?;
+    let path = tempdir.path().join("data.csv");
+    std::fs::write(&path, csv)?;
+    let expected = CsvReader::from_path(&path)?.finish()?;
+    assert_eq!(expected.shape(), (1000, 3));
+
+    let mut reader = CsvReader::from_path(&path)?.with_chunk_size(10);
+    let mut batched = reader.batched_borrowed_mmap()?;
+    let mut batches = vec![];
+    while let Some(dfs) = batched.next_batches(4)? {
+        batches.extend(dfs)
+    }
+    assert!(batches.len() > 1);
+    assert!(polars_core::utils::accumulate_dataframes_vertical(batches)?.frame_equal(&expected));
+
+    let mut reader = CsvReader::from_path(&path)?.with_chunk_size(10);
+    let mut batched = reader.batched_borrowed_read()?;
+    let mut batches = vec![];
+    while let Some(dfs) = batched.next_batches(4)? {
+        batches.extend(dfs)
+    }
+    assert!(batches.len() > 1);
+    assert!(polars_core::utils::accumulate_dataframes_vertical(batches)?.frame_equal(&expected));
+    Ok(())
+}
@@ -212,3 +212,26 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+fn test_write_ndjson_batched() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1i64), None, Some(3)],
+        "b" => ["x", "y\nz", "w"],
+    ]?;
+    let mut buf = vec![];
+    let mut writer = JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::JsonLines)
+        .batched(&df.schema())?;
+    writer.write_batch(&df.slice(0, 2))?;
+    writer.write_batch(&df.slice(2, 1))?;
+    writer.finish()?;
+
+    let out = JsonLineReader::new(Cursor::new(buf)).finish()?;
+    assert!(out.frame_equal_missing(&df));
+
+    // only JSON Lines can be written in batches
+    let writer = JsonWriter::new(vec![]).with_json_format(JsonFormat::Json);
+    assert!(writer.batched(&df.schema()).is_err());
+    Ok(())
+}
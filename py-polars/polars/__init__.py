@@ -49,6 +49,7 @@ from polars.datatypes import (
 )
 from polars.exceptions import (
     ArrowError,
+    CancelledError,
     ColumnNotFoundError,
     ComputeError,
     DuplicateError,
@@ -170,6 +171,7 @@ __all__ = [
     "exceptions",
     # exceptions/errors
     "ArrowError",
+    "CancelledError",
     "ColumnNotFoundError",
     "ComputeError",
     "DuplicateError",
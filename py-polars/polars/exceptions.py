@@ -1,6 +1,7 @@
 try:
     from polars.polars import (
         ArrowError,
+        CancelledError,
         ColumnNotFoundError,
         ComputeError,
         DuplicateError,
@@ -19,6 +20,9 @@ except ImportError:
     class ArrowError(Exception):  # type: ignore[no-redef]
         """Exception raised the underlying Arrow library encounters an error."""
 
+    class CancelledError(Exception):  # type: ignore[no-redef]
+        """Exception raised when a query is cancelled before it finished."""
+
     class ColumnNotFoundError(Exception):  # type: ignore[no-redef]
         """Exception raised when a specified column is not found."""
 
@@ -68,6 +72,7 @@ class TooManyRowsReturned(RowsException):
 
 __all__ = [
     "ArrowError",
+    "CancelledError",
     "ColumnNotFoundError",
     "ComputeError",
     "DuplicateError",
@@ -145,6 +145,8 @@ def scan_ipc(
     row_count_offset: int = 0,
     storage_options: dict[str, Any] | None = None,
     memory_map: bool = True,
+    *,
+    hive_partitioning: bool = False,
 ) -> LazyFrame:
     """
     Lazily read from an Arrow IPC (Feather v2) file or multiple files via glob patterns.
@@ -175,6 +177,10 @@ def scan_ipc(
         Try to memory map the file. This can greatly improve performance on repeated
         queries as the OS may cache pages.
         Only uncompressed IPC files can be memory mapped.
+    hive_partitioning
+        Infer the values of hive partition keys from the ``key=value`` directories
+        in the paths of the files, and add them as columns. Filters on these
+        columns skip the files of the partitions that they exclude.
 
     """
     return pli.LazyFrame._scan_ipc(
@@ -186,4 +192,5 @@ def scan_ipc(
         row_count_offset=row_count_offset,
         storage_options=storage_options,
         memory_map=memory_map,
+        hive_partitioning=hive_partitioning,
     )
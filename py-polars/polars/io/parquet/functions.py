@@ -173,6 +173,7 @@ def scan_parquet(
     low_memory: bool = False,
     *,
     use_statistics: bool = True,
+    hive_partitioning: bool = False,
 ) -> LazyFrame:
     """
     Lazily read from a parquet file or multiple files via glob patterns.
@@ -208,6 +209,10 @@ def scan_parquet(
     use_statistics
         Use statistics in the parquet to determine if pages
         can be skipped from reading.
+    hive_partitioning
+        Infer the values of hive partition keys from the ``key=value`` directories
+        in the paths of the files, and add them as columns. Filters on these
+        columns skip the files of the partitions that they exclude.
 
     """
     if isinstance(source, (str, Path)):
@@ -224,4 +229,5 @@ def scan_parquet(
         storage_options=storage_options,
         low_memory=low_memory,
         use_statistics=use_statistics,
+        hive_partitioning=hive_partitioning,
     )
@@ -381,6 +381,7 @@ class LazyFrame:
         storage_options: dict[str, object] | None = None,
         low_memory: bool = False,
         use_statistics: bool = True,
+        hive_partitioning: bool = False,
     ) -> Self:
         """
         Lazily read from a parquet file or multiple files via glob patterns.
@@ -412,6 +413,7 @@ class LazyFrame:
             low_memory,
             cloud_options=storage_options,
             use_statistics=use_statistics,
+            hive_partitioning=hive_partitioning,
         )
         return self
 
@@ -426,6 +428,7 @@ class LazyFrame:
         row_count_offset: int = 0,
         storage_options: dict[str, object] | None = None,
         memory_map: bool = True,
+        hive_partitioning: bool = False,
     ) -> Self:
         """
         Lazily read from an Arrow IPC (Feather v2) file.
@@ -457,6 +460,7 @@ class LazyFrame:
             rechunk,
             _prepare_row_count_args(row_count_name, row_count_offset),
             memory_map=memory_map,
+            hive_partitioning=hive_partitioning,
         )
         return self
 
@@ -1484,6 +1488,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         row_group_size: int | None = None,
         data_pagesize_limit: int | None = None,
         maintain_order: bool = True,
+        partition_by: str | Sequence[str] | None = None,
+        max_rows_per_file: int | None = None,
+        max_bytes_per_file: int | None = None,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -1527,6 +1534,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
+        partition_by
+            Write to a directory at ``path`` with a ``key=value`` subdirectory for
+            every combination of values in these columns, as read by
+            ``hive_partitioning`` of the scan functions.
+        max_rows_per_file
+            Start a new file in ``path`` (or in a partition) after this many rows.
+        max_bytes_per_file
+            Start a new file in ``path`` (or in a partition) after this many bytes.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -1572,6 +1587,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             row_group_size=row_group_size,
             data_pagesize_limit=data_pagesize_limit,
             maintain_order=maintain_order,
+            partition_by=[partition_by]
+            if isinstance(partition_by, str)
+            else partition_by,
+            max_rows_per_file=max_rows_per_file,
+            max_bytes_per_file=max_bytes_per_file,
         )
 
     def sink_ipc(
@@ -1580,6 +1600,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         *,
         compression: str | None = "zstd",
         maintain_order: bool = True,
+        partition_by: str | Sequence[str] | None = None,
+        max_rows_per_file: int | None = None,
+        max_bytes_per_file: int | None = None,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -1602,6 +1625,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
+        partition_by
+            Write to a directory at ``path`` with a ``key=value`` subdirectory for
+            every combination of values in these columns, as read by
+            ``hive_partitioning`` of the scan functions.
+        max_rows_per_file
+            Start a new file in ``path`` (or in a partition) after this many rows.
+        max_bytes_per_file
+            Start a new file in ``path`` (or in a partition) after this many bytes.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -1643,8 +1674,192 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             path=path,
             compression=compression,
             maintain_order=maintain_order,
+            partition_by=[partition_by]
+            if isinstance(partition_by, str)
+            else partition_by,
+            max_rows_per_file=max_rows_per_file,
+            max_bytes_per_file=max_bytes_per_file,
         )
 
+    def sink_csv(
+        self,
+        path: str | Path,
+        *,
+        has_header: bool = True,
+        separator: str = ",",
+        quote: str = '"',
+        batch_size: int = 1024,
+        datetime_format: str | None = None,
+        date_format: str | None = None,
+        time_format: str | None = None,
+        float_precision: int | None = None,
+        null_value: str | None = None,
+        maintain_order: bool = True,
+        type_coercion: bool = True,
+        predicate_pushdown: bool = True,
+        projection_pushdown: bool = True,
+        simplify_expression: bool = True,
+        no_optimization: bool = False,
+        slice_pushdown: bool = True,
+    ) -> DataFrame:
+        """
+        Persists a LazyFrame at the provided path as a CSV file.
+
+        This allows streaming results that are larger than RAM to be written to disk.
+
+        Parameters
+        ----------
+        path
+            File path to which the file should be written.
+        has_header
+            Whether to include header in the CSV output.
+        separator
+            Separate CSV fields with this symbol.
+        quote
+            Byte to use as quoting character.
+        batch_size
+            Number of rows that will be processed per thread.
+        datetime_format
+            A format string, with the specifiers defined by the
+            `chrono <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
+            Rust crate.
+        date_format
+            A format string, with the specifiers defined by the
+            `chrono <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
+            Rust crate.
+        time_format
+            A format string, with the specifiers defined by the
+            `chrono <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
+            Rust crate.
+        float_precision
+            Number of decimal places to write, applied to both ``Float32`` and
+            ``Float64`` datatypes.
+        null_value
+            A string representing null values (defaulting to the empty string).
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will  be slightly faster.
+        type_coercion
+            Do type coercion optimization.
+        predicate_pushdown
+            Do predicate pushdown optimization.
+        projection_pushdown
+            Do projection pushdown optimization.
+        simplify_expression
+            Run simplify expressions optimization.
+        no_optimization
+            Turn off (certain) optimizations.
+        slice_pushdown
+            Slice pushdown optimization.
+
+        Returns
+        -------
+        DataFrame
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> lf.sink_csv("out.csv")  # doctest: +SKIP
+
+        """
+        if len(separator) > 1:
+            raise ValueError("only single byte separator is allowed")
+        elif len(quote) > 1:
+            raise ValueError("only single byte quote char is allowed")
+        elif null_value == "":
+            null_value = None
+
+        if no_optimization:
+            predicate_pushdown = False
+            projection_pushdown = False
+            slice_pushdown = False
+
+        lf = self._ldf.optimization_toggle(
+            type_coercion,
+            predicate_pushdown,
+            projection_pushdown,
+            simplify_expression,
+            slice_pushdown,
+            cse=False,
+            streaming=True,
+        )
+        return lf.sink_csv(
+            path=path,
+            has_header=has_header,
+            separator=ord(separator),
+            quote=ord(quote),
+            batch_size=batch_size,
+            datetime_format=datetime_format,
+            date_format=date_format,
+            time_format=time_format,
+            float_precision=float_precision,
+            null_value=null_value,
+            maintain_order=maintain_order,
+        )
+
+    def sink_ndjson(
+        self,
+        path: str | Path,
+        *,
+        maintain_order: bool = True,
+        type_coercion: bool = True,
+        predicate_pushdown: bool = True,
+        projection_pushdown: bool = True,
+        simplify_expression: bool = True,
+        no_optimization: bool = False,
+        slice_pushdown: bool = True,
+    ) -> DataFrame:
+        """
+        Persists a LazyFrame at the provided path as a newline delimited JSON file.
+
+        This allows streaming results that are larger than RAM to be written to disk.
+
+        Parameters
+        ----------
+        path
+            File path to which the file should be written.
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will  be slightly faster.
+        type_coercion
+            Do type coercion optimization.
+        predicate_pushdown
+            Do predicate pushdown optimization.
+        projection_pushdown
+            Do projection pushdown optimization.
+        simplify_expression
+            Run simplify expressions optimization.
+        no_optimization
+            Turn off (certain) optimizations.
+        slice_pushdown
+            Slice pushdown optimization.
+
+        Returns
+        -------
+        DataFrame
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> lf.sink_ndjson("out.ndjson")  # doctest: +SKIP
+
+        """
+        if no_optimization:
+            predicate_pushdown = False
+            projection_pushdown = False
+            slice_pushdown = False
+
+        lf = self._ldf.optimization_toggle(
+            type_coercion,
+            predicate_pushdown,
+            projection_pushdown,
+            simplify_expression,
+            slice_pushdown,
+            cse=False,
+            streaming=True,
+        )
+        return lf.sink_ndjson(path=path, maintain_order=maintain_order)
+
     def fetch(
         self,
         n_rows: int = 500,
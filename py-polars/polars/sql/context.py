@@ -41,6 +41,22 @@ class SQLContext:
         """
         self._ctxt.register(name, lf._ldf)
 
+    def unregister(self, name: str) -> None:
+        """
+        Remove the table registered under ``name`` from this ``SQLContext``.
+
+        Parameters
+        ----------
+        name
+            Name of the table
+
+        """
+        self._ctxt.unregister(name)
+
+    def tables(self) -> list[str]:
+        """Return the names of the registered tables, in sorted order."""
+        return self._ctxt.get_tables()
+
     def execute(self, query: str) -> LazyFrame:
         """
         Parse the givens SQL query and transform that to a ``LazyFrame``.
@@ -4,7 +4,7 @@ use std::io::Error;
 use polars::prelude::PolarsError;
 use polars_core::error::ArrowError;
 use pyo3::create_exception;
-use pyo3::exceptions::{PyException, PyIOError, PyRuntimeError};
+use pyo3::exceptions::{PyException, PyIOError, PyRuntimeError, PyTimeoutError};
 use pyo3::prelude::*;
 use thiserror::Error;
 
@@ -32,6 +32,7 @@ impl std::convert::From<PyPolarsErr> for PyErr {
         match &err {
             Polars(err) => match err {
                 PolarsError::ArrowError(err) => ArrowErrorException::new_err(format!("{err:?}")),
+                PolarsError::Cancelled(err) => CancelledError::new_err(err.to_string()),
                 PolarsError::ColumnNotFound(name) => ColumnNotFoundError::new_err(name.to_string()),
                 PolarsError::ComputeError(err) => ComputeError::new_err(err.to_string()),
                 PolarsError::Duplicate(err) => DuplicateError::new_err(err.to_string()),
@@ -48,6 +49,7 @@ impl std::convert::From<PyPolarsErr> for PyErr {
                 PolarsError::StructFieldNotFound(name) => {
                     StructFieldNotFoundError::new_err(name.to_string())
                 }
+                PolarsError::Timeout(err) => PyTimeoutError::new_err(err.to_string()),
             },
             Arrow(err) => ArrowErrorException::new_err(format!("{err:?}")),
             _ => default(),
@@ -67,6 +69,7 @@ impl Debug for PyPolarsErr {
 }
 
 create_exception!(exceptions, ArrowErrorException, PyException);
+create_exception!(exceptions, CancelledError, PyException);
 create_exception!(exceptions, ColumnNotFoundError, PyException);
 create_exception!(exceptions, ComputeError, PyException);
 create_exception!(exceptions, DuplicateError, PyException);
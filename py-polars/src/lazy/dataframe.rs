@@ -46,6 +46,23 @@ fn extract_cloud_options(url: &str, py_object: PyObject) -> PyResult<cloud::Clou
     )
 }
 
+/// Partition the output of a sink if any of the partitioning options is set.
+#[cfg(feature = "streaming")]
+fn sink_partition_options(
+    partition_by: Option<Vec<String>>,
+    max_rows_per_file: Option<usize>,
+    max_bytes_per_file: Option<u64>,
+) -> Option<SinkPartitionOptions> {
+    if partition_by.is_none() && max_rows_per_file.is_none() && max_bytes_per_file.is_none() {
+        return None;
+    }
+    Some(SinkPartitionOptions {
+        by: partition_by.unwrap_or_default(),
+        max_rows_per_file,
+        max_bytes_per_file,
+    })
+}
+
 #[pymethods]
 impl PyLazyGroupBy {
     pub fn agg(&mut self, aggs: Vec<PyExpr>) -> PyLazyFrame {
@@ -286,7 +303,7 @@ impl PyLazyFrame {
     #[staticmethod]
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (path, n_rows, cache, parallel, rechunk, row_count,
-        low_memory, cloud_options, use_statistics)
+        low_memory, cloud_options, use_statistics, hive_partitioning)
     )]
     pub fn new_from_parquet(
         path: String,
@@ -298,6 +315,7 @@ impl PyLazyFrame {
         low_memory: bool,
         cloud_options: Option<PyObject>,
         use_statistics: bool,
+        hive_partitioning: bool,
     ) -> PyResult<Self> {
         let cloud_options = cloud_options
             .map(|po| extract_cloud_options(&path, po))
@@ -312,6 +330,7 @@ impl PyLazyFrame {
             low_memory,
             cloud_options,
             use_statistics,
+            hive_partitioning,
         };
         let lf = LazyFrame::scan_parquet(path, args).map_err(PyPolarsErr::from)?;
         Ok(lf.into())
@@ -319,7 +338,7 @@ impl PyLazyFrame {
 
     #[cfg(feature = "ipc")]
     #[staticmethod]
-    #[pyo3(signature = (path, n_rows, cache, rechunk, row_count, memory_map))]
+    #[pyo3(signature = (path, n_rows, cache, rechunk, row_count, memory_map, hive_partitioning))]
     pub fn new_from_ipc(
         path: String,
         n_rows: Option<usize>,
@@ -327,6 +346,7 @@ impl PyLazyFrame {
         rechunk: bool,
         row_count: Option<(String, IdxSize)>,
         memory_map: bool,
+        hive_partitioning: bool,
     ) -> PyResult<Self> {
         let row_count = row_count.map(|(name, offset)| RowCount { name, offset });
         let args = ScanArgsIpc {
@@ -335,6 +355,7 @@ impl PyLazyFrame {
             rechunk,
             row_count,
             memmap: memory_map,
+            hive_partitioning,
         };
         let lf = LazyFrame::scan_ipc(path, args).map_err(PyPolarsErr::from)?;
         Ok(lf.into())
@@ -467,7 +488,7 @@ impl PyLazyFrame {
 
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "streaming")]
-    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_pagesize_limit, maintain_order))]
+    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_pagesize_limit, maintain_order, partition_by, max_rows_per_file, max_bytes_per_file))]
     pub fn sink_parquet(
         &self,
         py: Python,
@@ -478,6 +499,9 @@ impl PyLazyFrame {
         row_group_size: Option<usize>,
         data_pagesize_limit: Option<usize>,
         maintain_order: bool,
+        partition_by: Option<Vec<String>>,
+        max_rows_per_file: Option<usize>,
+        max_bytes_per_file: Option<u64>,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
 
@@ -487,37 +511,109 @@ impl PyLazyFrame {
             row_group_size,
             data_pagesize_limit,
             maintain_order,
+            ..Default::default()
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
         // threads we deadlock.
+        let partitioning =
+            sink_partition_options(partition_by, max_rows_per_file, max_bytes_per_file);
         py.allow_threads(|| {
             let ldf = self.ldf.clone();
-            ldf.sink_parquet(path, options).map_err(PyPolarsErr::from)
+            match partitioning {
+                Some(partitioning) => ldf.sink_parquet_partitioned(path, partitioning, options),
+                None => ldf.sink_parquet(path, options),
+            }
+            .map_err(PyPolarsErr::from)
         })?;
         Ok(())
     }
 
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "streaming")]
-    #[pyo3(signature = (path, compression, maintain_order))]
+    #[pyo3(signature = (path, compression, maintain_order, partition_by, max_rows_per_file, max_bytes_per_file))]
     pub fn sink_ipc(
         &self,
         py: Python,
         path: PathBuf,
         compression: Option<Wrap<IpcCompression>>,
         maintain_order: bool,
+        partition_by: Option<Vec<String>>,
+        max_rows_per_file: Option<usize>,
+        max_bytes_per_file: Option<u64>,
     ) -> PyResult<()> {
         let options = IpcWriterOptions {
             compression: compression.map(|c| c.0),
             maintain_order,
         };
 
+        // if we don't allow threads and we have udfs trying to acquire the gil from different
+        // threads we deadlock.
+        let partitioning =
+            sink_partition_options(partition_by, max_rows_per_file, max_bytes_per_file);
+        py.allow_threads(|| {
+            let ldf = self.ldf.clone();
+            match partitioning {
+                Some(partitioning) => ldf.sink_ipc_partitioned(path, partitioning, options),
+                None => ldf.sink_ipc(path, options),
+            }
+            .map_err(PyPolarsErr::from)
+        })?;
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[cfg(all(feature = "streaming", feature = "csv-file"))]
+    #[pyo3(signature = (path, has_header, separator, quote, batch_size, datetime_format, date_format, time_format, float_precision, null_value, maintain_order))]
+    pub fn sink_csv(
+        &self,
+        py: Python,
+        path: PathBuf,
+        has_header: bool,
+        separator: u8,
+        quote: u8,
+        batch_size: usize,
+        datetime_format: Option<String>,
+        date_format: Option<String>,
+        time_format: Option<String>,
+        float_precision: Option<usize>,
+        null_value: Option<String>,
+        maintain_order: bool,
+    ) -> PyResult<()> {
+        let options = CsvWriterOptions {
+            include_header: has_header,
+            batch_size,
+            maintain_order,
+            serialize_options: SerializeOptions {
+                date_format,
+                time_format,
+                datetime_format,
+                float_precision,
+                delimiter: separator,
+                quote,
+                null: null_value.unwrap_or_default(),
+                ..Default::default()
+            },
+        };
+
+        // if we don't allow threads and we have udfs trying to acquire the gil from different
+        // threads we deadlock.
+        py.allow_threads(|| {
+            let ldf = self.ldf.clone();
+            ldf.sink_csv(path, options).map_err(PyPolarsErr::from)
+        })?;
+        Ok(())
+    }
+
+    #[cfg(all(feature = "streaming", feature = "json"))]
+    pub fn sink_ndjson(&self, py: Python, path: PathBuf, maintain_order: bool) -> PyResult<()> {
+        let options = JsonWriterOptions { maintain_order };
+
         // if we don't allow threads and we have udfs trying to acquire the gil from different
         // threads we deadlock.
         py.allow_threads(|| {
             let ldf = self.ldf.clone();
-            ldf.sink_ipc(path, options).map_err(PyPolarsErr::from)
+            ldf.sink_ndjson(path, options).map_err(PyPolarsErr::from)
         })?;
         Ok(())
     }
@@ -54,8 +54,9 @@ use pyo3::wrap_pyfunction;
 use crate::conversion::{get_df, get_lf, get_series, Wrap};
 use crate::dataframe::PyDataFrame;
 use crate::error::{
-    ArrowErrorException, ColumnNotFoundError, ComputeError, DuplicateError, InvalidOperationError,
-    NoDataError, PyPolarsErr, SchemaError, SchemaFieldNotFoundError, StructFieldNotFoundError,
+    ArrowErrorException, CancelledError, ColumnNotFoundError, ComputeError, DuplicateError,
+    InvalidOperationError, NoDataError, PyPolarsErr, SchemaError, SchemaFieldNotFoundError,
+    StructFieldNotFoundError,
 };
 use crate::file::{get_either_file, EitherRustPythonFile};
 use crate::lazy::dataframe::{PyLazyFrame, PyLazyGroupBy};
@@ -608,6 +609,8 @@ fn get_float_fmt() -> PyResult<String> {
 fn polars(py: Python, m: &PyModule) -> PyResult<()> {
     m.add("ArrowError", py.get_type::<ArrowErrorException>())
         .unwrap();
+    m.add("CancelledError", py.get_type::<CancelledError>())
+        .unwrap();
     m.add("ColumnNotFoundError", py.get_type::<ColumnNotFoundError>())
         .unwrap();
     m.add("ComputeError", py.get_type::<ComputeError>())
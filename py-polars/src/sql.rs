@@ -29,6 +29,14 @@ impl PySQLContext {
         self.context.register(name, lf.ldf)
     }
 
+    pub fn unregister(&mut self, name: &str) {
+        self.context.unregister(name)
+    }
+
+    pub fn get_tables(&self) -> Vec<String> {
+        self.context.get_tables()
+    }
+
     pub fn execute(&mut self, query: &str) -> PyResult<PyLazyFrame> {
         Ok(self
             .context
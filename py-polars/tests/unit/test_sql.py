@@ -115,3 +115,22 @@ def test_sql_trim(foods_ipc_path: Path) -> None:
     """
     )
     assert out.to_dict(False) == {"category": ["egetables", "seafood"]}
+
+
+def test_sql_register_unregister() -> None:
+    c = pl.SQLContext()
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+    c.register("tbl_b", lf)
+    c.register("tbl_a", lf)
+    assert c.tables() == ["tbl_a", "tbl_b"]
+
+    c.unregister("tbl_b")
+    assert c.tables() == ["tbl_a"]
+
+    c.query("DROP TABLE tbl_a")
+    assert c.tables() == []
+
+    # every context owns its tables
+    other = pl.SQLContext()
+    other.register("tbl", lf)
+    assert c.tables() == []
@@ -1,6 +1,8 @@
+from __future__ import annotations
+
 import time
 from datetime import date
-from typing import Any
+from typing import TYPE_CHECKING, Any
 
 import numpy as np
 import pytest
@@ -8,6 +10,9 @@ import pytest
 import polars as pl
 from polars.testing import assert_frame_equal, assert_series_equal
 
+if TYPE_CHECKING:
+    from polars.type_aliases import JoinStrategy
+
 
 def test_streaming_groupby_types() -> None:
     df = pl.DataFrame(
@@ -132,6 +137,53 @@ def test_streaming_non_streaming_gb() -> None:
     assert_frame_equal(q.collect(streaming=True), q.collect())
 
 
+def test_streaming_groupby_statistical_aggregates() -> None:
+    np.random.seed(1)
+    n = 10_000
+    df = pl.DataFrame(
+        {
+            "a": np.random.randint(0, 5, n),
+            "b": np.random.randint(0, 1000, n),
+            "c": np.random.random(n).astype(np.float32),
+        }
+    ).with_columns(
+        pl.when(pl.col("b") % 7 == 0).then(None).otherwise(pl.col("b")).alias("b")
+    )
+
+    q = (
+        df.lazy()
+        .groupby("a")
+        .agg(
+            [
+                pl.col("b").std().alias("b_std"),
+                pl.col("b").var(ddof=0).alias("b_var"),
+                pl.col("c").std().alias("c_std"),
+                pl.col("b").n_unique().alias("b_n_unique"),
+                pl.col("b").list().alias("b_list"),
+            ]
+        )
+        .sort("a")
+    )
+    assert_frame_equal(q.collect(streaming=True), q.collect())
+
+    # quantiles of large groups are approximated
+    q = (
+        df.lazy()
+        .groupby("a")
+        .agg(
+            [
+                pl.col("b").median().alias("b_median"),
+                pl.col("b").quantile(0.9).alias("b_q90"),
+                pl.col("c").quantile(0.1, "linear").alias("c_q10"),
+            ]
+        )
+        .sort("a")
+    )
+    assert_frame_equal(
+        q.collect(streaming=True), q.collect(), check_exact=False, rtol=0.05
+    )
+
+
 def test_streaming_groupby_sorted_fast_path() -> None:
     a = np.random.randint(0, 20, 80)
     df = pl.DataFrame(
@@ -413,3 +465,19 @@ def test_streaming_groupby_ooc(monkeypatch: Any) -> None:
             "a_first": [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
             "a_last": [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
         }
+
+
+@pytest.mark.write_disk()
+def test_streaming_join_ooc(monkeypatch: Any) -> None:
+    monkeypatch.setenv("POLARS_FORCE_OOC_JOIN", "1")
+    np.random.seed(1)
+    df_a = pl.DataFrame({"a": np.random.randint(0, 100, 1000), "b": np.arange(1000)})
+    df_b = pl.DataFrame({"a": np.random.randint(50, 150, 300), "c": np.arange(300)})
+
+    join_strategies: list[JoinStrategy] = ["inner", "left", "outer", "semi", "anti"]
+    for how in join_strategies:
+        for left, right in [(df_a, df_b), (df_b, df_a)]:
+            q = left.lazy().join(right.lazy(), on="a", how=how)
+            expected = q.collect()
+            out = q.collect(streaming=True)
+            assert_frame_equal(out.sort(out.columns), expected.sort(expected.columns))